@@ -0,0 +1,103 @@
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Fields, Type};
+
+use crate::external_name;
+
+/// Returns `true` for `Option<T>` fields, which are `None` when they are not set on the builder.
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.segments.last().is_some_and(|segment| segment.ident == "Option"),
+        Type::Group(group) => is_option(&group.elem),
+        Type::Paren(paren) => is_option(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Generates `<Name>Builder`, with a method per field that sets it, and `build()`, which returns the struct only if every
+/// field without an `Option` type was set and it passes `validate_all`. `Name::builder()` returns an empty builder.
+pub(crate) fn derive_builder(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    let fields = match &ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(fields_named), .. }) => &fields_named.named,
+        _ => abort!(
+            ast.ident.span(), "`RodBuilder` can only be derived for structs with named fields";
+            help = "Build enums and tuple structs directly, then validate them with `validate_all`"
+        ),
+    };
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let builder = format_ident!("{}Builder", name);
+    let field_names = fields.iter().map(|field| field.ident.as_ref().unwrap()).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let setters = fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let field_vis = &field.vis;
+        let doc = format!("Sets `{}`. It is validated with the rest of the struct by `build`.", field_name.unraw());
+        quote! {
+            #[doc = #doc]
+            #field_vis fn #field_name(mut self, value: #ty) -> Self {
+                self.#field_name = Some(value);
+                self
+            }
+        }
+    });
+    let check_fields = fields.iter().filter(|field| !is_option(&field.ty)).map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let path = external_name(field_name, field);
+        quote! {
+            if self.#field_name.is_none() {
+                errors.push(RodValidateError::Struct(StructValidation::Missing(String::from(#path))));
+            }
+        }
+    });
+    let take_fields = fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        if is_option(&field.ty) {
+            quote!(#field_name: self.#field_name.flatten())
+        } else {
+            quote!(#field_name: self.#field_name.expect("required fields are checked before building"))
+        }
+    });
+    let builder_doc = format!("A builder for [`{}`], whose `build` returns it only if it is valid.", name);
+    let builder_fn_doc = format!("Returns an empty [`{}`].", builder);
+    let generics = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #generics #where_clause {
+            #( #field_names: Option<#field_types>, )*
+        }
+
+        impl #impl_generics Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                #builder {
+                    #( #field_names: None, )*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #( #setters )*
+            /// Builds the value, returning every error if a required field was not set or the value is invalid.
+            #vis fn build(self) -> Result<#name #ty_generics, RodValidateErrorList> {
+                let mut errors = RodValidateErrorList::new();
+                #( #check_fields )*
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+                let value = #name { #( #take_fields ),* };
+                RodValidate::validate_all(&value)?;
+                Ok(value)
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #vis fn builder() -> #builder #ty_generics {
+                #builder::default()
+            }
+        }
+    }
+}
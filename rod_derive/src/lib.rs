@@ -1,18 +1,23 @@
 extern crate proc_macro;
 
+use std::collections::HashMap;
+
 use proc_macro::TokenStream;
-use proc_macro_error::{abort, emit_warning, proc_macro_error};
-use quote::quote;
+use proc_macro_error::{abort, proc_macro_error};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 use syn::{
-    Data, DeriveInput, ExprClosure, Fields, Ident, LitStr, Result as SynResult, Type, TypeTuple,
+    Data, DeriveInput, Expr, ExprClosure, Fields, Ident, LitStr, Result as SynResult, Type, TypeTuple,
     parse_macro_input,
 };
+mod builder;
+mod schema;
 mod types;
 use types::{
-    CustomContent, RodBooleanContent, RodFloatContent, RodIntegerContent, RodLiteralContent,
-    RodOptionContent, RodSkipContent, RodStringContent, RodTupleContent,
+    bind_field_path, field_path, user_defined_error, CustomContent, RodBooleanContent, RodFloatContent, RodIntegerContent, RodLiteralContent,
+    RodOptionContent, RodSkipContent, RodStringContent, RodTupleContent, RuleMessage,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -63,15 +68,20 @@ fn type_is_nested_reference(ty: &Type) -> IsNestedReference {
     }
 }
 
-fn recurse_rod_attr_opt(input: &RodAttr, level: usize) -> Option<(RodAttrType, usize)> {
+/// Both `Option` and `Iterable` wrap their inner type in a generic argument, so they are unwrapped together,
+/// matching the nesting levels counted by `recurse_type_path`, e.g. `Option { Iterable { item: String } }` for `Option<Vec<String>>`.
+fn recurse_rod_attr_generic(input: &RodAttr, level: usize) -> Option<(RodAttrType, usize)> {
     match &input.content {
         RodAttrContent::Option(content) => {
             if let Some(inner) = &content.inner {
-                recurse_rod_attr_opt(&inner.as_ref(), level + 1)
+                recurse_rod_attr_generic(inner.as_ref(), level + 1)
             } else {
                 None
             }
         }
+        RodAttrContent::Iterable(content) => recurse_rod_attr_generic(content.element(), level + 1),
+        // A literal can be compared to any type
+        RodAttrContent::Literal(_) => None,
         _ => Some((input.ty.clone(), level)),
     }
 }
@@ -103,7 +113,8 @@ fn recurse_rod_attr_tuple(input: &RodAttr, level: usize) -> Option<Vec<(RodAttrT
     match &input.content {
         RodAttrContent::Tuple(content) => {
             let mut types = Vec::new();
-            for field in &content.fields {
+            // Skipped elements are left out on both sides, so that they match any type, including nested tuples
+            for field in content.fields.iter().filter(|field| !matches!(field.ty, RodAttrType::Skip(_))) {
                 if let Some(inner_types) = recurse_rod_attr_tuple(field, level + 1) {
                     types.extend(inner_types);
                 } else {
@@ -116,13 +127,21 @@ fn recurse_rod_attr_tuple(input: &RodAttr, level: usize) -> Option<Vec<(RodAttrT
     }
 }
 
-fn recurse_tuple(ty: &Type, level: usize) -> Option<Vec<(RodAttrType, usize)>> {
+fn recurse_tuple(ty: &Type, expected: Option<&RodAttr>, level: usize) -> Option<Vec<(RodAttrType, usize)>> {
     if let Type::Tuple(tuple) = ty {
+        let fields = match expected.map(|expected| &expected.content) {
+            Some(RodAttrContent::Tuple(content)) => content.fields.as_slice(),
+            _ => &[],
+        };
         let mut types = Vec::new();
-        for elem in &tuple.elems {
+        for (i, elem) in tuple.elems.iter().enumerate() {
+            let field = fields.get(i);
+            if field.is_some_and(|field| matches!(field.ty, RodAttrType::Skip(_))) {
+                continue;
+            }
             match elem {
                 Type::Tuple(_) => {
-                    if let Some(inner_types) = recurse_tuple(elem, level + 1) {
+                    if let Some(inner_types) = recurse_tuple(elem, field, level + 1) {
                         types.extend(inner_types);
                     }
                 }
@@ -160,18 +179,11 @@ fn diff_tuple_array(
     (expected[i].clone(), actual[j].clone())
 }
 
-fn recurse_iterable(input: &RodAttr, level: usize) -> Option<(RodAttrType, usize)> {
-    match &input.content {
-        RodAttrContent::Iterable(content) => recurse_iterable(content.item.as_ref(), level + 1),
-        _ => Some((input.ty.clone(), level)),
-    }
-}
-
 macro_rules! assert_type {
     ($name:expr, $ty:expr, $expected:expr) => {
         match $expected.ty {
             RodAttrType::Iterable(_) => {
-                let item_type = recurse_iterable(&$expected, 0);
+                let item_type = recurse_rod_attr_generic(&$expected, 0);
                 let item_actual_type = recurse_type_path($ty, 0);
                 if item_type.is_some() && item_type != item_actual_type {
                     if let Some((item_type, level)) = item_type {
@@ -194,7 +206,7 @@ macro_rules! assert_type {
                 }
             },
             RodAttrType::Option(_) => {
-                let inner_type = recurse_rod_attr_opt(&$expected, 0);
+                let inner_type = recurse_rod_attr_generic(&$expected, 0);
                 let inner_actual_type = recurse_type_path($ty, 0);
                 if inner_type.is_some() && inner_type != inner_actual_type {
                     if let Some((inner_type, level)) = inner_type {
@@ -218,7 +230,7 @@ macro_rules! assert_type {
             }
             RodAttrType::Tuple(_) => {
                 let inner_ty_array = recurse_rod_attr_tuple(&$expected, 0);
-                let inner_actual_ty_array = recurse_tuple($ty, 0);
+                let inner_actual_ty_array = recurse_tuple($ty, Some(&$expected), 0);
                 debug_assert!(inner_ty_array.is_some() && inner_actual_ty_array.is_some(), "Expected a tuple type, but found: {:?}", $ty);
                 if inner_ty_array != inner_actual_ty_array {
                     let (i, j) = diff_tuple_array(inner_ty_array.as_ref().unwrap(), inner_actual_ty_array.as_ref().unwrap());
@@ -253,23 +265,66 @@ macro_rules! assert_type {
     };
 }
 
+/// Returns the span from `start` to `end`, or the call site if they cannot be joined, e.g. on stable Rust.
+fn join_span(start: proc_macro2::Span, end: proc_macro2::Span) -> proc_macro2::Span {
+    start.join(end).unwrap_or_else(proc_macro2::Span::call_site)
+}
+
 enum RodExpr {
-    Attribute(RodAttr),
+    Attribute(Box<RodAttr>),
     Check(RodCheck),
     Message(RodMessage),
+    Tags(RodTags),
+    SkipIf(RodSkipIf),
+    With(RodWith),
+    MessageFn(RodMessageFn),
+    MessageKey(RodMessageKey),
+    Sensitive(proc_macro2::Span),
+    Rename(RodRename),
+    Coerce(RodCoerce),
 }
 
 impl Parse for RodExpr {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        if input.peek(Ident) && input.peek2(syn::Token![=]) {
+        if input.peek(Ident) && input.peek2(syn::token::Paren) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "tags") {
+            let rod_tags: RodTags = input.parse()?;
+            Ok(RodExpr::Tags(rod_tags))
+        } else if input.peek(Ident) && input.peek2(syn::Token![=]) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "skip_if") {
+            let rod_skip_if: RodSkipIf = input.parse()?;
+            Ok(RodExpr::SkipIf(rod_skip_if))
+        } else if input.peek(Ident) && input.peek2(syn::Token![=]) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "with") {
+            let rod_with: RodWith = input.parse()?;
+            Ok(RodExpr::With(rod_with))
+        } else if input.peek(Ident) && input.peek2(syn::Token![=]) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "message_fn") {
+            let rod_message_fn: RodMessageFn = input.parse()?;
+            Ok(RodExpr::MessageFn(rod_message_fn))
+        } else if input.peek(Ident) && input.peek2(syn::Token![=]) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "rename") {
+            let rod_rename: RodRename = input.parse()?;
+            Ok(RodExpr::Rename(rod_rename))
+        } else if input.peek(Ident) && input.peek2(syn::token::Paren) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "coerce") {
+            let rod_coerce: RodCoerce = input.parse()?;
+            Ok(RodExpr::Coerce(rod_coerce))
+        } else if input.peek(Ident) && input.peek2(syn::Token![=]) {
             let rod_check: RodCheck = input.parse()?;
             Ok(RodExpr::Check(rod_check))
+        } else if input.peek(syn::Token![?]) {
+            // `?"message" check = ...` gives a single check its own message, and `?warn "message"` makes it a warning
+            let message: RuleMessage = input.parse()?;
+            let mut rod_check: RodCheck = input.parse()?;
+            rod_check.message = Some(message);
+            Ok(RodExpr::Check(rod_check))
+        } else if input.peek(Ident) && input.peek2(syn::Token![:]) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "message_key") {
+            let rod_message_key: RodMessageKey = input.parse()?;
+            Ok(RodExpr::MessageKey(rod_message_key))
         } else if input.peek(Ident) && input.peek2(syn::Token![:]) {
             let rod_message: RodMessage = input.parse()?;
             Ok(RodExpr::Message(rod_message))
+        } else if input.peek(Ident) && !input.peek2(syn::token::Brace) && input.fork().parse::<Ident>().is_ok_and(|ident| ident == "sensitive") {
+            let ident = input.parse::<Ident>()?;
+            Ok(RodExpr::Sensitive(ident.span()))
         } else {
             let rod_attr: RodAttr = input.parse()?;
-            Ok(RodExpr::Attribute(rod_attr))
+            Ok(RodExpr::Attribute(Box::new(rod_attr)))
         }
     }
 }
@@ -280,43 +335,132 @@ struct RodAttr {
     span: proc_macro2::Span,
 }
 
+/// A `check = |v| ...` closure, or with `negated` set, a `fail_if = |v| ...` closure.
+/// A path to a function, e.g. `check = my_module::is_valid_slug`, is accepted in place of the closure.
+/// On fields, a `?"message"` in front of it replaces the error of this check only, and `?warn "message"` reports it as a warning.
+/// With `receives_self` set, it is a field-level `check_self = |this: &Self, value| ...` closure that also receives the whole value.
 struct RodCheck {
-    closure: ExprClosure,
+    closure: Expr,
+    negated: bool,
+    receives_self: bool,
+    message: Option<RuleMessage>,
     span: proc_macro2::Span,
 }
 
+impl RodCheck {
+    fn name(&self) -> &'static str {
+        if self.receives_self {
+            "check_self"
+        } else if self.negated {
+            "fail_if"
+        } else {
+            "check"
+        }
+    }
+}
+
 impl Parse for RodCheck {
     fn parse(input: ParseStream) -> SynResult<Self> {
         let ident = input.parse::<Ident>()?;
-        if ident != "check" {
+        if ident != "check" && ident != "fail_if" && ident != "check_self" {
             abort!(
                 ident.span(),
-                "Unknown attribute `{}`. Expected `check`",
+                "Unknown attribute `{}`. Expected `check`, `fail_if` or `check_self`",
                 ident
             )
         }
+        let receives_self = ident == "check_self";
         input.parse::<syn::Token![=]>()?;
-        let expr: ExprClosure = input.parse()?;
-        let span = ident
-            .span()
-            .join(expr.span())
-            .unwrap_or_else(|| proc_macro2::Span::call_site());
-        if expr.inputs.len() != 1 {
-            abort!(
-                expr.span(), "Expected a single argument for `check` closure, but found {} arguments",
-                expr.inputs.len();
+        let expr: Expr = input.parse()?;
+        let span = join_span(ident.span(), expr.span());
+        match &expr {
+            Expr::Closure(closure) if receives_self && closure.inputs.len() != 2 => abort!(
+                expr.span(), "Expected two arguments for `check_self` closure, but found {} arguments",
+                closure.inputs.len();
+                help = "The closure receives the whole value and the field, e.g. `check_self = |this: &Self, value| ...`"
+            ),
+            Expr::Closure(closure) if !receives_self && closure.inputs.len() != 1 => abort!(
+                expr.span(), "Expected a single argument for `{}` closure, but found {} arguments",
+                ident, closure.inputs.len();
                 help = "Make sure the closure has exactly one argument"
-            );
+            ),
+            Expr::Closure(_) | Expr::Path(_) => {}
+            _ => abort!(
+                expr.span(), "Expected a closure or a path to a function for `{}`", ident;
+                help = "Example: `{} = |v| ...` or `{} = my_module::is_valid`", ident, ident
+            ),
         }
+        // Calling the function from a closure lets deref coercion apply, e.g. a `fn(&str) -> bool` for a `String` field
+        let closure = match expr {
+            Expr::Path(path) if receives_self => syn::parse_quote!(|this, value| #path(this, value)),
+            Expr::Path(path) => syn::parse_quote!(|value| #path(value)),
+            expr => expr,
+        };
         Ok(RodCheck {
-            closure: expr,
+            closure,
+            negated: ident == "fail_if",
+            receives_self,
+            message: None,
             span,
         })
     }
 }
 
+/// A `skip_if = |s: &Self| ...` closure. When it returns `true`, none of the rules of the field run.
+struct RodSkipIf {
+    closure: ExprClosure,
+    span: proc_macro2::Span,
+}
+
+impl RodSkipIf {
+    /// Wraps the validations or fixes of a field in a guard that bypasses them when the closure returns `true`.
+    fn guard(&self, inner: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let closure = &self.closure;
+        quote! {
+            {
+                let skip_if: fn(&Self) -> bool = #closure;
+                if !skip_if(self) {
+                    #inner
+                }
+            }
+        }
+    }
+}
+
+impl Parse for RodSkipIf {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<syn::Token![=]>()?;
+        let expr: ExprClosure = input.parse()?;
+        let span = join_span(ident.span(), expr.span());
+        if expr.inputs.len() != 1 {
+            abort!(
+                expr.span(), "Expected a single argument for `skip_if` closure, but found {} arguments", expr.inputs.len();
+                help = "The closure receives the whole value, e.g. `skip_if = |s: &Self| s.is_draft`"
+            );
+        }
+        Ok(RodSkipIf { closure: expr, span })
+    }
+}
+
+/// A `with = path::to::fn` validator, a function `fn(&T) -> Result<(), RodValidateError>` called with the field.
+struct RodWith {
+    path: syn::Path,
+    span: proc_macro2::Span,
+}
+
+impl Parse for RodWith {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<syn::Token![=]>()?;
+        let path: syn::Path = input.parse()?;
+        let span = join_span(ident.span(), path.span());
+        Ok(RodWith { path, span })
+    }
+}
+
 struct RodMessage {
-    message: LitStr,
+    message: RuleMessage,
     span: proc_macro2::Span,
 }
 
@@ -332,11 +476,567 @@ impl Parse for RodMessage {
         }
         input.parse::<syn::Token![:]>()?;
         let message: LitStr = input.parse()?;
-        let span = ident
-            .span()
-            .join(message.span())
-            .unwrap_or_else(|| proc_macro2::Span::call_site());
-        Ok(RodMessage { message, span })
+        let span = join_span(ident.span(), message.span());
+        Ok(RodMessage { message: message.into(), span })
+    }
+}
+
+/// A `message_fn = |value| ...` closure, or a path to a function, that computes the message of the field from its value.
+/// Like `message`, it replaces the errors of the field, but messages set on individual rules or checks are kept.
+struct RodMessageFn {
+    closure: Expr,
+    span: proc_macro2::Span,
+}
+
+impl RodMessageFn {
+    /// Replaces the errors reported by `inner` by shadowing `rod_wrap_error`, which every error of the field goes through.
+    fn wrap(&self, inner: proc_macro2::TokenStream, field_type: &proc_macro2::TokenStream, field_access: &Ident) -> proc_macro2::TokenStream {
+        let closure = &self.closure;
+        quote! {
+            {
+                let message_fn: fn(#field_type) -> String = #closure;
+                #[allow(unused_variables)]
+                let rod_wrap_error = |error: RodValidateError| -> RodValidateError {
+                    match error {
+                        RodValidateError::UserDefined(_) => rod_wrap_error(error),
+                        _ => rod_wrap_error(RodValidateError::UserDefined(message_fn(#field_access))),
+                    }
+                };
+                #inner
+            }
+        }
+    }
+}
+
+impl Parse for RodMessageFn {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<syn::Token![=]>()?;
+        let expr: Expr = input.parse()?;
+        let span = join_span(ident.span(), expr.span());
+        let closure = match expr {
+            Expr::Closure(closure) if closure.inputs.len() != 1 => abort!(
+                closure.span(), "Expected a single argument for `message_fn` closure, but found {} arguments", closure.inputs.len();
+                help = "The closure receives the field, e.g. `message_fn = |value| format!(\"bad id: {}\", value)`"
+            ),
+            Expr::Closure(closure) => Expr::Closure(closure),
+            // Calling the function from a closure lets deref coercion apply, as for `check`
+            Expr::Path(path) => syn::parse_quote!(|value| #path(value)),
+            expr => abort!(
+                expr.span(), "Expected a closure or a path to a function for `message_fn`";
+                help = "Example: `message_fn = |value| format!(\"bad id: {}\", value)` or `message_fn = my_module::describe`"
+            ),
+        };
+        Ok(RodMessageFn { closure, span })
+    }
+}
+
+/// A `message_key: "..."` key, from which the translator set with `set_translator` builds the message of the field.
+/// Like `message`, it replaces the errors of the field, but messages set on individual rules or checks are kept.
+struct RodMessageKey {
+    key: LitStr,
+    span: proc_macro2::Span,
+}
+
+impl RodMessageKey {
+    /// Replaces the errors reported by `inner` the same way as [`RodMessageFn::wrap`], translating them with their params.
+    fn wrap(&self, inner: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let key = &self.key;
+        quote! {
+            {
+                #[allow(unused_variables)]
+                let rod_wrap_error = |error: RodValidateError| -> RodValidateError {
+                    match error {
+                        RodValidateError::UserDefined(_) => rod_wrap_error(error),
+                        _ => rod_wrap_error(error.translated(#key)),
+                    }
+                };
+                #inner
+            }
+        }
+    }
+}
+
+impl Parse for RodMessageKey {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<syn::Token![:]>()?;
+        let key: LitStr = input.parse()?;
+        let span = join_span(ident.span(), key.span());
+        Ok(RodMessageKey { key, span })
+    }
+}
+
+/// A `rename = "..."` name, used in place of the name of the field in the paths and messages of its errors,
+/// e.g. to report the names of an API rather than Rust identifiers.
+struct RodRename {
+    name: LitStr,
+    span: proc_macro2::Span,
+}
+
+impl Parse for RodRename {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<syn::Token![=]>()?;
+        let name: LitStr = input.parse()?;
+        let span = join_span(ident.span(), name.span());
+        if name.value().is_empty() {
+            abort!(
+                span, "`rename` cannot be empty";
+                help = "Remove `rename` to use the name of the field"
+            );
+        }
+        Ok(RodRename { name, span })
+    }
+}
+
+/// A `coerce(Type)` attribute on a string field, which parses the string with `str::parse` and checks the rules of the type attribute,
+/// e.g. `#[rod(coerce(i32), i32 { size: 1..=10 })]`, on the parsed value. A string that does not parse fails with a `ParsesTo` error.
+struct RodCoerce {
+    ty: syn::Type,
+    span: proc_macro2::Span,
+}
+
+impl RodCoerce {
+    /// Wraps `validations`, written for a value of the coerced type, so they run on the parsed string bound to `field_name`.
+    fn wrap(&self, validations: proc_macro2::TokenStream, field_name: &Ident, ret: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let ty = &self.ty;
+        quote! {
+            match #field_name.parse::<#ty>() {
+                Ok(parsed) => {
+                    let #field_name = &parsed;
+                    #validations
+                }
+                Err(_) => {
+                    #ret;
+                }
+            }
+        }
+    }
+}
+
+impl Parse for RodCoerce {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        let content;
+        let paren = syn::parenthesized!(content in input);
+        let ty: syn::Type = content.parse()?;
+        let span = join_span(ident.span(), paren.span.close());
+        Ok(RodCoerce { ty, span })
+    }
+}
+
+/// `RodTags` represents the `tags("...", ...)` attribute of a field.
+/// Tagged fields can be validated on their own with `validate_tagged` and `validate_all_tagged`.
+struct RodTags {
+    tags: Vec<LitStr>,
+    span: proc_macro2::Span,
+}
+
+impl Parse for RodTags {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+        let content;
+        let paren = syn::parenthesized!(content in input);
+        let tags = content.parse_terminated(<LitStr as Parse>::parse, syn::Token![,])?;
+        if tags.is_empty() {
+            abort!(
+                ident.span(), "Expected at least one tag";
+                help = "Example: `tags(\"pii\", \"billing\")`"
+            );
+        }
+        let span = join_span(ident.span(), paren.span.join());
+        Ok(RodTags {
+            tags: tags.into_iter().collect(),
+            span,
+        })
+    }
+}
+
+/// `RodContainerAttrs` holds the `#[rod(...)]` attributes placed on the struct or enum itself.
+/// # Attributes
+/// - `wrap_error = path`: A function `fn(RodValidateError) -> RodValidateError` called on every error before it is returned or collected,
+///   e.g. to emit tracing events or record metrics. This includes errors reported by nested types.
+/// - `disjoint(a, b, ...)`: The collection fields share no elements. The overlapping elements, formatted with `Debug`, are reported in the error.
+/// - `subset_of(a, b)`: Every element of the collection `a` is also in `b`. `superset_of(b, a)` is the same rule.
+///   The missing elements, formatted with `Debug`, are reported in the error.
+/// - `same_length(a, b, ...)`: The collections, or strings, have the same `len()` as the first one. Both lengths are reported in the error.
+/// - `exclusive(a, b, ...)`: At most one of the `Option` fields is `Some`. The fields that are present are reported in the error.
+/// - `any_of(a, b, ...)`: At least one of the `Option` fields is `Some`.
+/// - `check = |s: &Self| ...`: A closure that receives the whole value, for invariants that span several fields. `fail_if` is the inverse.
+///   A `message: "..."` directly after it replaces the `CheckFailed` error. Unlike the other rules, it can also be placed on enums.
+/// - `field_validators`: Generates an associated function `validate_<field>(value)` for each field with rules, which runs them on a value
+///   that is not part of the struct yet, e.g. in a form handler. Rules that depend on other fields are left out.
+/// - `setters`: Generates a method `set_<field>(value)` for each field with rules, which assigns the value only if it passes them.
+/// - `constructor`: Generates `new(field, ...)`, which takes the fields in order and returns the struct only if it passes `validate_all`.
+/// - `patch`: Generates `<Name>Patch`, a partial update with every field wrapped in an `Option`, whose validation runs the rules of
+///   the fields that are present, except those that compare them to other fields. `patch(derive(...))` adds derives to it,
+///   e.g. `patch(derive(Debug, serde::Deserialize))`.
+/// - `newtype`: For a struct with a single private field, generates `new(value)`, `TryFrom` and `into_inner()`, which validate or give
+///   back the value, and `Deref` to it. Since the field is private, a value of the struct is always valid.
+///
+/// Struct-level rules run after the field validations, and are not part of any tag.
+#[derive(Default)]
+struct RodContainerAttrs {
+    wrap_error: Option<syn::Path>,
+    rules: Vec<RodContainerRule>,
+    field_validators: bool,
+    setters: bool,
+    constructor: bool,
+    patch: Option<Vec<syn::Path>>,
+    newtype: bool,
+}
+
+impl RodContainerAttrs {
+    fn from_attrs(attrs: &[syn::Attribute]) -> Self {
+        let mut container_attrs = RodContainerAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("rod")) {
+            let exprlist = attr
+                .parse_args_with(syn::punctuated::Punctuated::<RodContainerExpr, syn::Token![,]>::parse_terminated)
+                .unwrap_or_else(|e| abort!(e.span(), "Failed to parse attribute: {}", e));
+            for expr in exprlist {
+                match expr {
+                    RodContainerExpr::WrapError(path) => {
+                        if container_attrs.wrap_error.is_some() {
+                            abort!(
+                                path.span(), "Multiple `wrap_error` attributes found";
+                                help = "Remove the extra `wrap_error` attributes"
+                            );
+                        }
+                        container_attrs.wrap_error = Some(path);
+                    }
+                    RodContainerExpr::Rule(rule) => container_attrs.rules.push(rule),
+                    RodContainerExpr::FieldValidators(span) => {
+                        if container_attrs.field_validators {
+                            abort!(
+                                span, "Multiple `field_validators` attributes found";
+                                help = "Remove the extra `field_validators` attributes"
+                            );
+                        }
+                        container_attrs.field_validators = true;
+                    }
+                    RodContainerExpr::Setters(span) => {
+                        if container_attrs.setters {
+                            abort!(
+                                span, "Multiple `setters` attributes found";
+                                help = "Remove the extra `setters` attributes"
+                            );
+                        }
+                        container_attrs.setters = true;
+                    }
+                    RodContainerExpr::Constructor(span) => {
+                        if container_attrs.constructor {
+                            abort!(
+                                span, "Multiple `constructor` attributes found";
+                                help = "Remove the extra `constructor` attributes"
+                            );
+                        }
+                        if container_attrs.newtype {
+                            abort!(
+                                span, "`newtype` and `constructor` both generate `new`";
+                                help = "Remove the `constructor` attribute"
+                            );
+                        }
+                        container_attrs.constructor = true;
+                    }
+                    RodContainerExpr::Patch(span, derives) => {
+                        if container_attrs.patch.is_some() {
+                            abort!(
+                                span, "Multiple `patch` attributes found";
+                                help = "List all derives in a single `patch(derive(...))` attribute"
+                            );
+                        }
+                        container_attrs.patch = Some(derives);
+                    }
+                    RodContainerExpr::Newtype(span) => {
+                        if container_attrs.newtype {
+                            abort!(
+                                span, "Multiple `newtype` attributes found";
+                                help = "Remove the extra `newtype` attributes"
+                            );
+                        }
+                        if container_attrs.constructor {
+                            abort!(
+                                span, "`newtype` and `constructor` both generate `new`";
+                                help = "Remove the `constructor` attribute"
+                            );
+                        }
+                        container_attrs.newtype = true;
+                    }
+                    RodContainerExpr::Message(message) => match container_attrs.rules.last_mut() {
+                        Some(RodContainerRule::Check { message: slot @ None, .. }) => *slot = Some(message.message),
+                        _ => abort!(
+                            message.span, "`message` must directly follow a struct-level `check` or `fail_if`";
+                            help = "Example: `#[rod(check = |s: &Self| s.start < s.end, message: \"start must precede end\")]`"
+                        ),
+                    },
+                }
+            }
+        }
+        container_attrs
+    }
+    /// Generates the checks of the struct-level rules, which run after the field validations.
+    /// Aborts if a rule names a field that does not exist.
+    /// Fields are reported by the name given with `rename`, like in their own errors.
+    fn get_validations(&self, name: &Ident, data: &Data, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        self.rules.iter().map(|rule| {
+            let rule_fields = rule.fields();
+            let mut paths = HashMap::new();
+            if !rule_fields.is_empty() {
+                let fields = match data {
+                    Data::Struct(syn::DataStruct { fields: Fields::Named(fields_named), .. }) => &fields_named.named,
+                    _ => abort!(
+                        rule.span(), "Struct-level rules that name fields are only supported on structs with named fields"
+                    ),
+                };
+                for field in rule_fields {
+                    match fields.iter().find(|f| f.ident.as_ref() == Some(field)) {
+                        Some(f) => paths.insert(field.clone(), external_name(field, f)),
+                        None => abort!(field.span(), "No field named `{}`", field),
+                    };
+                }
+            }
+            rule.get_validations(name, &paths, wrap_return)
+        }).collect()
+    }
+}
+
+/// A rule placed on the struct itself that relates several of its fields.
+enum RodContainerRule {
+    /// `disjoint(a, b, ...)`: the collections share no elements, compared pairwise.
+    Disjoint(Vec<Ident>),
+    /// `subset_of(a, b)`, or `superset_of(b, a)`: every element of `subset` is also in `superset`.
+    SubsetOf { subset: Ident, superset: Ident },
+    /// `same_length(a, b, ...)`: the collections all have the length of the first one.
+    SameLength(Vec<Ident>),
+    /// `exclusive(a, b, ...)`: at most one of the `Option` fields is `Some`.
+    Exclusive(Vec<Ident>),
+    /// `any_of(a, b, ...)`: at least one of the `Option` fields is `Some`.
+    AnyOf(Vec<Ident>),
+    /// `check = |s: &Self| ...` or `fail_if = |s: &Self| ...`, with an optional `message` replacing the error.
+    Check { check: RodCheck, message: Option<RuleMessage> },
+}
+
+impl RodContainerRule {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            RodContainerRule::Check { check, .. } => check.span,
+            _ => self.fields()[0].span(),
+        }
+    }
+    fn fields(&self) -> Vec<&Ident> {
+        match self {
+            RodContainerRule::Disjoint(fields) => fields.iter().collect(),
+            RodContainerRule::SubsetOf { subset, superset } => vec![subset, superset],
+            RodContainerRule::SameLength(fields) => fields.iter().collect(),
+            RodContainerRule::Exclusive(fields) => fields.iter().collect(),
+            RodContainerRule::AnyOf(fields) => fields.iter().collect(),
+            RodContainerRule::Check { .. } => Vec::new(),
+        }
+    }
+    fn get_validations(&self, name: &Ident, paths: &HashMap<Ident, String>, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            RodContainerRule::Check { check, message } => {
+                let closure = &check.closure;
+                let path = name.to_string();
+                let ret = match message {
+                    Some(msg) => user_defined_error(wrap_return, msg),
+                    None => wrap_return(quote! { RodValidateError::CheckFailed(#path.to_string()) }),
+                };
+                let failed = if check.negated {
+                    quote!(check(self))
+                } else {
+                    quote!(!check(self))
+                };
+                quote! {
+                    {
+                        let check: fn(&Self) -> bool = #closure;
+                        if #failed {
+                            #ret;
+                        }
+                    }
+                }
+            }
+            RodContainerRule::Disjoint(fields) => fields.iter().enumerate().flat_map(|(i, first)| {
+                fields[i + 1..].iter().map(move |second| {
+                    let first_path = &paths[first];
+                    let second_path = &paths[second];
+                    let ret = wrap_return(quote! {
+                        RodValidateError::Struct(StructValidation::Disjoint(#first_path.to_string(), #second_path.to_string(), rod_overlap))
+                    });
+                    quote! {
+                        {
+                            let rod_overlap: Vec<String> = (&self.#first).into_iter()
+                                .filter(|item| (&self.#second).into_iter().any(|other| other == *item))
+                                .map(|item| format!("{:?}", item))
+                                .collect();
+                            if !rod_overlap.is_empty() {
+                                #ret;
+                            }
+                        }
+                    }
+                })
+            }).collect(),
+            RodContainerRule::SubsetOf { subset, superset } => {
+                let subset_path = &paths[subset];
+                let superset_path = &paths[superset];
+                let ret = wrap_return(quote! {
+                    RodValidateError::Struct(StructValidation::SubsetOf(#subset_path.to_string(), #superset_path.to_string(), rod_missing))
+                });
+                quote! {
+                    {
+                        let rod_missing: Vec<String> = (&self.#subset).into_iter()
+                            .filter(|item| !(&self.#superset).into_iter().any(|other| other == *item))
+                            .map(|item| format!("{:?}", item))
+                            .collect();
+                        if !rod_missing.is_empty() {
+                            #ret;
+                        }
+                    }
+                }
+            }
+            RodContainerRule::Exclusive(fields) => {
+                let group = fields.iter().map(|field| &paths[field]);
+                let paths = fields.iter().map(|field| &paths[field]);
+                let ret = wrap_return(quote! {
+                    RodValidateError::Struct(StructValidation::Exclusive(vec![#(#group.to_string()),*], rod_present))
+                });
+                quote! {
+                    {
+                        let rod_present: Vec<String> = [#( (#paths, self.#fields.is_some()) ),*]
+                            .into_iter()
+                            .filter_map(|(path, present)| present.then(|| path.to_string()))
+                            .collect();
+                        if rod_present.len() > 1 {
+                            #ret;
+                        }
+                    }
+                }
+            }
+            RodContainerRule::AnyOf(fields) => {
+                let group = fields.iter().map(|field| &paths[field]);
+                let ret = wrap_return(quote! {
+                    RodValidateError::Struct(StructValidation::AnyOf(vec![#(#group.to_string()),*]))
+                });
+                quote! {
+                    if #( self.#fields.is_none() )&&* {
+                        #ret;
+                    }
+                }
+            }
+            RodContainerRule::SameLength(fields) => {
+                let first = &fields[0];
+                let first_path = &paths[first];
+                fields[1..].iter().map(|other| {
+                    let other_path = &paths[other];
+                    let ret = wrap_return(quote! {
+                        RodValidateError::Struct(StructValidation::SameLength(#first_path.to_string(), self.#first.len(), #other_path.to_string(), self.#other.len()))
+                    });
+                    quote! {
+                        if self.#first.len() != self.#other.len() {
+                            #ret;
+                        }
+                    }
+                }).collect()
+            }
+        }
+    }
+}
+
+/// Parses the parenthesized field list of a struct-level rule, e.g. `(admins, members)`.
+fn parse_rule_fields(input: ParseStream, rule: &Ident) -> SynResult<Vec<Ident>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let fields = syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated(&content)?;
+    if fields.len() < 2 {
+        abort!(
+            rule.span(), "`{}` needs at least two fields", rule;
+            help = "Example: `#[rod({}(a, b))]`", rule
+        );
+    }
+    Ok(fields.into_iter().collect())
+}
+
+enum RodContainerExpr {
+    WrapError(syn::Path),
+    Rule(RodContainerRule),
+    Message(RodMessage),
+    FieldValidators(proc_macro2::Span),
+    Setters(proc_macro2::Span),
+    Constructor(proc_macro2::Span),
+    Patch(proc_macro2::Span, Vec<syn::Path>),
+    Newtype(proc_macro2::Span),
+}
+
+impl Parse for RodContainerExpr {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if input.fork().parse::<Ident>().is_ok_and(|ident| ident == "check" || ident == "fail_if") {
+            let check: RodCheck = input.parse()?;
+            return Ok(RodContainerExpr::Rule(RodContainerRule::Check { check, message: None }));
+        }
+        if input.fork().parse::<Ident>().is_ok_and(|ident| ident == "message") {
+            return Ok(RodContainerExpr::Message(input.parse()?));
+        }
+        let ident = input.parse::<Ident>()?;
+        if ident == "wrap_error" {
+            input.parse::<syn::Token![=]>()?;
+            Ok(RodContainerExpr::WrapError(input.parse()?))
+        } else if ident == "field_validators" {
+            Ok(RodContainerExpr::FieldValidators(ident.span()))
+        } else if ident == "setters" {
+            Ok(RodContainerExpr::Setters(ident.span()))
+        } else if ident == "constructor" {
+            Ok(RodContainerExpr::Constructor(ident.span()))
+        } else if ident == "newtype" {
+            Ok(RodContainerExpr::Newtype(ident.span()))
+        } else if ident == "patch" {
+            let mut derives = Vec::new();
+            if input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                let derive = content.parse::<Ident>()?;
+                if derive != "derive" {
+                    abort!(
+                        derive.span(), "Unknown `patch` option `{}`", derive;
+                        help = "Example: `#[rod(patch(derive(Debug, serde::Deserialize)))]`"
+                    );
+                }
+                let paths;
+                syn::parenthesized!(paths in content);
+                derives = syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated(&paths)?.into_iter().collect();
+            }
+            Ok(RodContainerExpr::Patch(ident.span(), derives))
+        } else if ident == "disjoint" {
+            Ok(RodContainerExpr::Rule(RodContainerRule::Disjoint(parse_rule_fields(input, &ident)?)))
+        } else if ident == "same_length" {
+            Ok(RodContainerExpr::Rule(RodContainerRule::SameLength(parse_rule_fields(input, &ident)?)))
+        } else if ident == "exclusive" {
+            Ok(RodContainerExpr::Rule(RodContainerRule::Exclusive(parse_rule_fields(input, &ident)?)))
+        } else if ident == "any_of" {
+            Ok(RodContainerExpr::Rule(RodContainerRule::AnyOf(parse_rule_fields(input, &ident)?)))
+        } else if ident == "subset_of" || ident == "superset_of" {
+            let mut fields = parse_rule_fields(input, &ident)?;
+            if fields.len() != 2 {
+                abort!(
+                    fields[2].span(), "`{}` takes exactly two fields", ident;
+                    help = "Example: `#[rod({}(a, b))]`", ident
+                );
+            }
+            let second = fields.pop().unwrap();
+            let first = fields.pop().unwrap();
+            let rule = if ident == "subset_of" {
+                RodContainerRule::SubsetOf { subset: first, superset: second }
+            } else {
+                RodContainerRule::SubsetOf { subset: second, superset: first }
+            };
+            Ok(RodContainerExpr::Rule(rule))
+        } else {
+            abort!(
+                ident.span(),
+                "Unknown attribute `{}`. Expected `wrap_error`, `check`, `fail_if`, `disjoint`, `subset_of`, `superset_of`, `same_length`, `exclusive`, `any_of`, `field_validators`, `setters`, `constructor`, `patch` or `newtype`",
+                ident
+            )
+        }
     }
 }
 
@@ -363,6 +1063,9 @@ macro_rules! impl_rod_types {
                 match (self, other) {
                     (RodAttrType::Skip(_), _) => true,
                     (_, RodAttrType::Skip(_)) => true,
+                    // An enum tag rule applies to any user-defined enum
+                    (RodAttrType::EnumTag(_), RodAttrType::Custom(_)) => true,
+                    (RodAttrType::Custom(_), RodAttrType::EnumTag(_)) => true,
                     $(
                         (RodAttrType::$variant(ident1), RodAttrType::$variant(ident2)) => ident1 == ident2,
                     )*
@@ -476,6 +1179,9 @@ macro_rules! impl_rod_types {
                         }
                     ),*
                 };
+                if let (RodAttrContent::Integer(content), RodAttrType::Integer(TypeEnum::Type(ident))) = (&content, &rod_type) {
+                    content.check_size_fits(ident);
+                }
                 Ok(RodAttr { ty: rod_type, content, span })
             }
         }
@@ -485,7 +1191,7 @@ macro_rules! impl_rod_types {
 impl_rod_types! {
     String {
         ident: Ident,
-        content: RodStringContent,
+        content: Box<RodStringContent>,
         match: ["String", "str", "OsString", "OsStr", "PathBuf", "Path", "Cow"]
     },
     Integer {
@@ -530,9 +1236,14 @@ impl_rod_types! {
     },
     Iterable {
         ident: Ident,
-        content: types::RodIterableContent,
+        content: Box<types::RodIterableContent>,
         match: ["Iterable"]
     },
+    EnumTag {
+        ident: Ident,
+        content: types::RodEnumTagContent,
+        match: ["EnumTag"]
+    },
 }
 
 macro_rules! rod_content_match {
@@ -552,30 +1263,42 @@ macro_rules! rod_content_match {
     };
 }
 
+/// `$self_free` names the option, like `patch`, whose validations run without the rest of the struct.
+/// Rules that need it, like `check_self` and `le_field`, are left out, and rules that still refer to `self` abort.
 macro_rules!  get_field_validations {
     (
         $field_access:expr,
         $field:expr,
-        $wrap_return:expr
+        $wrap_return:expr,
+        $tagged:expr
+    ) => {
+        get_field_validations!($field_access, $field, $wrap_return, $tagged, None::<&str>)
+    };
+    (
+        $field_access:expr,
+        $field:expr,
+        $wrap_return:expr,
+        $tagged:expr,
+        $self_free:expr
     ) => {
         $field.attrs.iter().filter_map(|attr| {
             if attr.path().is_ident("rod") {
-                let mut check_opt = None;
+                let mut checks: Vec<RodCheck> = Vec::new();
                 let mut rod_attr_opt = None;
                 let mut message_opt = None;
+                let mut tags_opt: Option<RodTags> = None;
+                let mut skip_if_opt: Option<RodSkipIf> = None;
+                let mut with_opt: Option<RodWith> = None;
+                let mut message_fn_opt: Option<RodMessageFn> = None;
+                let mut message_key_opt: Option<RodMessageKey> = None;
+                let mut sensitive_opt: Option<proc_macro2::Span> = None;
+                let mut rename_opt: Option<RodRename> = None;
+                let mut coerce_opt: Option<RodCoerce> = None;
                 match attr.parse_args_with(syn::punctuated::Punctuated::<RodExpr, syn::Token![,]>::parse_terminated) {
                     Ok(exprlist) => {
                         for expr in exprlist {
                             match expr {
-                                RodExpr::Check(check) => {
-                                    if check_opt.is_some() {
-                                        abort!(
-                                            check.span, "Multiple `check` attributes found on field `{}`", $field_access;
-                                            help = "Remove the extra `check` attributes"
-                                        );
-                                    }
-                                    check_opt = Some(check);
-                                }
+                                RodExpr::Check(check) => checks.push(check),
                                 RodExpr::Attribute(rod_attr) => {
                                     if rod_attr_opt.is_some() {
                                         abort!(
@@ -583,7 +1306,7 @@ macro_rules!  get_field_validations {
                                             help = "Remove the extra attributes"
                                         );
                                     }
-                                    rod_attr_opt = Some(rod_attr);
+                                    rod_attr_opt = Some(*rod_attr);
                                 }
                                 RodExpr::Message(message) => {
                                     if message_opt.is_some() {
@@ -594,104 +1317,1008 @@ macro_rules!  get_field_validations {
                                     }
                                     message_opt = Some(message);
                                 }
-                            }
-                        }
-                    },
+                                RodExpr::Tags(tags) => {
+                                    if tags_opt.is_some() {
+                                        abort!(
+                                            tags.span, "Multiple `tags` attributes found on field `{}`", $field_access;
+                                            help = "List all tags in a single `tags(...)` attribute"
+                                        );
+                                    }
+                                    tags_opt = Some(tags);
+                                }
+                                RodExpr::SkipIf(skip_if) => {
+                                    if skip_if_opt.is_some() {
+                                        abort!(
+                                            skip_if.span, "Multiple `skip_if` attributes found on field `{}`", $field_access;
+                                            help = "Remove the extra `skip_if` attributes"
+                                        );
+                                    }
+                                    skip_if_opt = Some(skip_if);
+                                }
+                                RodExpr::With(with) => {
+                                    if with_opt.is_some() {
+                                        abort!(
+                                            with.span, "Multiple `with` attributes found on field `{}`", $field_access;
+                                            help = "Combine the validators in a single function"
+                                        );
+                                    }
+                                    with_opt = Some(with);
+                                }
+                                RodExpr::MessageFn(message_fn) => {
+                                    if message_fn_opt.is_some() {
+                                        abort!(
+                                            message_fn.span, "Multiple `message_fn` attributes found on field `{}`", $field_access;
+                                            help = "Remove the extra `message_fn` attributes"
+                                        );
+                                    }
+                                    message_fn_opt = Some(message_fn);
+                                }
+                                RodExpr::MessageKey(message_key) => {
+                                    if message_key_opt.is_some() {
+                                        abort!(
+                                            message_key.span, "Multiple `message_key` attributes found on field `{}`", $field_access;
+                                            help = "Remove the extra `message_key` attributes"
+                                        );
+                                    }
+                                    message_key_opt = Some(message_key);
+                                }
+                                RodExpr::Sensitive(span) => {
+                                    if sensitive_opt.is_some() {
+                                        abort!(
+                                            span, "Multiple `sensitive` attributes found on field `{}`", $field_access;
+                                            help = "Remove the extra `sensitive` attributes"
+                                        );
+                                    }
+                                    sensitive_opt = Some(span);
+                                }
+                                RodExpr::Rename(rename) => {
+                                    if rename_opt.is_some() {
+                                        abort!(
+                                            rename.span, "Multiple `rename` attributes found on field `{}`", $field_access;
+                                            help = "Remove the extra `rename` attributes"
+                                        );
+                                    }
+                                    rename_opt = Some(rename);
+                                }
+                                RodExpr::Coerce(coerce) => {
+                                    if coerce_opt.is_some() {
+                                        abort!(
+                                            coerce.span, "Multiple `coerce` attributes found on field `{}`", $field_access;
+                                            help = "Remove the extra `coerce` attributes"
+                                        );
+                                    }
+                                    coerce_opt = Some(coerce);
+                                }
+                            }
+                        }
+                    },
                     Err(e) => {
                         abort!(
                             e.span(), "Failed to parse attribute: {}", e
                         );
                     }
                 }
-                match rod_attr_opt {
-                    Some(rod_attr) => {
-                        assert_type!($field_access, &$field.ty, rod_attr);
-                        let validations_for_field = if let Some(message) = message_opt.as_ref() {
+                let self_free: Option<&str> = $self_free;
+                if let Some(option) = self_free {
+                    if let Some(skip_if) = skip_if_opt.as_ref() {
+                        abort!(
+                            skip_if.closure.span(), "`skip_if` on field `{}` needs the whole struct, which `{}` does not have", $field_access, option;
+                            help = "Remove `skip_if`, or `{}`", option
+                        );
+                    }
+                    checks.retain(|check| !check.receives_self);
+                    if let Some(rod_attr) = rod_attr_opt.as_mut() {
+                        strip_field_comparisons(rod_attr);
+                    }
+                }
+                let skipped = rod_attr_opt.as_ref().is_some_and(|rod_attr| matches!(rod_attr.ty, RodAttrType::Skip(_)));
+                if let Some(rename) = rename_opt.as_ref() {
+                    if skipped {
+                        abort!(
+                            rename.span, "Cannot use `rename` with `skip` attribute on field `{}`", $field_access;
+                            help = "Remove the `rename` attribute"
+                        );
+                    }
+                }
+                if let (Some(message), Some(message_fn)) = (message_opt.as_ref(), message_fn_opt.as_ref()) {
+                    abort!(
+                        message_fn.span.join(message.span).unwrap_or(message_fn.span), "Cannot use both `message` and `message_fn` on field `{}`", $field_access;
+                        help = "Remove `message` to compute the message from the value, or `message_fn` to keep a fixed message"
+                    );
+                }
+                if let Some(message_key) = message_key_opt.as_ref() {
+                    let other = message_opt.as_ref().map(|message| ("message", message.span))
+                        .or(message_fn_opt.as_ref().map(|message_fn| ("message_fn", message_fn.span)));
+                    if let Some((other, span)) = other {
+                        abort!(
+                            message_key.span.join(span).unwrap_or(message_key.span), "Cannot use both `{}` and `message_key` on field `{}`", other, $field_access;
+                            help = "Remove `{}` to translate the message, or `message_key` to keep it", other
+                        );
+                    }
+                }
+                let validations_for_field = match rod_attr_opt {
+                    Some(rod_attr) if coerce_opt.is_some() => {
+                        let coerce = coerce_opt.as_ref().unwrap();
+                        if skipped {
+                            abort!(
+                                coerce.span, "Cannot use `coerce` with `skip` attribute on field `{}`", $field_access;
+                                help = "Remove the `coerce` attribute"
+                            );
+                        }
+                        let actual_type: RodAttrType = (&$field.ty).into();
+                        if !matches!(actual_type, RodAttrType::String(_)) {
+                            abort!(
+                                $field.ty.span(), "`coerce` parses a string, but `{}` is a `{}`", $field_access, get_type(&$field.ty).unwrap();
+                                help = "Remove `coerce` to validate the field as it is"
+                            );
+                        }
+                        // The rules are written for the coerced type, so it is the one they are checked against
+                        assert_type!($field_access, &coerce.ty, rod_attr);
+                        let validations = if let Some(message) = message_opt.as_ref() {
                             rod_content_match!(
                                 &rod_attr.content, 
                                 $field_access, 
                                 $wrap_return, 
                                 &message.message, 
-                                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
+                                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
                             )
                         } else {
                             rod_content_match!(
                                 &rod_attr.content, 
                                 $field_access, 
                                 $wrap_return, 
-                                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
+                                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
                             )
                         };
-                        let check = check_opt.map_or_else(|| quote! {}, |check| {
-                            if matches!(rod_attr.ty, RodAttrType::Skip(_)) {
-                                abort!(
-                                    check.span, "Cannot use `check` with `skip` attribute on field `{}`", $field_access;
-                                    help = "Remove the `check` attribute"
-                                );
-                            }
-                            let closure = &check.closure;
-                            let ty = &$field.ty;
-                            let field_type = match type_is_nested_reference(ty) {
-                                IsNestedReference::None => quote! {
-                                    &#ty
-                                },
-                                IsNestedReference::Single => quote! {
-                                    #ty
-                                },
-                                IsNestedReference::More => unreachable!(), // This should have been caught earlier
-                            };
-                            let path = $field_access.to_string();
-                            let ret = if let Some(message) = message_opt.as_ref() {
-                                let msg = &message.message;
-                                $wrap_return(quote! { RodValidateError::UserDefined(#msg.to_string()) })
-                            } else {
-                                $wrap_return(quote! { RodValidateError::CheckFailed(#path) })
-                            };
+                        let ret = if let Some(message) = message_opt.as_ref() {
+                            user_defined_error($wrap_return, &message.message)
+                        } else {
+                            let path = field_path($field_access);
+                            let ty = coerce.ty.clone();
+                            let ty = quote!(#ty).to_string();
                             let field_access = $field_access;
-                            quote! {
-                                let check: fn(#field_type) -> bool = #closure;
-                                if !check(#field_access) {
-                                    #ret;
-                                }
-                            }
-                        });
-                        Some(quote! {
-                            #check
-                            #validations_for_field
-                        })
+                            $wrap_return(quote! { RodValidateError::String(StringValidation::ParsesTo(#path, #field_access.to_string(), #ty)) })
+                        };
+                        coerce.wrap(validations, $field_access, ret)
+                    }
+                    Some(rod_attr) => {
+                        assert_type!($field_access, &$field.ty, rod_attr);
+                        if let Some(message) = message_opt.as_ref() {
+                            rod_content_match!(
+                                &rod_attr.content, 
+                                $field_access, 
+                                $wrap_return, 
+                                &message.message, 
+                                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
+                            )
+                        } else {
+                            rod_content_match!(
+                                &rod_attr.content, 
+                                $field_access, 
+                                $wrap_return, 
+                                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
+                            )
+                        }
+                    }
+                    // A delegated validator can stand on its own, without a type attribute
+                    None if with_opt.is_some() => quote! {},
+                    None if coerce_opt.is_some() => {
+                        abort!(
+                            attr.span(), "`coerce` needs the type to parse field `{}` to", $field_access;
+                            help = "Add its rules next to `coerce`, e.g. `#[rod(coerce(i32), i32 {{ size: 1..=10 }})]`"
+                        );
+                    }
+                    None if rename_opt.is_some() => {
+                        abort!(
+                            attr.span(), "`rename` needs the type of field `{}`", $field_access;
+                            help = "Add it next to `rename`, e.g. `#[rod(rename = \"homeAddress\", Address)]` for a type that implements `RodValidate`"
+                        );
                     }
                     None => {
                         abort!(
                             attr.span(), "Failed to parse attribute",
                         );
                     }
+                };
+                let ty = &$field.ty;
+                let field_type = match type_is_nested_reference(ty) {
+                    IsNestedReference::None => quote! {
+                        &#ty
+                    },
+                    IsNestedReference::Single => quote! {
+                        #ty
+                    },
+                    IsNestedReference::More => unreachable!(), // This should have been caught earlier
+                };
+                // Checks run in the order they are written
+                let check = checks.into_iter().map(|check| {
+                    if skipped {
+                        abort!(
+                            check.span, "Cannot use `{}` with `skip` attribute on field `{}`", check.name(), $field_access;
+                            help = "Remove the `{}` attribute", check.name()
+                        );
+                    }
+                    let closure = &check.closure;
+                    let path = field_path($field_access);
+                    let ret = if let Some(msg) = check.message.as_ref().or(message_opt.as_ref().map(|message| &message.message)) {
+                        user_defined_error($wrap_return, msg)
+                    } else {
+                        $wrap_return(quote! { RodValidateError::CheckFailed(#path) })
+                    };
+                    let field_access = $field_access;
+                    let (signature, call) = if check.receives_self {
+                        (quote!(fn(&Self, #field_type) -> bool), quote!(check(self, #field_access)))
+                    } else {
+                        (quote!(fn(#field_type) -> bool), quote!(check(#field_access)))
+                    };
+                    let failed = if check.negated {
+                        call
+                    } else {
+                        quote!(!#call)
+                    };
+                    quote! {
+                        {
+                            let check: #signature = #closure;
+                            if #failed {
+                                #ret;
+                            }
+                        }
+                    }
+                }).collect::<proc_macro2::TokenStream>();
+                let with = with_opt.map(|with| {
+                    if skipped {
+                        abort!(
+                            with.span, "Cannot use `with` with `skip` attribute on field `{}`", $field_access;
+                            help = "Remove the `with` attribute"
+                        );
+                    }
+                    let path = &with.path;
+                    let ret = if let Some(message) = message_opt.as_ref() {
+                        user_defined_error($wrap_return, &message.message)
+                    } else {
+                        $wrap_return(quote! { e })
+                    };
+                    let field_access = $field_access;
+                    quote! {
+                        {
+                            let with: fn(#field_type) -> Result<(), RodValidateError> = #path;
+                            if let Err(e) = with(#field_access) {
+                                #ret;
+                            }
+                        }
+                    }
+                });
+                let validations = quote! {
+                    #check
+                    #with
+                    #validations_for_field
+                };
+                let validations = match message_fn_opt {
+                    Some(message_fn) => {
+                        if skipped {
+                            abort!(
+                                message_fn.span, "Cannot use `message_fn` with `skip` attribute on field `{}`", $field_access;
+                                help = "Remove the `message_fn` attribute"
+                            );
+                        }
+                        message_fn.wrap(validations, &field_type, $field_access)
+                    }
+                    None => validations,
+                };
+                let validations = match message_key_opt {
+                    Some(message_key) => {
+                        if skipped {
+                            abort!(
+                                message_key.span, "Cannot use `message_key` with `skip` attribute on field `{}`", $field_access;
+                                help = "Remove the `message_key` attribute"
+                            );
+                        }
+                        message_key.wrap(validations)
+                    }
+                    None => validations,
+                };
+                let validations = match sensitive_opt {
+                    Some(span) => {
+                        if skipped {
+                            abort!(
+                                span, "Cannot use `sensitive` with `skip` attribute on field `{}`", $field_access;
+                                help = "Remove the `sensitive` attribute"
+                            );
+                        }
+                        quote! {
+                            {
+                                #[allow(unused_variables)]
+                                let rod_wrap_error = |error: RodValidateError| -> RodValidateError {
+                                    rod_wrap_error(error.redacted())
+                                };
+                                #validations
+                            }
+                        }
+                    }
+                    None => validations,
+                };
+                let validations = match skip_if_opt {
+                    Some(skip_if) => {
+                        if skipped {
+                            abort!(
+                                skip_if.span, "Cannot use `skip_if` with `skip` attribute on field `{}`", $field_access;
+                                help = "Remove the `skip_if` attribute"
+                            );
+                        }
+                        skip_if.guard(validations)
+                    }
+                    None => validations,
+                };
+                if let Some(option) = self_free && mentions_self(validations.clone()) {
+                    abort!(
+                        attr.span(), "The rules of field `{}` refer to `self`, which `{}` does not have", $field_access, option;
+                        help = "Move the rules that use `self` to a struct-level `check = |s: &Self| ...`"
+                    );
+                }
+                if !$tagged {
+                    Some(validations)
+                } else {
+                    // Untagged rules do not belong to any category, so they are skipped when filtering by tags
+                    tags_opt.map(|tags| {
+                        let tags = &tags.tags;
+                        quote! {
+                            if rod_tags.is_some_and(|rod_tags| [#(#tags),*].iter().any(|tag| rod_tags.contains(tag))) {
+                                #validations
+                            }
+                        }
+                    })
+                }
+            } else {
+                None
+            }
+        })
+    };
+}
+
+macro_rules! check_valid_rod_type {
+    ($ty:expr, $span:expr, $field_name:expr) => {
+        if !implements_rod_validate(&$ty) && let Some(suggestion) = suggest_rod_attr(&$ty) {
+            let valid_type = get_type(&$ty).unwrap();
+            abort!(
+                $span,
+                "Field `{}` has no `#[rod(...)]` attribute, but its type `{}` does not implement `RodValidate`.",
+                $field_name.as_ref().unwrap(), valid_type;
+                help = "If you want to validate this field, add a `#[rod({})]` attribute to it and fill in the rules.\nIf you want to skip validation, use `#[rod(Skip)]`.",
+                suggestion
+            )
+        }
+    };
+}
+
+/// Binds the path of a field of the type itself, which is its name, or the name given with `rename`. Paths of nested values are built from it.
+fn bind_top_level_path(field_name: &Ident, field: &syn::Field) -> proc_macro2::TokenStream {
+    let name = external_name(field_name, field);
+    bind_field_path(field_name, quote!(String::from(#name)))
+}
+
+/// With `filtered` set, runs `validations` only if `rod_field`, the path given to `validate_field`, is the path of the field or a path nested in it.
+fn filter_field(filtered: bool, validations: proc_macro2::TokenStream, field_name: &Ident, field: &syn::Field) -> proc_macro2::TokenStream {
+    if !filtered || validations.is_empty() {
+        return validations;
+    }
+    let name = external_name(field_name, field);
+    quote! {
+        if rod_field.strip_prefix(#name).is_some_and(|rest| rest.is_empty() || rest.starts_with('.') || rest.starts_with('[')) {
+            #validations
+        }
+    }
+}
+
+/// Returns the name of the field in errors: the name given with `rename`, or else `field_name`.
+pub(crate) fn external_name(field_name: &Ident, field: &syn::Field) -> String {
+    field.attrs.iter()
+        .filter(|attr| attr.path().is_ident("rod"))
+        .filter_map(|attr| attr.parse_args_with(syn::punctuated::Punctuated::<RodExpr, syn::Token![,]>::parse_terminated).ok())
+        .flatten()
+        .find_map(|expr| match expr {
+            RodExpr::Rename(rename) => Some(rename.name.value()),
+            _ => None,
+        })
+        .unwrap_or_else(|| field_name.unraw().to_string())
+}
+
+/// Returns `true` if the variant is marked `#[rod(skip)]`, so none of its fields are validated.
+/// Rules for the fields of a variant go on the fields themselves, so any other `#[rod(...)]` on a variant aborts.
+fn variant_is_skipped(variant: &syn::Variant) -> bool {
+    let mut skipped = false;
+    for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("rod")) {
+        match attr.parse_args::<Ident>() {
+            Ok(ident) if ident == "skip" || ident == "Skip" => skipped = true,
+            _ => abort!(
+                attr.span(), "Expected `#[rod(skip)]` on variant `{}`", variant.ident;
+                help = "Rules for the fields of a variant go on the fields themselves, e.g. `{}(#[rod(String {{ length: 1..=10 }})] String)`", variant.ident
+            ),
+        }
+    }
+    skipped
+}
+
+/// Types that are validated with `Iterable` rather than by implementing `RodValidate`.
+const ITERABLE_TYPES: [&str; 6] = ["Vec", "VecDeque", "HashSet", "BTreeSet", "LinkedList", "BinaryHeap"];
+
+/// Containers and pointers that implement `RodValidate` when the values they hold do.
+const CONTAINER_TYPES: [&str; 6] = ["Option", "Box", "Rc", "Arc", "Vec", "HashMap"];
+
+/// Types that implement `RodValidate` without any rules, so they are always valid.
+pub(crate) const NO_OP_TYPES: [&str; 22] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    "f32", "f64", "bool", "char", "String", "str", "OsString", "OsStr", "PathBuf", "Path",
+];
+
+/// Returns the type of the values of a container, e.g. `Address` for `Vec<Address>`, `[Address; 2]` or `HashMap<String, Address>`.
+pub(crate) fn container_value_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            if !CONTAINER_TYPES.contains(&segment.ident.to_string().as_str()) {
+                return None;
+            }
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            // The values of a `HashMap` are its last argument
+            args.args.iter().filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }).last()
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` for types that implement `RodValidate`, so fields of these types need no `#[rod(...)]` attribute:
+/// custom types, primitives and strings, which are always valid, and containers like `Vec<T>` or `Option<T>` of such types.
+/// Trait objects are assumed to have `RodValidate` as a supertrait, so `Vec<Box<dyn Shape>>` is validated through dynamic dispatch.
+pub(crate) fn implements_rod_validate(ty: &Type) -> bool {
+    match ty {
+        Type::TraitObject(_) => true,
+        Type::Reference(type_ref) => implements_rod_validate(&type_ref.elem),
+        Type::Paren(paren) => implements_rod_validate(&paren.elem),
+        Type::Group(group) => implements_rod_validate(&group.elem),
+        Type::Array(_) | Type::Slice(_) => container_value_type(ty).is_some_and(implements_rod_validate),
+        Type::Path(type_path) => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return false;
+            };
+            let ident = segment.ident.to_string();
+            if NO_OP_TYPES.contains(&ident.as_str()) {
+                true
+            } else if CONTAINER_TYPES.contains(&ident.as_str()) {
+                container_value_type(ty).is_some_and(implements_rod_validate)
+            } else {
+                !ITERABLE_TYPES.contains(&ident.as_str()) && !RodAttrType::type_is_valid_rod_type(ty)
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` for types that have nothing to validate, so fields of these types without a `#[rod(...)]` attribute are skipped:
+/// `PhantomData`, function pointers, and `Fn`, `FnMut` or `FnOnce` trait objects behind a reference, `Box`, `Rc` or `Arc`.
+fn is_auto_skipped(ty: &Type) -> bool {
+    match ty {
+        Type::BareFn(_) => true,
+        Type::Reference(type_ref) => is_auto_skipped(&type_ref.elem),
+        Type::Paren(paren) => is_auto_skipped(&paren.elem),
+        Type::Group(group) => is_auto_skipped(&group.elem),
+        Type::TraitObject(trait_object) => trait_object.bounds.iter().any(|bound| match bound {
+            syn::TypeParamBound::Trait(trait_bound) => trait_bound
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Fn" || segment.ident == "FnMut" || segment.ident == "FnOnce"),
+            _ => false,
+        }),
+        Type::Path(type_path) => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return false;
+            };
+            if segment.ident == "PhantomData" {
+                return true;
+            }
+            if segment.ident != "Box" && segment.ident != "Rc" && segment.ident != "Arc" {
+                return false;
+            }
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+                    syn::GenericArgument::Type(ty @ (Type::TraitObject(_) | Type::BareFn(_))) => is_auto_skipped(ty),
+                    _ => false,
+                }),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Suggests the `#[rod(...)]` attribute for a field without one, e.g. `Option { i32 }` for `Option<i32>`.
+/// Returns `None` for custom types, which are expected to implement `RodValidate` themselves.
+fn suggest_rod_attr(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(type_ref) => suggest_rod_attr(&type_ref.elem),
+        Type::Tuple(tuple) => {
+            let elems = tuple.elems.iter().map(suggest_rod_item).collect::<Vec<_>>();
+            Some(format!("Tuple ({})", elems.join(", ")))
+        }
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            let inner = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                }),
+                _ => None,
+            };
+            let ident = segment.ident.to_string();
+            match inner {
+                Some(inner) if ident == "Option" => Some(format!("Option {{ {} }}", suggest_rod_item(inner))),
+                Some(inner) if ITERABLE_TYPES.contains(&ident.as_str()) => Some(format!("Iterable {{ item: {} }}", suggest_rod_item(inner))),
+                _ if RodAttrType::type_is_valid_rod_type(ty) => Some(ident),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Like [`suggest_rod_attr`], but custom types are named as they are, since they can appear inside other Rod types.
+fn suggest_rod_item(ty: &Type) -> String {
+    suggest_rod_attr(ty).or_else(|| get_type(ty).map(|ty| ty.to_string())).unwrap_or_else(|| String::from("Skip"))
+}
+
+/// Generates the body of `suggest_fixes` for a single field.
+/// Only `size` ranges of numbers and `length` ranges of strings have automatic fixes; nested types are asked for theirs,
+/// and their paths are prefixed with the path of the field. `sensitive` fields have none, since a fix is derived from the value.
+fn get_field_fixes(field_access: &Ident, field: &syn::Field) -> Option<proc_macro2::TokenStream> {
+    let path = external_name(field_access, field);
+    let nested = quote! {
+        fixes.extend(RodValidate::suggest_fixes(#field_access).into_iter().map(|fix| fix.with_path_prefix(#path)));
+    };
+    let mut rod_attr = None;
+    let mut skip_if = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("rod")) {
+        let Ok(exprlist) = attr.parse_args_with(syn::punctuated::Punctuated::<RodExpr, syn::Token![,]>::parse_terminated) else {
+            continue;
+        };
+        for expr in exprlist {
+            match expr {
+                RodExpr::Attribute(attr) => rod_attr = rod_attr.or(Some(*attr)),
+                RodExpr::SkipIf(attr) => skip_if = skip_if.or(Some(attr)),
+                // The fixes of the rules are for the coerced type, not for the string
+                RodExpr::Coerce(_) => return None,
+                RodExpr::Sensitive(_) => return None,
+                _ => {}
+            }
+        }
+    }
+    let fixes = match rod_attr.map(|rod_attr| rod_attr.content) {
+        None if field.attrs.is_empty() && !is_auto_skipped(&field.ty) => Some(nested),
+        Some(RodAttrContent::String(content)) => content.get_fixes(field_access, &path),
+        Some(RodAttrContent::Integer(content)) => content.get_fixes(field_access, &path),
+        Some(RodAttrContent::Float(content)) => content.get_fixes(field_access, &path),
+        Some(RodAttrContent::Custom(content)) if content.is_nested() => Some(nested),
+        _ => None,
+    }?;
+    match skip_if {
+        Some(skip_if) => Some(skip_if.guard(fixes)),
+        None => Some(fixes),
+    }
+}
+
+/// Names of the methods of `RodValidate` that a `validate_<field>` function would hide when called as `Type::validate_<field>`.
+const RESERVED_FIELD_VALIDATORS: [&str; 7] = ["validate_all", "validate_all_limited", "validate_report", "validate_tagged", "validate_all_tagged", "validate_field", "validate_sanitized"];
+
+/// Generates the `validate_<field>(value)` functions of `#[rod(field_validators)]`, which run the rules of a field on a value on its own.
+/// Rules that refer to `self`, like `check_self` or `eq_field`, need the whole struct, so they are left out.
+/// `preamble` declares what the validations refer to, like `rod_wrap_error`, as in the methods of `RodValidate`.
+fn get_field_validators(ast: &DeriveInput, preamble: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let fields = named_fields(&ast.data, "field_validators");
+    let functions = fields.iter().filter_map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let validations = get_first_error_validations(field_name, field, Some("field_validators"));
+        if validations.is_empty() {
+            return None;
+        }
+        let function = format_ident!("validate_{}", field_name.unraw());
+        if RESERVED_FIELD_VALIDATORS.contains(&function.to_string().as_str()) {
+            abort!(
+                field_name.span(), "The validator of field `{}` would be named `{}`, like a method of `RodValidate`", field_name, function;
+                help = "Remove `field_validators`, or rename the field"
+            );
+        }
+        let ty = &field.ty;
+        let value_type = match type_is_nested_reference(ty) {
+            IsNestedReference::None => quote!(&#ty),
+            _ => quote!(#ty),
+        };
+        let vis = &field.vis;
+        let bind_path = bind_top_level_path(field_name, field);
+        let doc = format!("Validates `value` with the rules of the field `{}`, returning the first error.", field_name.unraw());
+        Some(quote! {
+            #[doc = #doc]
+            #vis fn #function(value: #value_type) -> Result<(), RodValidateError> {
+                #preamble
+                #[allow(unused_variables)]
+                let #field_name = value;
+                #bind_path
+                #validations
+                Ok(())
+            }
+        })
+    });
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#functions)*
+        }
+    }
+}
+
+/// Generates the `set_<field>(value)` methods of `#[rod(setters)]`, which run the rules of a field on a new value before assigning it.
+/// Rules that refer to `self`, like `eq_field`, compare the new value to the other fields as they are.
+fn get_setters(ast: &DeriveInput, preamble: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let fields = named_fields(&ast.data, "setters");
+    let functions = fields.iter().filter_map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let validations = get_first_error_validations(field_name, field, None);
+        if validations.is_empty() {
+            return None;
+        }
+        let function = format_ident!("set_{}", field_name.unraw());
+        let ty = &field.ty;
+        let binding = match type_is_nested_reference(ty) {
+            IsNestedReference::None => quote!(&rod_value),
+            _ => quote!(rod_value),
+        };
+        let vis = &field.vis;
+        let bind_path = bind_top_level_path(field_name, field);
+        let doc = format!("Sets the field `{}` to `value` if it passes the rules of the field, returning the first error otherwise.", field_name.unraw());
+        Some(quote! {
+            #[doc = #doc]
+            #vis fn #function(&mut self, rod_value: #ty) -> Result<(), RodValidateError> {
+                {
+                    #preamble
+                    #[allow(unused_variables)]
+                    let #field_name = #binding;
+                    #bind_path
+                    #validations
+                }
+                self.#field_name = rod_value;
+                Ok(())
+            }
+        })
+    });
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#functions)*
+        }
+    }
+}
+
+/// Returns the named fields of a struct, aborting for other types since `option` needs the names of the fields.
+fn named_fields<'a>(data: &'a Data, option: &str) -> &'a syn::punctuated::Punctuated<syn::Field, syn::Token![,]> {
+    match data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(fields_named), .. }) => &fields_named.named,
+        _ => abort!(
+            proc_macro2::Span::call_site(), "`{}` is only supported on structs with named fields", option
+        ),
+    }
+}
+
+/// Generates the rules of a field with a `#[rod(...)]` attribute that return the first error, as in `validate`.
+/// Fields without one have no rules of their own, so they have none. `self_free` is as in `get_field_validations!`.
+fn get_first_error_validations(field_name: &Ident, field: &syn::Field, self_free: Option<&str>) -> proc_macro2::TokenStream {
+    if field.attrs.is_empty() {
+        return quote! {};
+    }
+    let wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream = |ret| quote! {
+        return Err(rod_wrap_error(#ret));
+    };
+    get_field_validations!(
+        field_name,
+        field,
+        wrap_return,
+        false,
+        self_free
+    ).collect()
+}
+
+/// Generates the `new(field, ...)` function of `#[rod(constructor)]`, which validates the struct before returning it.
+fn get_constructor(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    let fields = named_fields(&ast.data, "constructor");
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let field_names = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty);
+    let doc = format!("Creates a `{}` from its fields, returning every error if it is invalid.", name);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc]
+            #[allow(clippy::too_many_arguments)]
+            #vis fn new(#( #field_names: #field_types ),*) -> Result<Self, RodValidateErrorList> {
+                let value = Self { #( #field_names ),* };
+                RodValidate::validate_all(&value)?;
+                Ok(value)
+            }
+        }
+    }
+}
+
+/// Generates the `new`, `into_inner`, `Deref` and `TryFrom` of `#[rod(newtype)]` for a struct with a single private field.
+/// The field must be private, or it could be changed to an invalid value without `new`.
+fn get_newtype(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let fields = match &ast.data {
+        Data::Struct(syn::DataStruct { fields, .. }) => fields,
+        _ => abort!(proc_macro2::Span::call_site(), "`newtype` is only supported on structs"),
+    };
+    if fields.len() != 1 {
+        abort!(
+            fields.span(), "`newtype` requires a struct with exactly one field, found {}", fields.len();
+            help = "Example: `#[rod(newtype)] struct Email(#[rod(String {{ format: Email }})] String);`"
+        );
+    }
+    let field = fields.iter().next().unwrap();
+    if !matches!(field.vis, syn::Visibility::Inherited) {
+        abort!(
+            field.vis.span(), "The field of a `newtype` must be private";
+            help = "Remove the visibility, so the field can only be set through `new`"
+        );
+    }
+    let inner = &field.ty;
+    let (construct, access) = match &field.ident {
+        Some(field_name) => (quote! { Self { #field_name: value } }, quote! { #field_name }),
+        None => (quote! { Self(value) }, quote! { 0 }),
+    };
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let new_doc = format!("Creates a `{}` from its value, returning every error if it is invalid.", name);
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #new_doc]
+            #vis fn new(value: #inner) -> Result<Self, RodValidateErrorList> {
+                let value = #construct;
+                RodValidate::validate_all(&value)?;
+                Ok(value)
+            }
+
+            /// Returns the value, which is no longer checked once it changes.
+            #vis fn into_inner(self) -> #inner {
+                self.#access
+            }
+        }
+
+        impl #impl_generics std::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#access
+            }
+        }
+
+        impl #impl_generics TryFrom<#inner> for #name #ty_generics #where_clause {
+            type Error = RodValidateErrorList;
+
+            fn try_from(value: #inner) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+    }
+}
+
+/// Generates the `<Name>Patch` struct of `#[rod(patch)]`, with every field wrapped in an `Option`, and its `RodValidate` implementation,
+/// which runs the rules of each field only if it is present. Rules that depend on other fields are left out,
+/// since the patch may not have them. `preamble` and `all_preamble` declare what the validations refer to in `validate` and `validate_all`.
+fn get_patch(ast: &DeriveInput, derives: &[syn::Path], preamble: &proc_macro2::TokenStream, all_preamble: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let fields = named_fields(&ast.data, "patch");
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let patch = format_ident!("{}Patch", name);
+    let get_validations = |wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream| -> proc_macro2::TokenStream {
+        fields.iter().map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let validations = if field.attrs.is_empty() && is_auto_skipped(&field.ty) {
+                return quote! {};
+            } else if field.attrs.is_empty() {
+                let path = field_path(field_name);
+                let ret = wrap_return(quote! { e.with_path_prefix(&#path) });
+                quote! {
+                    let assert = assert_impl_rod_validate(#field_name, rod_tags, &mut rod_warnings, rod_max_errors);
+                    if let Err(errs) = assert {
+                        for e in errs {
+                            #ret;
+                        }
+                    }
+                }
+            } else {
+                let validations: proc_macro2::TokenStream = get_field_validations!(
+                    field_name,
+                    field,
+                    wrap_return,
+                    false,
+                    Some("patch")
+                ).collect();
+                if validations.is_empty() {
+                    return quote! {};
+                }
+                validations
+            };
+            let bind_path = bind_top_level_path(field_name, field);
+            let binding = match type_is_nested_reference(&field.ty) {
+                IsNestedReference::None => quote!(&self.#field_name),
+                _ => quote!(self.#field_name),
+            };
+            quote! {
+                if let Some(#field_name) = #binding {
+                    #bind_path
+                    #validations
+                }
+            }
+        }).collect()
+    };
+    let validations = get_validations(|ret| quote! {
+        return Err(rod_wrap_error(#ret));
+    });
+    let all_validations = get_validations(|ret| quote! {
+        errors.push(rod_wrap_error(#ret));
+    });
+    let field_names = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty);
+    let field_vis = fields.iter().map(|field| &field.vis);
+    let derives = (!derives.is_empty()).then(|| quote!(#[derive(#(#derives),*)]));
+    let doc = format!("A partial update of [`{}`], where the fields that are `None` are left unchanged.", name);
+    let apply_doc = format!("Sets the fields of `target` that are present in the patch. The `{}` is not validated again.", name);
+    let generics = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        #[doc = #doc]
+        #derives
+        #vis struct #patch #generics #where_clause {
+            #( #field_vis #field_names: Option<#field_types>, )*
+        }
+
+        impl #impl_generics Default for #patch #ty_generics #where_clause {
+            fn default() -> Self {
+                #patch {
+                    #( #field_names: None, )*
+                }
+            }
+        }
+
+        impl #impl_generics #patch #ty_generics #where_clause {
+            #[doc = #apply_doc]
+            #vis fn apply(self, target: &mut #name #ty_generics) {
+                #(
+                    if let Some(value) = self.#field_names {
+                        target.#field_names = value;
+                    }
+                )*
+            }
+        }
+
+        impl #impl_generics RodValidate for #patch #ty_generics #where_clause {
+            fn validate(&self) -> Result<(), RodValidateError> {
+                #preamble
+                #validations
+                Ok(())
+            }
+            fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+                #all_preamble
+                let mut errors = RodValidateErrorList::new();
+                #all_validations
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
                 }
-            } else {
-                None
             }
-        })
-    };
+        }
+    }
 }
 
-macro_rules! check_valid_rod_type {
-    ($ty:expr, $span:expr, $field_name:expr) => {
-        if RodAttrType::type_is_valid_rod_type(&$ty) {
-            let valid_type = get_type(&$ty).unwrap();
-            emit_warning!(
-                $span,
-                "Field `{}` has no `#[rod(...)]` attribute, however it is of type `{}` which is a valid Rod type.",
-                $field_name.as_ref().unwrap(), valid_type;
-                help = "If you want to validate this field, add a `#[rod({}{{...}})]` attribute to it.\nIf you want to skip validation, use `#[rod(Skip)]`.",
-                valid_type
-            )
+/// Removes the comparisons to other fields, like `le_field`, from the rules of `rod_attr` and the rules nested in it.
+pub(crate) fn strip_field_comparisons(rod_attr: &mut RodAttr) {
+    match &mut rod_attr.content {
+        RodAttrContent::Integer(content) => content.strip_field_comparisons(),
+        RodAttrContent::Float(content) => content.strip_field_comparisons(),
+        RodAttrContent::Option(content) => content.strip_field_comparisons(),
+        RodAttrContent::Iterable(content) => content.strip_field_comparisons(),
+        RodAttrContent::Tuple(content) => content.strip_field_comparisons(),
+        _ => {}
+    }
+}
+
+/// Returns the first comparison to another field, like `eq_field`, in the rules of `rod_attr` or the rules nested in it.
+pub(crate) fn find_field_comparison(rod_attr: &RodAttr) -> Option<&types::FieldComparison> {
+    match &rod_attr.content {
+        RodAttrContent::Integer(content) => content.field_comparison(),
+        RodAttrContent::Float(content) => content.field_comparison(),
+        RodAttrContent::String(content) => content.field_comparison(),
+        RodAttrContent::Custom(content) => content.field_comparison(),
+        RodAttrContent::Option(content) => content.field_comparison(),
+        RodAttrContent::Iterable(content) => content.field_comparison(),
+        RodAttrContent::Tuple(content) => content.field_comparison(),
+        _ => None,
+    }
+}
+
+/// Aborts if a rule of `field`, which belongs to `owner`, compares it to another field, like `eq_field`.
+/// The other field is read through `self`, so comparisons only work on structs with named fields, not on tuple structs or enum variants.
+fn reject_field_comparisons(field: &syn::Field, owner: &str) {
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("rod")) {
+        // Malformed attributes are reported when the field's validations are generated
+        let Ok(exprlist) = attr.parse_args_with(syn::punctuated::Punctuated::<RodExpr, syn::Token![,]>::parse_terminated) else {
+            continue;
+        };
+        for expr in exprlist {
+            if let RodExpr::Attribute(rod_attr) = expr && let Some(comparison) = find_field_comparison(&rod_attr) {
+                abort!(
+                    comparison.rule().span(), "`{}` can only be used on structs with named fields, not on the fields of {}", comparison.rule(), owner;
+                    help = "Compare the fields with a container-level `check = |s: &Self| ...` instead"
+                );
+            }
         }
-    };
+    }
+}
+
+/// Returns `true` if `tokens` use `self`, e.g. to compare the field to another field.
+fn mentions_self(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => ident == "self",
+        proc_macro2::TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
+
+/// Generates the body of `sanitize` for a single field, bound to `field_access` as a mutable reference.
+/// Only `String` fields have transforms of their own; nested types are asked to sanitize themselves. `skip_if` does not apply.
+fn get_field_sanitize(field_access: &Ident, field: &syn::Field) -> Option<proc_macro2::TokenStream> {
+    // Borrowed values cannot be changed
+    let nested = (type_is_nested_reference(&field.ty) == IsNestedReference::None).then(|| quote! {
+        RodValidate::sanitize(#field_access);
+    });
+    let rod_attr = field.attrs.iter()
+        .filter(|attr| attr.path().is_ident("rod"))
+        .filter_map(|attr| attr.parse_args_with(syn::punctuated::Punctuated::<RodExpr, syn::Token![,]>::parse_terminated).ok())
+        .flatten()
+        .find_map(|expr| match expr {
+            RodExpr::Attribute(rod_attr) => Some(*rod_attr),
+            _ => None,
+        });
+    match rod_attr {
+        None if field.attrs.is_empty() && !is_auto_skipped(&field.ty) => nested,
+        Some(RodAttr { ty, content: RodAttrContent::String(content), span }) => {
+            let sanitize = content.get_sanitize(field_access)?;
+            if let RodAttrType::String(string_type) = &ty && !matches!(string_type, TypeEnum::Type(ident) if ident == "String") {
+                abort!(
+                    span, "Transforms like `trim` need an owned `String` field, but `{}` is a `{}`", field_access, string_type;
+                    help = "Change the type of the field to `String`, or remove the transforms"
+                );
+            }
+            Some(sanitize)
+        }
+        Some(RodAttr { content: RodAttrContent::Custom(content), .. }) if content.is_nested() => nested,
+        _ => None,
+    }
 }
 
 /// Derives the `RodValidate` trait for a struct.
 ///
 /// Implements validation logic for struct fields annotated with `#[rod(...)]`.
-/// Fields without the attribute are required to implement `RodValidate`.
+/// Fields without the attribute are required to implement `RodValidate`, as containers like `Vec<T>` or `Option<T>` of such types do,
+/// except `PhantomData`, function pointers and boxed or borrowed `Fn` trait objects, which have nothing to validate and are skipped.
+/// Primitives and strings implement it without any rules, so they are not constrained.
 /// Many standard types are supported, including [`RodStringContent`][crate::types::RodStringContent], [`RodIntegerContent`][crate::types::RodIntegerContent], [`RodLiteralContent`][crate::types::RodLiteralContent], [`RodBooleanContent`][crate::types::RodBooleanContent], and [`RodOptionContent`][crate::types::RodOptionContent].
 /// To see the available attributes, refer to the documentation for each type.
 /// # Examples
@@ -761,31 +2388,137 @@ macro_rules! check_valid_rod_type {
 /// };
 /// assert!(entity.validate().is_ok());
 /// ```
+/// `fail_if` is the inverse of `check`: validation fails when the closure returns `true`.
+/// Both can be used on the same field, any number of times, and run in the order they are written.
+/// ```
+/// use rod_validation::prelude::*;
+/// #[derive(RodValidate)]
+/// struct MyEntity {
+///     #[rod(
+///         String {
+///             length: 3..=16,
+///         },
+///         fail_if = |s| ["admin", "root"].contains(&s.as_str())
+///     )]
+///     username: String,
+/// }
+/// assert!(MyEntity { username: "alice".to_string() }.validate().is_ok());
+/// assert!(MyEntity { username: "root".to_string() }.validate().is_err());
+/// ```
+/// # Error Hooks
+/// A `wrap_error` function placed on the type itself is called on every error before it is returned or collected.
+/// Use it to attach tracing events or metrics, or to replace errors.
+/// ```
+/// use rod::prelude::*;
+/// fn log_error(error: RodValidateError) -> RodValidateError {
+///     eprintln!("validation failed: {}", error);
+///     error
+/// }
+/// #[derive(RodValidate)]
+/// #[rod(wrap_error = log_error)]
+/// struct MyEntity {
+///     #[rod(String { length: 5..=10 })]
+///     my_string: String,
+/// }
+/// ```
 #[proc_macro_error]
 #[proc_macro_derive(RodValidate, attributes(rod))]
 pub fn derive_rod_validate(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let name = &ast.ident;
+    match &ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Unnamed(fields_unnamed), .. }) => {
+            let owner = format!("tuple struct `{}`", name);
+            fields_unnamed.unnamed.iter().for_each(|field| reject_field_comparisons(field, &owner));
+        }
+        Data::Enum(data_enum) => {
+            for variant in data_enum.variants.iter() {
+                let owner = format!("variant `{}`", variant.ident);
+                variant.fields.iter().for_each(|field| reject_field_comparisons(field, &owner));
+            }
+        }
+        _ => {}
+    }
+
+    // Fields of tuple structs and tuple variants are bound to `field_0`, `field_1`, ..., which are also their paths
+    let unnamed_validations = |fields_unnamed: &syn::FieldsUnnamed,
+                               wrap_validations: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+                               tagged: bool,
+                               filtered: bool,
+                               owner: &str|
+     -> (Vec<Ident>, proc_macro2::TokenStream) {
+        let field_count = fields_unnamed.unnamed.len();
+        let field_idents: Vec<syn::Ident> = (0..field_count)
+            .map(|i| syn::Ident::new(&format!("field_{}", i), proc_macro2::Span::call_site()))
+            .collect();
+        let validations = fields_unnamed.unnamed.iter().enumerate().map(|(idx, field)| {
+            let field_ident = field_idents.get(idx);
+            if type_is_nested_reference(&field.ty) == IsNestedReference::More {
+                abort!(
+                    field.ty.span(), "Field {} of {} is a reference to a reference, which is not supported.", idx, owner;
+                    help = "Use a single reference instead, e.g. `&T` instead of `&&T`."
+                )
+            }
+            let bind_path = bind_top_level_path(field_ident.unwrap(), field);
+            if field.attrs.is_empty() && is_auto_skipped(&field.ty) {
+                quote! {}
+            } else if field.attrs.is_empty() {
+                check_valid_rod_type!(field.ty, field.ty.span(), field_ident);
+                let path = field_path(field_ident.unwrap());
+                let ret = wrap_validations(quote! { e.with_path_prefix(&#path) });
+                quote! {
+                    #bind_path
+                    let assert = assert_impl_rod_validate(#field_ident, rod_tags, &mut rod_warnings, rod_max_errors);
+                    if let Err(errs) = assert {
+                        for e in errs {
+                            #ret;
+                        }
+                    }
+                }
+            } else {
+                let validations: proc_macro2::TokenStream = get_field_validations!(
+                    field_ident.as_ref().unwrap(),
+                    field,
+                    wrap_validations,
+                    tagged
+                ).collect();
+                quote! {
+                    #bind_path
+                    #validations
+                }
+            }
+        }).zip(fields_unnamed.unnamed.iter().zip(field_idents.iter())).map(|(validations, (field, field_ident))| {
+            filter_field(filtered, validations, field_ident, field)
+        }).collect();
+        (field_idents, validations)
+    };
 
     let get_validations = |wrap_validations: fn(
         proc_macro2::TokenStream,
-    ) -> proc_macro2::TokenStream|
+    ) -> proc_macro2::TokenStream,
+                           tagged: bool,
+                           filtered: bool|
      -> proc_macro2::TokenStream {
         match &ast.data {
-            Data::Struct(data_struct) => {
-                if let Fields::Named(fields_named) = &data_struct.fields {
+            Data::Struct(data_struct) => match &data_struct.fields {
+                Fields::Named(fields_named) => {
                     fields_named.named.iter().map(|field| {
                         let field_name = &field.ident;
                         // If no attributes are present, we assume it's a custom type that implements `RodValidate`
                         // If a custom type appears inside a Rod type, it has to be explicitly annotated with `#[rod(...CustomType...)]`
                         // The name of the custom type and the annotation must match
                         // Otherwise, the custom type can just have no #rod attribute
-                        if field.attrs.is_empty() {
+                        if field.attrs.is_empty() && is_auto_skipped(&field.ty) {
+                            quote! {}
+                        } else if field.attrs.is_empty() {
                             check_valid_rod_type!(field.ty, field.ty.span(), field_name);
-                            let ret = wrap_validations(quote! { e });
+                            let path = field_path(field_name.as_ref().unwrap());
+                            let ret = wrap_validations(quote! { e.with_path_prefix(&#path) });
+                            let bind_path = bind_top_level_path(field_name.as_ref().unwrap(), field);
                             quote! {
                                 let #field_name = &self.#field_name;
-                                let assert = assert_impl_rod_validate(#field_name);
+                                #bind_path
+                                let assert = assert_impl_rod_validate(#field_name, rod_tags, &mut rod_warnings, rod_max_errors);
                                 if let Err(errs) = assert {
                                     for e in errs {
                                         #ret;
@@ -796,15 +2529,23 @@ pub fn derive_rod_validate(input: TokenStream) -> TokenStream {
                             let validations: proc_macro2::TokenStream = get_field_validations!(
                                 field_name.as_ref().unwrap(),
                                 field,
-                                wrap_validations
+                                wrap_validations,
+                                tagged
                             ).collect();
+                            if validations.is_empty() {
+                                // e.g. untagged fields when filtering by tags
+                                return quote! {};
+                            }
+                            let bind_path = bind_top_level_path(field_name.as_ref().unwrap(), field);
                             match type_is_nested_reference(&field.ty) {
                                 IsNestedReference::None => quote! {
                                     let #field_name = &self.#field_name;
+                                    #bind_path
                                     #validations
                                 },
                                 IsNestedReference::Single => quote! {
                                     let #field_name = self.#field_name;
+                                    #bind_path
                                     #validations
                                 },
                                 IsNestedReference::More => {
@@ -818,14 +2559,28 @@ pub fn derive_rod_validate(input: TokenStream) -> TokenStream {
                                 }
                             }
                         }
+                    }).zip(fields_named.named.iter()).map(|(validations, field)| {
+                        filter_field(filtered, validations, field.ident.as_ref().unwrap(), field)
                     }).collect()
-                } else {
-                    unreachable!()
                 }
-            }
+                Fields::Unnamed(fields_unnamed) => {
+                    let (field_idents, validations) = unnamed_validations(fields_unnamed, wrap_validations, tagged, filtered, &format!("`{}`", name));
+                    quote! {
+                        #[allow(unused_variables)]
+                        let Self(#( #field_idents ),*) = self;
+                        #validations
+                    }
+                }
+                Fields::Unit => quote! {},
+            },
             Data::Enum(data_enum) => {
                 let match_arms = data_enum.variants.iter().map(|variant| {
                     let variant_ident = &variant.ident;
+                    if variant_is_skipped(variant) {
+                        return quote! {
+                            Self::#variant_ident { .. } => {}
+                        };
+                    }
                     match &variant.fields {
                         Fields::Named(fields_named) => {
                             let field_names = fields_named.named.iter().map(|f| f.ident.clone());
@@ -837,12 +2592,16 @@ pub fn derive_rod_validate(input: TokenStream) -> TokenStream {
                                         help = "Use a single reference instead, e.g. `&T` instead of `&&T`."
                                     )
                                 }
-                                if field.attrs.is_empty() {
+                                let bind_path = bind_top_level_path(field_name.as_ref().unwrap(), field);
+                                if field.attrs.is_empty() && is_auto_skipped(&field.ty) {
+                                    quote! {}
+                                } else if field.attrs.is_empty() {
                                     check_valid_rod_type!(field.ty, field.ty.span(), field_name);
-                                    let ret = wrap_validations(quote! { e });
+                                    let path = field_path(field_name.as_ref().unwrap());
+                                    let ret = wrap_validations(quote! { e.with_path_prefix(&#path) });
                                     quote! {
-                                        let #field_name = &self.#field_name;
-                                        let assert = assert_impl_rod_validate(#field_name);
+                                        #bind_path
+                                        let assert = assert_impl_rod_validate(#field_name, rod_tags, &mut rod_warnings, rod_max_errors);
                                         if let Err(errs) = assert {
                                             for e in errs {
                                                 #ret;
@@ -850,54 +2609,33 @@ pub fn derive_rod_validate(input: TokenStream) -> TokenStream {
                                         }
                                     }
                                 } else {
-                                    get_field_validations!(
+                                    let validations: proc_macro2::TokenStream = get_field_validations!(
                                         field_name.as_ref().unwrap(),
                                         field,
-                                        wrap_validations
-                                    ).collect()
+                                        wrap_validations,
+                                        tagged
+                                    ).collect();
+                                    quote! {
+                                        #bind_path
+                                        #validations
+                                    }
                                 }
+                            }).zip(fields_named.named.iter()).map(|(validations, field)| {
+                                filter_field(filtered, validations, field.ident.as_ref().unwrap(), field)
                             });
                             quote! {
+                                #[allow(unused_variables)]
                                 Self::#variant_ident { #( #field_names ),* } => {
                                     #(#validations_iter)*
                                 }
                             }
                         }
                         Fields::Unnamed(fields_unnamed) => {
-                            let field_count = fields_unnamed.unnamed.len();
-                            let field_idents: Vec<syn::Ident> = (0..field_count)
-                                .map(|i| syn::Ident::new(&format!("field_{}", i), proc_macro2::Span::call_site()))
-                                .collect();
-                            let validations_iter = fields_unnamed.unnamed.iter().enumerate().map(|(idx, field)| {
-                                let field_ident = field_idents.get(idx);
-                                if type_is_nested_reference(&field.ty) == IsNestedReference::More {
-                                    abort!(
-                                        field.ty.span(), "Field {} of variant `{}` is a reference to a reference, which is not supported.", idx, variant.ident;
-                                        help = "Use a single reference instead, e.g. `&T` instead of `&&T`."
-                                    )
-                                }
-                                if field.attrs.is_empty() {
-                                    check_valid_rod_type!(field.ty, field.ty.span(), field_ident);
-                                    let ret = wrap_validations(quote! { e });
-                                    quote! {
-                                        let assert = assert_impl_rod_validate(#field_ident);
-                                        if let Err(errs) = assert {
-                                            for e in errs {
-                                                #ret;
-                                            }
-                                        }
-                                    }
-                                } else {
-                                    get_field_validations!(
-                                        field_ident.as_ref().unwrap(),
-                                        field,
-                                        wrap_validations
-                                    ).collect()
-                                }
-                            });
+                            let (field_idents, validations) = unnamed_validations(fields_unnamed, wrap_validations, tagged, filtered, &format!("variant `{}`", variant_ident));
                             quote! {
+                                #[allow(unused_variables)]
                                 Self::#variant_ident(#( #field_idents ),*) => {
-                                    #(#validations_iter)*
+                                    #validations
                                 }
                             }
                         }
@@ -920,42 +2658,498 @@ pub fn derive_rod_validate(input: TokenStream) -> TokenStream {
 
     let validations = get_validations(|ret| {
         quote! {
-            return Err(#ret);
+            return Err(rod_wrap_error(#ret));
         }
-    });
+    }, false, false);
 
     let all_validations = get_validations(|ret| {
         quote! {
-            errors.push(#ret);
+            errors.push(rod_wrap_error(#ret));
+        }
+    }, false, false);
+
+    let limited_validations = get_validations(|ret| {
+        quote! {
+            if errors.len() >= rod_max_errors {
+                errors.set_truncated(true);
+                return Err(errors);
+            }
+            errors.push(rod_wrap_error(#ret));
+        }
+    }, false, false);
+
+    let fixes = match &ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => fields_named.named.iter().filter_map(|field| {
+                let field_name = field.ident.as_ref().unwrap();
+                let fixes = get_field_fixes(field_name, field)?;
+                let binding = match type_is_nested_reference(&field.ty) {
+                    // Reborrowed, since a `&mut` reference cannot be moved out of `self`
+                    IsNestedReference::Single => quote! { &*self.#field_name },
+                    _ => quote! { &self.#field_name },
+                };
+                Some(quote! {
+                    {
+                        let #field_name = #binding;
+                        #fixes
+                    }
+                })
+            }).collect(),
+            Fields::Unnamed(fields_unnamed) => fields_unnamed.unnamed.iter().enumerate().filter_map(|(index, field)| {
+                let field_ident = format_ident!("field_{}", index);
+                let fixes = get_field_fixes(&field_ident, field)?;
+                let index = syn::Index::from(index);
+                let binding = match type_is_nested_reference(&field.ty) {
+                    IsNestedReference::Single => quote! { &*self.#index },
+                    _ => quote! { &self.#index },
+                };
+                Some(quote! {
+                    {
+                        let #field_ident = #binding;
+                        #fixes
+                    }
+                })
+            }).collect(),
+            Fields::Unit => quote! {},
+        },
+        Data::Enum(data_enum) => {
+            let match_arms = data_enum.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                if variant_is_skipped(variant) {
+                    return quote! {
+                        Self::#variant_ident { .. } => {}
+                    };
+                }
+                match &variant.fields {
+                    Fields::Named(fields_named) => {
+                        let (field_names, field_fixes): (Vec<_>, Vec<_>) = fields_named.named.iter().filter_map(|field| {
+                            let field_name = field.ident.as_ref().unwrap();
+                            Some((field_name, get_field_fixes(field_name, field)?))
+                        }).unzip();
+                        quote! {
+                            Self::#variant_ident { #( #field_names, )* .. } => {
+                                #( #field_fixes )*
+                            }
+                        }
+                    }
+                    Fields::Unnamed(fields_unnamed) => {
+                        let mut field_fixes = Vec::new();
+                        let patterns = fields_unnamed.unnamed.iter().enumerate().map(|(idx, field)| {
+                            let field_ident = syn::Ident::new(&format!("field_{}", idx), proc_macro2::Span::call_site());
+                            match get_field_fixes(&field_ident, field) {
+                                Some(fixes) => {
+                                    field_fixes.push(fixes);
+                                    quote! { #field_ident }
+                                }
+                                None => quote! { _ },
+                            }
+                        }).collect::<Vec<_>>();
+                        quote! {
+                            Self::#variant_ident( #( #patterns ),* ) => {
+                                #( #field_fixes )*
+                            }
+                        }
+                    }
+                    Fields::Unit => quote! {
+                        Self::#variant_ident => {}
+                    },
+                }
+            });
+            quote! {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        }
+        Data::Union(_) => quote! {},
+    };
+
+    let tagged_validations = get_validations(|ret| {
+        quote! {
+            return Err(rod_wrap_error(#ret));
+        }
+    }, true, false);
+
+    let all_tagged_validations = get_validations(|ret| {
+        quote! {
+            errors.push(rod_wrap_error(#ret));
+        }
+    }, true, false);
+
+    let sanitize = match &ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => fields_named.named.iter().filter_map(|field| {
+                let field_name = field.ident.as_ref().unwrap();
+                let sanitize = get_field_sanitize(field_name, field)?;
+                Some(quote! {
+                    {
+                        let #field_name = &mut self.#field_name;
+                        #sanitize
+                    }
+                })
+            }).collect(),
+            Fields::Unnamed(fields_unnamed) => fields_unnamed.unnamed.iter().enumerate().filter_map(|(index, field)| {
+                let field_ident = format_ident!("field_{}", index);
+                let sanitize = get_field_sanitize(&field_ident, field)?;
+                let index = syn::Index::from(index);
+                Some(quote! {
+                    {
+                        let #field_ident = &mut self.#index;
+                        #sanitize
+                    }
+                })
+            }).collect(),
+            Fields::Unit => quote! {},
+        },
+        Data::Enum(data_enum) => {
+            let match_arms = data_enum.variants.iter().filter(|variant| !variant_is_skipped(variant)).filter_map(|variant| {
+                let variant_ident = &variant.ident;
+                match &variant.fields {
+                    Fields::Named(fields_named) => {
+                        let (field_names, field_sanitize): (Vec<_>, Vec<_>) = fields_named.named.iter().filter_map(|field| {
+                            let field_name = field.ident.as_ref().unwrap();
+                            Some((field_name, get_field_sanitize(field_name, field)?))
+                        }).unzip();
+                        (!field_sanitize.is_empty()).then(|| quote! {
+                            Self::#variant_ident { #( #field_names, )* .. } => {
+                                #( #field_sanitize )*
+                            }
+                        })
+                    }
+                    Fields::Unnamed(fields_unnamed) => {
+                        let mut field_sanitize = Vec::new();
+                        let patterns = fields_unnamed.unnamed.iter().enumerate().map(|(idx, field)| {
+                            let field_ident = syn::Ident::new(&format!("field_{}", idx), proc_macro2::Span::call_site());
+                            match get_field_sanitize(&field_ident, field) {
+                                Some(sanitize) => {
+                                    field_sanitize.push(sanitize);
+                                    quote! { #field_ident }
+                                }
+                                None => quote! { _ },
+                            }
+                        }).collect::<Vec<_>>();
+                        (!field_sanitize.is_empty()).then(|| quote! {
+                            Self::#variant_ident( #( #patterns ),* ) => {
+                                #( #field_sanitize )*
+                            }
+                        })
+                    }
+                    Fields::Unit => None,
+                }
+            }).collect::<Vec<_>>();
+            if match_arms.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #( #match_arms )*
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Data::Union(_) => quote! {},
+    };
+
+    let field_validations = get_validations(|ret| {
+        quote! {
+            errors.push(rod_wrap_error(#ret));
+        }
+    }, false, true);
+
+    let container_attrs = RodContainerAttrs::from_attrs(&ast.attrs);
+    let container_validations = container_attrs.get_validations(name, &ast.data, |ret| {
+        quote! {
+            return Err(rod_wrap_error(#ret));
+        }
+    });
+    let all_container_validations = container_attrs.get_validations(name, &ast.data, |ret| {
+        quote! {
+            errors.push(rod_wrap_error(#ret));
+        }
+    });
+    let limited_container_validations = container_attrs.get_validations(name, &ast.data, |ret| {
+        quote! {
+            if errors.len() >= rod_max_errors {
+                errors.set_truncated(true);
+                return Err(errors);
+            }
+            errors.push(rod_wrap_error(#ret));
         }
     });
+    let wrap_error = match container_attrs.wrap_error {
+        Some(path) => quote! { #path(error) },
+        None => quote! { error },
+    };
+    // Comparisons like `eq_field` name the other field in their errors, by the name given with `rename` if it has one
+    let renames = match &ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(fields_named), .. }) => fields_named.named.iter().filter_map(|field| {
+            let field_name = field.ident.as_ref().unwrap().unraw().to_string();
+            let name = external_name(field.ident.as_ref().unwrap(), field);
+            (name != field_name).then(|| quote! { #field_name => #name, })
+        }).collect(),
+        _ => quote! {},
+    };
+    let rod_wrap_error = quote! {
+        fn rod_wrap_error(error: RodValidateError) -> RodValidateError {
+            #wrap_error
+        }
+        #[allow(dead_code)]
+        fn rod_field_name(field: &'static str) -> &'static str {
+            match field {
+                #renames
+                field => field,
+            }
+        }
+    };
+
+    let assert_impl_rod_validate = quote! {
+        fn assert_impl_rod_validate<T: RodValidate>(value: &T, tags: Option<&[&str]>, _warnings: &mut RodValidateErrorList, _max_errors: usize) -> Result<(), Vec<RodValidateError>> {
+            let result = match tags {
+                Some(tags) => value.validate_tagged(tags),
+                None => value.validate(),
+            };
+            if result.is_err() {
+                return Err(vec![result.unwrap_err()]);
+            }
+            Ok(())
+        }
+    };
+
+    let assert_impl_rod_validate_all = quote! {
+        fn assert_impl_rod_validate<T: RodValidate>(value: &T, tags: Option<&[&str]>, _warnings: &mut RodValidateErrorList, _max_errors: usize) -> Result<(), RodValidateErrorList> {
+            match tags {
+                Some(tags) => value.validate_all_tagged(tags),
+                None => value.validate_all(),
+            }
+        }
+    };
+
+    // Nested types report their warnings too, which are added to the warnings of the report
+    let assert_impl_rod_validate_report = quote! {
+        fn assert_impl_rod_validate<T: RodValidate>(value: &T, _tags: Option<&[&str]>, warnings: &mut RodValidateErrorList, _max_errors: usize) -> Result<(), RodValidateErrorList> {
+            let mut report = value.validate_report();
+            warnings.extend(std::mem::take(&mut report.warnings));
+            report.into_result()
+        }
+    };
+
+    // One more error than the limit is asked of nested types, so that the list is marked as truncated if they have more errors
+    let assert_impl_rod_validate_limited = quote! {
+        fn assert_impl_rod_validate<T: RodValidate>(value: &T, _tags: Option<&[&str]>, _warnings: &mut RodValidateErrorList, max_errors: usize) -> Result<(), RodValidateErrorList> {
+            value.validate_all_limited(max_errors.saturating_add(1))
+        }
+    };
+
+    // Rules with a `?warn` message add to `rod_warnings`, which only `validate_report` returns
+    let discard_warnings = quote! {
+        #[allow(unused_mut, unused_variables)]
+        let mut rod_warnings = RodValidateErrorList::new();
+    };
+    let unlimited = quote! {
+        #[allow(unused_variables)]
+        let rod_max_errors = usize::MAX;
+    };
+
+    // The functions generated for single fields declare what their rules refer to, like the methods of `RodValidate`
+    let preamble = quote! {
+        #assert_impl_rod_validate
+        #rod_wrap_error
+        #discard_warnings
+        #unlimited
+        #[allow(unused_variables)]
+        let rod_tags: Option<&[&str]> = None;
+    };
+    let field_validators = if container_attrs.field_validators {
+        get_field_validators(&ast, &preamble)
+    } else {
+        quote! {}
+    };
+    let setters = if container_attrs.setters {
+        get_setters(&ast, &preamble)
+    } else {
+        quote! {}
+    };
+
+    let constructor = if container_attrs.constructor {
+        get_constructor(&ast)
+    } else {
+        quote! {}
+    };
+
+    let newtype = if container_attrs.newtype {
+        get_newtype(&ast)
+    } else {
+        quote! {}
+    };
+
+    let patch = match &container_attrs.patch {
+        Some(derives) => {
+            let all_preamble = quote! {
+                #assert_impl_rod_validate_all
+                #rod_wrap_error
+                #discard_warnings
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = None;
+            };
+            get_patch(&ast, derives, &preamble, &all_preamble)
+        }
+        None => quote! {},
+    };
+
+    let schema = schema::get_schema(name, &ast.data);
+    // Lifetimes let fields borrow the values they validate, like `&'a Address`
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
     quote! {
-        impl RodValidate for #name {
+        impl #impl_generics RodValidate for #name #ty_generics #where_clause {
             fn validate(&self) -> Result<(), RodValidateError> {
-                fn assert_impl_rod_validate<T: RodValidate>(value: &T) -> Result<(), Vec<RodValidateError>> {
-                    let result = value.validate();
-                    if result.is_err() {
-                        return Err(vec![result.unwrap_err()]);
-                    }
-                    Ok(())
-                }
+                #assert_impl_rod_validate
+                #rod_wrap_error
+                #discard_warnings
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = None;
                 #validations
+                #container_validations
                 Ok(())
             }
             fn validate_all(&self) -> Result<(), RodValidateErrorList> {
-                fn assert_impl_rod_validate<T: RodValidate>(value: &T) -> Result<(), RodValidateErrorList> {
-                    return value.validate_all();
+                #assert_impl_rod_validate_all
+                #rod_wrap_error
+                #discard_warnings
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = None;
+                let mut errors = RodValidateErrorList::new();
+                #all_validations
+                #all_container_validations
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+            fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+                #assert_impl_rod_validate_limited
+                #rod_wrap_error
+                #discard_warnings
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = None;
+                #[allow(unused_variables)]
+                let rod_max_errors = max_errors.max(1);
+                let mut errors = RodValidateErrorList::new();
+                #limited_validations
+                #limited_container_validations
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
                 }
+            }
+            fn validate_report(&self) -> RodValidateReport {
+                #assert_impl_rod_validate_report
+                #rod_wrap_error
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = None;
                 let mut errors = RodValidateErrorList::new();
+                #[allow(unused_mut)]
+                let mut rod_warnings = RodValidateErrorList::new();
                 #all_validations
+                #all_container_validations
+                RodValidateReport { errors, warnings: rod_warnings }
+            }
+            fn validate_field(&self, rod_field: &str) -> Result<(), RodValidateErrorList> {
+                #assert_impl_rod_validate_all
+                #rod_wrap_error
+                #discard_warnings
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = None;
+                let mut errors = RodValidateErrorList::new();
+                #field_validations
+                // Nested types report the errors of all their fields
+                errors.retain_paths(rod_field);
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+            fn suggest_fixes(&self) -> Vec<RodFix> {
+                let mut fixes = Vec::new();
+                #fixes
+                fixes
+            }
+            fn sanitize(&mut self) {
+                #sanitize
+            }
+            fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+                #assert_impl_rod_validate
+                #rod_wrap_error
+                #discard_warnings
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = Some(tags);
+                #tagged_validations
+                Ok(())
+            }
+            fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+                #assert_impl_rod_validate_all
+                #rod_wrap_error
+                #discard_warnings
+                #unlimited
+                #[allow(unused_variables)]
+                let rod_tags: Option<&[&str]> = Some(tags);
+                let mut errors = RodValidateErrorList::new();
+                #all_tagged_validations
                 if errors.is_empty() {
                     Ok(())
                 } else {
                     Err(errors)
                 }
             }
+            fn rod_schema() -> __rod_schema::StructSchema {
+                #schema
+            }
         }
+
+        #field_validators
+        #setters
+        #constructor
+        #newtype
+        #patch
     }
     .into()
 }
+
+/// Derives a builder for a struct with named fields, whose `build()` returns the struct only if it passes `validate_all`.
+///
+/// `Name::builder()` returns a `NameBuilder` with a method per field that sets it. Fields of type `Option<T>` are `None` when they are not set,
+/// and other fields that are not set are reported as `StructValidation::Missing` errors. The struct must also derive `RodValidate`.
+/// # Examples
+///
+/// ```
+/// use rod::prelude::*;
+///
+/// #[derive(RodValidate, RodBuilder)]
+/// struct User {
+///     #[rod(String { length: 3..=12 })]
+///     username: String,
+///     #[rod(Option { required: false, inner: String { length: 1.. } })]
+///     nickname: Option<String>,
+/// }
+///
+/// let user = User::builder().username("ferris".to_string()).build();
+/// assert!(user.is_ok());
+/// assert!(User::builder().build().is_err());
+/// ```
+#[proc_macro_error]
+#[proc_macro_derive(RodBuilder, attributes(rod))]
+pub fn derive_rod_builder(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    builder::derive_builder(&ast).into()
+}
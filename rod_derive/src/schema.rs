@@ -0,0 +1,190 @@
+use quote::{format_ident, quote};
+use syn::{Data, Fields, Ident, Type};
+
+use crate::types::schema_rule;
+use crate::{container_value_type, external_name, is_auto_skipped, NO_OP_TYPES, RodAttr, RodAttrContent, RodAttrType, RodExpr, TypeEnum};
+
+/// Returns the type behind references and parentheses, e.g. `Address` for `&'static Address`.
+fn strip_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => strip_type(&reference.elem),
+        Type::Group(group) => strip_type(&group.elem),
+        Type::Paren(paren) => strip_type(&paren.elem),
+        ty => ty,
+    }
+}
+
+/// Returns the generic type arguments of a type, e.g. `T` for `Option<T>`.
+fn type_arguments(ty: &Type) -> Vec<&Type> {
+    let Type::Path(type_path) = strip_type(ty) else {
+        return Vec::new();
+    };
+    match type_path.path.segments.last().map(|segment| &segment.arguments) {
+        Some(syn::PathArguments::AngleBracketed(arguments)) => arguments.args.iter().filter_map(|argument| match argument {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the type of the items of an iterable type, e.g. `T` for `Vec<T>`, `[T; N]` or `&[T]`.
+fn item_type(ty: &Type) -> Option<&Type> {
+    match strip_type(ty) {
+        Type::Array(array) => Some(&array.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        ty => type_arguments(ty).last().copied(),
+    }
+}
+
+/// Returns the types of the elements of a tuple type.
+fn element_types(ty: &Type) -> Option<Vec<&Type>> {
+    match strip_type(ty) {
+        Type::Tuple(tuple) => Some(tuple.elems.iter().collect()),
+        _ => None,
+    }
+}
+
+/// Returns the `schema::TypeSchema` of a type attribute, where `ty` is the Rust type it is written for, if it is known.
+/// Nested user-defined types are described by their `rod_schema`, which is looked up on `ty`, or else on the type named in the attribute.
+pub(crate) fn get_attr_schema(rod_attr: &RodAttr, ty: Option<&Type>) -> proc_macro2::TokenStream {
+    match &rod_attr.content {
+        RodAttrContent::String(content) => content.get_schema(),
+        RodAttrContent::Integer(content) => {
+            let integer_type = match &rod_attr.ty {
+                RodAttrType::Integer(TypeEnum::Type(ident)) => Some(ident.to_string()),
+                _ => None,
+            };
+            content.get_schema(integer_type.as_deref())
+        }
+        RodAttrContent::Float(content) => content.get_schema(),
+        RodAttrContent::Boolean(content) => content.get_schema(),
+        RodAttrContent::Literal(content) => content.get_schema(),
+        RodAttrContent::EnumTag(content) => content.get_schema(),
+        RodAttrContent::Option(content) => content.get_schema(ty.and_then(|ty| type_arguments(ty).first().copied())),
+        RodAttrContent::Iterable(content) => content.get_schema(ty.and_then(item_type)),
+        RodAttrContent::Tuple(content) => content.get_schema(ty.and_then(element_types)),
+        RodAttrContent::Custom(content) => {
+            let name = match &rod_attr.ty {
+                RodAttrType::Custom(TypeEnum::Type(ident)) => ident.to_string(),
+                ty => ty.to_string(),
+            };
+            let ty = match ty {
+                Some(ty) => {
+                    let ty = strip_type(ty);
+                    quote!(#ty)
+                }
+                None => {
+                    let ident = Ident::new(&name, rod_attr.span);
+                    quote!(#ident)
+                }
+            };
+            content.get_schema(&ty, &name)
+        }
+        RodAttrContent::Skip(_) => quote!(__rod_schema::TypeSchema::Skip),
+    }
+}
+
+/// Returns the `schema::TypeSchema` of a type that implements `RodValidate`.
+/// Containers, like `Vec<Address>`, `HashMap<String, Address>` or `Option<Address>`, are described by the schema of the values they hold,
+/// and primitives and strings, which have no rules, are not validated. Trait objects have no schema of their own.
+fn nested_schema(ty: &Type) -> proc_macro2::TokenStream {
+    let ty = strip_type(ty);
+    let container = match ty {
+        Type::Path(type_path) => type_path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    };
+    match (container.as_deref(), container_value_type(ty)) {
+        (Some("Option"), Some(inner)) => {
+            let inner = nested_schema(inner);
+            quote!(__rod_schema::TypeSchema::Optional(Box::new(#inner)))
+        }
+        (Some("Box" | "Rc" | "Arc"), Some(inner)) => nested_schema(inner),
+        (Some("HashMap"), Some(value)) => {
+            let value = nested_schema(value);
+            quote!(__rod_schema::TypeSchema::Map(Box::new(#value)))
+        }
+        (_, Some(item)) => {
+            let item = nested_schema(item);
+            quote!(__rod_schema::TypeSchema::Iterable { item: Box::new(#item), rules: Vec::new() })
+        }
+        (Some(ident), None) if NO_OP_TYPES.contains(&ident) => quote!(__rod_schema::TypeSchema::Skip),
+        // The rules of a trait object depend on the type behind it
+        _ if matches!(ty, Type::TraitObject(_)) => {
+            let name = quote!(#ty).to_string();
+            quote!(__rod_schema::TypeSchema::Other { name: #name, rules: Vec::new() })
+        }
+        _ => quote!(__rod_schema::TypeSchema::Nested(<#ty as RodValidate>::rod_schema)),
+    }
+}
+
+/// Returns the `schema::FieldSchema` of a field, named like in its errors.
+/// `check`, `fail_if` and `with` are added to the rules of the type as custom checks, and `coerce` describes the parsed type.
+fn get_field_schema(field_name: &Ident, field: &syn::Field) -> proc_macro2::TokenStream {
+    let name = external_name(field_name, field);
+    let mut rod_attr = None;
+    let mut message = None;
+    let mut coerce = None;
+    let mut checks = Vec::new();
+    let exprs = field.attrs.iter()
+        .filter(|attr| attr.path().is_ident("rod"))
+        .filter_map(|attr| attr.parse_args_with(syn::punctuated::Punctuated::<RodExpr, syn::Token![,]>::parse_terminated).ok())
+        .flatten();
+    for expr in exprs {
+        match expr {
+            RodExpr::Attribute(attr) => rod_attr = rod_attr.or(Some(*attr)),
+            RodExpr::Message(rod_message) => message = Some(rod_message.message),
+            RodExpr::Check(check) => checks.push(schema_rule(quote!(__rod_schema::RuleKind::Check), check.message.as_ref())),
+            RodExpr::With(_) => checks.push(schema_rule(quote!(__rod_schema::RuleKind::Check), None)),
+            RodExpr::Coerce(rod_coerce) => coerce = Some(rod_coerce),
+            _ => {}
+        }
+    }
+    let ty = match (rod_attr, coerce) {
+        (Some(rod_attr), Some(coerce)) => {
+            // The value arrives as a string, with the rules of the type it is parsed to
+            let parsed = get_attr_schema(&rod_attr, Some(&coerce.ty));
+            let coerce_ty = &coerce.ty;
+            let description = format!("parses to {}", quote!(#coerce_ty));
+            quote! {
+                __rod_schema::TypeSchema::String(vec![__rod_schema::Rule::new(__rod_schema::RuleKind::Other(#description))])
+                    .with_rules(#parsed.rules().iter().cloned())
+            }
+        }
+        (Some(rod_attr), None) => get_attr_schema(&rod_attr, Some(&field.ty)),
+        (None, _) if field.attrs.is_empty() && is_auto_skipped(&field.ty) => quote!(__rod_schema::TypeSchema::Skip),
+        (None, _) if field.attrs.is_empty() => nested_schema(&field.ty),
+        // A delegated validator without a type attribute
+        (None, _) => quote!(__rod_schema::TypeSchema::Other { name: "any", rules: Vec::new() }),
+    };
+    let ty = if checks.is_empty() {
+        ty
+    } else {
+        quote!(#ty.with_rules(vec![#(#checks),*]))
+    };
+    match message {
+        Some(message) => quote!(__rod_schema::FieldSchema::new(#name, #ty).with_message(#message)),
+        None => quote!(__rod_schema::FieldSchema::new(#name, #ty)),
+    }
+}
+
+/// Generates the body of `rod_schema`, with a `schema::FieldSchema` per field of a struct.
+/// The fields of tuple structs are named `field_0`, `field_1`, ..., like in their errors. Enums have no fields in their schema.
+pub(crate) fn get_schema(name: &Ident, data: &Data) -> proc_macro2::TokenStream {
+    let name = name.to_string();
+    let fields = match data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(fields_named), .. }) => {
+            fields_named.named.iter().map(|field| get_field_schema(field.ident.as_ref().unwrap(), field)).collect()
+        }
+        Data::Struct(syn::DataStruct { fields: Fields::Unnamed(fields_unnamed), .. }) => fields_unnamed.unnamed.iter().enumerate().map(|(index, field)| {
+            get_field_schema(&format_ident!("field_{}", index), field)
+        }).collect(),
+        _ => Vec::new(),
+    };
+    quote! {
+        __rod_schema::StructSchema {
+            name: #name,
+            fields: vec![#(#fields),*],
+        }
+    }
+}
@@ -1,36 +1,111 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, Ident, LitStr};
+use syn::{parse::Parse, Ident, LitBool};
 use quote::quote;
 
-use super::optional_braced;
+use super::{field_path, optional_braced, user_defined_error, RuleMessage};
 
 
 /// `RodBooleanContent` is a struct that represents the content of a boolean field in a Rod entity.
 /// It is used to parse and validate boolean attributes in the `#[rod]` attribute macro.
-/// The struct is empty because boolean fields do not have any specific attributes to validate.
-/// To check if a boolean is true or false, use `Literal` instead. 
-pub struct RodBooleanContent {}
+/// # Attributes
+/// - `is`: An optional attribute that specifies the value the boolean must have, e.g. `is: true`.
+/// # Usage
+/// ```
+/// use rod::prelude::*;
+///
+/// #[derive(RodValidate)]
+/// struct MyEntity {
+///     #[rod(bool { is: true })]
+///     accepted_terms: bool,
+/// }
+///
+/// let entity = MyEntity { accepted_terms: true };
+/// assert!(entity.validate().is_ok());
+/// ```
+pub struct RodBooleanContent {
+    is: Option<LitBool>,
+    custom_error: Option<RuleMessage>,
+}
 
 impl Parse for RodBooleanContent {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let inner = optional_braced(input);
-        if let Ok(Some(buffer)) = &inner {
-            if !buffer.is_empty() {
+        let inner = match optional_braced(input)? {
+            Some(inner) => inner,
+            None => return Ok(RodBooleanContent { is: None, custom_error: None }),
+        };
+        let mut is: Option<LitBool> = None;
+        let mut message: Option<RuleMessage> = None;
+        let mut custom_error: Option<RuleMessage> = None;
+        while !inner.is_empty() {
+            let lookahead = inner.lookahead1();
+            if lookahead.peek(Ident) {
+                let ident: Ident = inner.parse()?;
+                if ident == "is" {
+                    check_already_used_attr!(is, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    is = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_error = Some(msg);
+                    }
+                } else {
+                    abort!(
+                        ident.span(),
+                        "Unknown attribute `{}`", ident;
+                        help = "Use `is: true` or `is: false` to check the value of a boolean"
+                    );
+                }
+                _ = inner.parse::<syn::Token![,]>();
+            } else if lookahead.peek(syn::Token![?]) {
+                let result: RuleMessage = inner.parse()?;
+                message = Some(result);
+            } else {
                 abort!(
-                    buffer.span(),
-                    "Boolean fields do not have any attributes. If you want to check if a boolean is true or false, use `Literal` instead."
+                    inner.span(),
+                    "Expected an identifier"
                 );
             }
         }
-        Ok(RodBooleanContent {})
+        Ok(RodBooleanContent { is, custom_error: custom_error.or(message) })
     }
 }
 
 impl RodBooleanContent {
-    pub(crate) fn get_validations(&self, _field_name: &Ident, _wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        quote! {}
+    /// Returns the `schema::TypeSchema` of the field, a literal if it must be `true` or `false`.
+    pub(crate) fn get_schema(&self) -> proc_macro2::TokenStream {
+        match self.is.as_ref() {
+            Some(is) => {
+                let literal = is.value.to_string();
+                quote!(__rod_schema::TypeSchema::Literal(#literal))
+            }
+            None => quote!(__rod_schema::TypeSchema::Boolean),
+        }
     }
-    pub(crate) fn get_validations_with_custom_error(&self, _field_name: &Ident, _wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, _custom_error: &LitStr) -> proc_macro2::TokenStream {
-        quote! {}
+    pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Some(is) = self.is.as_ref() else {
+            return quote! {};
+        };
+        let path = field_path(field_name);
+        let ret = match self.custom_error.as_ref() {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None => wrap_return(quote! {
+                RodValidateError::Boolean(BooleanValidation::Is(#path, #is))
+            }),
+        };
+        quote! {
+            if *#field_name != #is {
+                #ret;
+            }
+        }
     }
-}
\ No newline at end of file
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        let Some(is) = self.is.as_ref() else {
+            return quote! {};
+        };
+        let ret = user_defined_error(wrap_return, self.custom_error.as_ref().unwrap_or(custom_error));
+        quote! {
+            if *#field_name != #is {
+                #ret;
+            }
+        }
+    }
+}
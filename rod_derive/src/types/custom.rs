@@ -1,31 +1,119 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, LitStr};
+use syn::{parse::Parse, Expr, Ident};
 use quote::quote;
 
-use super::{optional_braced, user_defined_error};
+use super::{field_path, optional_braced, schema_other_rule, user_defined_error, FieldComparison, RuleMessage};
 
-pub struct CustomContent;
+/// `CustomContent` is the content of a field of a user-defined type, e.g. `#[rod(Address)]`.
+/// By default the field is validated with its own `RodValidate` implementation.
+/// # Attributes
+/// - `equals`: An optional expression that the field must be equal to, e.g. `equals: Status::Active`.
+///   The type must implement `PartialEq` and `Debug` instead of `RodValidate`.
+/// - `eq_field`, `ne_field`, `lt_field`, `le_field`, `gt_field`, `ge_field`: Optional attributes that compare the field to a sibling field
+///   of the struct, e.g. `lt_field: ends_at` for dates. The type must implement `PartialEq` or `PartialOrd` instead of `RodValidate`.
+///
+/// With any of these attributes, the field is not validated with `RodValidate`.
+pub struct CustomContent {
+    equals: Option<Expr>,
+    field_comparisons: Vec<FieldComparison>,
+    custom_error: Option<RuleMessage>,
+}
 
 impl Parse for CustomContent {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let inner = optional_braced(input);
-        if let Ok(Some(buffer)) = &inner {
-            if !buffer.is_empty() {
-            abort!(
-                buffer.span(),
-                "Custom fields do not have any attributes"
-            );
+        let inner = match optional_braced(input)? {
+            Some(inner) => inner,
+            None => return Ok(CustomContent { equals: None, field_comparisons: Vec::new(), custom_error: None }),
+        };
+        let mut equals: Option<Expr> = None;
+        let mut field_comparisons = Vec::new();
+        let mut message: Option<RuleMessage> = None;
+        let mut custom_error: Option<RuleMessage> = None;
+        while !inner.is_empty() {
+            let lookahead = inner.lookahead1();
+            if lookahead.peek(Ident) {
+                let ident: Ident = inner.parse()?;
+                if ident == "equals" {
+                    check_already_used_attr!(equals, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    equals = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_error = Some(msg);
+                    }
+                } else if let Some(comparison) = FieldComparison::parse_rule(&ident, &inner, &mut message, true)? {
+                    field_comparisons.push(comparison);
+                } else {
+                    abort!(
+                        ident.span(),
+                        "Unknown attribute `{}`", ident;
+                        help = "Custom fields only support `equals` and field comparisons such as `eq_field`"
+                    );
+                }
+                _ = inner.parse::<syn::Token![,]>();
+            } else if lookahead.peek(syn::Token![?]) {
+                let result: RuleMessage = inner.parse()?;
+                message = Some(result);
+            } else {
+                abort!(
+                    inner.span(),
+                    "Expected an identifier"
+                );
             }
         }
-        Ok(CustomContent)
+        Ok(CustomContent { equals, field_comparisons, custom_error: custom_error.or(message) })
     }
 }
 
 impl CustomContent {
+    /// Returns the first comparison to another field, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        self.field_comparisons.first()
+    }
+    /// Whether the field is validated with its own `RodValidate` implementation.
+    pub(crate) fn is_nested(&self) -> bool {
+        self.equals.is_none() && self.field_comparisons.is_empty()
+    }
+    /// Returns the `schema::TypeSchema` of the field of type `ty`, named `name` in the schema.
+    /// A nested type is described by its own `rod_schema`, and `equals` and comparisons are described in words.
+    pub(crate) fn get_schema(&self, ty: &proc_macro2::TokenStream, name: &str) -> proc_macro2::TokenStream {
+        if self.is_nested() {
+            return quote!(__rod_schema::TypeSchema::Nested(<#ty as RodValidate>::rod_schema));
+        }
+        let equals = self.equals.as_ref().map(|equals| {
+            schema_other_rule(&format!("equals {}", quote!(#equals).to_string().replace(" :: ", "::")), self.custom_error.as_ref())
+        });
+        let rules = equals.into_iter().chain(self.field_comparisons.iter().map(FieldComparison::schema_rule));
+        quote!(__rod_schema::TypeSchema::Other { name: #name, rules: vec![#(#rules),*] })
+    }
     pub(crate) fn get_validations(&self, field_name: &syn::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let ret = wrap_return(quote! { e });
+        if !self.is_nested() {
+            let path = field_path(field_name);
+            let equals_opt = self.equals.as_ref().map(|equals| {
+                let ret = match self.custom_error.as_ref() {
+                    Some(msg) => user_defined_error(wrap_return, msg),
+                    None => wrap_return(quote! {
+                        RodValidateError::Custom(CustomValidation::Equals(#path, format!("{:?}", #field_name), format!("{:?}", rod_expected)))
+                    }),
+                };
+                quote! {
+                    {
+                        let rod_expected = #equals;
+                        if *#field_name != rod_expected {
+                            #ret;
+                        }
+                    }
+                }
+            });
+            let comparisons = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, None));
+            return quote! {
+                #equals_opt
+                #( #comparisons )*
+            };
+        }
+        let path = field_path(field_name);
+        let ret = wrap_return(quote! { e.with_path_prefix(&#path) });
         quote! {
-            let assert = assert_impl_rod_validate(#field_name);
+            let assert = assert_impl_rod_validate(#field_name, rod_tags, &mut rod_warnings, rod_max_errors);
             if let Err(errs) = assert {
                 for e in errs {
                     #ret;
@@ -33,13 +121,28 @@ impl CustomContent {
             }
         }
     }
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &syn::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &syn::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        if !self.is_nested() {
+            let equals_opt = self.equals.as_ref().map(|equals| {
+                let ret = user_defined_error(wrap_return, self.custom_error.as_ref().unwrap_or(custom_error));
+                quote! {
+                    if *#field_name != #equals {
+                        #ret;
+                    }
+                }
+            });
+            let comparisons = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, Some(custom_error)));
+            return quote! {
+                #equals_opt
+                #( #comparisons )*
+            };
+        }
         let ret = user_defined_error(wrap_return, custom_error);
         quote! {
-            let assert = assert_impl_rod_validate(#field_name);
+            let assert = assert_impl_rod_validate(#field_name, rod_tags, &mut rod_warnings, rod_max_errors);
             if let Err(_errs) = assert {
                 #ret;
             }
         }
     }
-}
\ No newline at end of file
+}
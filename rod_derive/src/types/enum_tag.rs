@@ -0,0 +1,153 @@
+use proc_macro_error::abort;
+use syn::{parse::Parse, Ident, Path};
+use quote::quote;
+
+use super::{field_path, optional_braced, schema_other_rule, user_defined_error, RuleMessage};
+
+/// `RodEnumTagContent` is a struct that represents the content of a field holding a plain C-like enum in a Rod entity.
+/// It is used to parse and validate enum tag attributes in the `#[rod]` attribute macro.
+/// This struct includes a single field `one_of`, which lists the variants the field is allowed to be.
+/// # Attributes
+/// - `one_of`: A required attribute that specifies the allowed unit variants, e.g. `[Status::Active, Status::Paused]`.
+/// # Usage
+/// ```
+/// use rod::prelude::*;
+///
+/// enum Status {
+///     Active,
+///     Paused,
+///     Deleted,
+/// }
+///
+/// #[derive(RodValidate)]
+/// struct MyEntity {
+///     #[rod(
+///         EnumTag {
+///             one_of: [Status::Active, Status::Paused],
+///         }
+///     )]
+///     status: Status,
+/// }
+///
+/// let entity = MyEntity { status: Status::Active };
+/// assert!(entity.validate().is_ok());
+/// ```
+pub struct RodEnumTagContent {
+    one_of: Vec<Path>,
+    custom_error: Option<RuleMessage>,
+}
+
+impl RodEnumTagContent {
+    /// Returns the `schema::TypeSchema` of the field, whose allowed variants are described in words.
+    pub(crate) fn get_schema(&self) -> proc_macro2::TokenStream {
+        let rule = schema_other_rule(&format!("one of [{}]", self.allowed()), self.custom_error.as_ref());
+        quote!(__rod_schema::TypeSchema::Other { name: "enum", rules: vec![#rule] })
+    }
+    /// Returns the allowed variants as they are written, e.g. `Status::Active, Status::Paused`.
+    fn allowed(&self) -> String {
+        self.one_of
+            .iter()
+            .map(|path| path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>().join("::"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl Parse for RodEnumTagContent {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let opt = optional_braced(input)?;
+        let inner = match opt {
+            Some(buffer) => buffer,
+            None => {
+                abort!(
+                    input.span(),
+                    "Must specify the allowed variants using `one_of: [<variants>]` inside the `EnumTag` attribute.";
+                    help = "Example: `#[rod(EnumTag { one_of: [Status::Active, Status::Paused] })]`"
+                )
+            }
+        };
+        let mut one_of: Option<Vec<Path>> = None;
+        let mut message: Option<RuleMessage> = None;
+        let mut custom_error: Option<RuleMessage> = None;
+        while !inner.is_empty() {
+            let lookahead = inner.lookahead1();
+            if lookahead.peek(syn::Ident) {
+                let ident: syn::Ident = inner.parse()?;
+                if ident == "one_of" {
+                    check_already_used_attr!(one_of, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    let content;
+                    syn::bracketed!(content in inner);
+                    let variants = content.parse_terminated(Path::parse, syn::Token![,])?;
+                    if variants.is_empty() {
+                        abort!(
+                            ident.span(), "Expected at least one variant";
+                            help = "Example: `one_of: [Status::Active, Status::Paused]`"
+                        );
+                    }
+                    one_of = Some(variants.into_iter().collect());
+                    if let Some(msg) = message.take() {
+                        custom_error = Some(msg);
+                    }
+                } else {
+                    abort!(
+                        ident.span(),
+                        "Unknown attribute `{}`", ident
+                    );
+                }
+                _ = inner.parse::<syn::Token![,]>();
+            } else if lookahead.peek(syn::Token![?]) {
+                let result: RuleMessage = inner.parse()?;
+                message = Some(result);
+            } else {
+                abort!(
+                    inner.span(),
+                    "Expected an identifier"
+                );
+            }
+        }
+        if let Some(one_of) = one_of {
+            let custom_error = custom_error.or(message);
+            Ok(RodEnumTagContent { one_of, custom_error })
+        } else {
+            abort!(
+                input.span(),
+                "Must specify the allowed variants using `one_of: [<variants>]` inside the `EnumTag` attribute.";
+                help = "Example: `#[rod(EnumTag { one_of: [Status::Active, Status::Paused] })]`"
+            )
+        }
+    }
+}
+
+impl RodEnumTagContent {
+    pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
+        let one_of = &self.one_of;
+        let allowed = self.allowed();
+        let ret = if let Some(msg) = self.custom_error.as_ref() {
+            user_defined_error(wrap_return, msg)
+        } else {
+            wrap_return(quote! {
+                RodValidateError::EnumTag(EnumTagValidation::OneOf(#path, #allowed))
+            })
+        };
+        quote! {
+            if !matches!(#field_name, #(#one_of)|*) {
+                #ret;
+            }
+        }
+    }
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        let one_of = &self.one_of;
+        let ret = if let Some(msg) = self.custom_error.as_ref() {
+            user_defined_error(wrap_return, msg)
+        } else {
+            user_defined_error(wrap_return, custom_error)
+        };
+        quote! {
+            if !matches!(#field_name, #(#one_of)|*) {
+                #ret;
+            }
+        }
+    }
+}
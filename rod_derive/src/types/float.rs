@@ -1,9 +1,9 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, Ident, LitStr};
+use syn::{parse::Parse, Ident, LitFloat};
 use quote::{quote, ToTokens};
 
 
-use super::{optional_braced, user_defined_error, LengthOrSize, NumberSign};
+use super::{field_path, optional_braced, optional_paren, schema_other_rule, schema_rule, user_defined_error, FieldComparison, LengthOrSize, NumberSign, RuleMessage};
 
 enum FloatType {
     Nan,
@@ -31,26 +31,208 @@ impl Parse for FloatType {
     }
 }
 
-impl ToTokens for FloatType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let ident = match self {
+impl FloatType {
+    fn name(&self) -> &'static str {
+        match self {
             FloatType::Nan => "NaN",
             FloatType::Finite => "Finite",
             FloatType::Infinite => "Infinite",
             FloatType::Normal => "Normal",
             FloatType::Subnormal => "Subnormal",
-        };
+        }
+    }
+}
+
+impl ToTokens for FloatType {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let ident = self.name();
         tokens.extend(quote!(#ident));
     }
 }
 
+/// `FloatTypeRule` is the value of the `ftype` attribute: a [`FloatType`] the float must have,
+/// or with `!NaN` or `not(NaN)`, a type the float must not have.
+struct FloatTypeRule {
+    r#type: FloatType,
+    negated: bool,
+}
+
+impl FloatTypeRule {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        let check = match self.r#type {
+            FloatType::Nan => quote!(#field_name.is_nan()),
+            FloatType::Finite => quote!(#field_name.is_finite()),
+            FloatType::Infinite => quote!(#field_name.is_infinite()),
+            FloatType::Normal => quote!(#field_name.is_normal()),
+            FloatType::Subnormal => quote!(#field_name.is_subnormal()),
+        };
+        if self.negated {
+            quote!(!#check)
+        } else {
+            check
+        }
+    }
+    fn describe(&self) -> String {
+        if self.negated {
+            format!("not {}", self.r#type.name())
+        } else {
+            self.r#type.name().to_string()
+        }
+    }
+}
+
+impl Parse for FloatTypeRule {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Token![!]) {
+            input.parse::<syn::Token![!]>()?;
+            Ok(FloatTypeRule { r#type: input.parse()?, negated: true })
+        } else if input.peek(Ident) && input.peek2(syn::token::Paren) {
+            let ident: Ident = input.parse()?;
+            if ident != "not" {
+                abort!(
+                    ident.span(), "Unknown float type modifier `{}`", ident;
+                    help = "Use `not(...)` or `!` to negate a float type"
+                );
+            }
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(FloatTypeRule { r#type: content.parse()?, negated: true })
+        } else {
+            Ok(FloatTypeRule { r#type: input.parse()?, negated: false })
+        }
+    }
+}
+
+/// `FloatSize` is the `size` of a float: a range, or an exact value that is compared within a tolerance rather than with `==`.
+enum FloatSize {
+    Exact {
+        value: syn::Expr,
+        tolerance: Option<LitFloat>,
+    },
+    Range(LengthOrSize),
+}
+
+impl Parse for FloatSize {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if let Some(inner) = optional_paren(input)? {
+            let value = exact_float(inner.parse()?);
+            inner.parse::<syn::Token![,]>()?;
+            let ident: Ident = inner.parse()?;
+            if ident != "tolerance" {
+                abort!(
+                    ident.span(), "Unknown attribute `{}`. Expected `tolerance`", ident;
+                    help = "Example: `size: (3.14, tolerance: 1e-6)`"
+                );
+            }
+            inner.parse::<syn::Token![:]>()?;
+            let tolerance = parse_float_lit(&inner, "tolerance")?;
+            _ = inner.parse::<syn::Token![,]>();
+            Ok(FloatSize::Exact { value, tolerance: Some(tolerance) })
+        } else {
+            match input.parse()? {
+                LengthOrSize::Exact(value) => Ok(FloatSize::Exact { value: exact_float(value), tolerance: None }),
+                range => Ok(FloatSize::Range(range)),
+            }
+        }
+    }
+}
+
+/// Turns integer literals such as `6` into float literals such as `6.0`, so that they can be compared with floats.
+/// Other expressions, e.g. constants, are kept as they are.
+pub(super) fn exact_float(value: syn::Expr) -> syn::Expr {
+    match value {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), attrs }) => syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(LitFloat::new(&format!("{}.0", lit.base10_digits()), lit.span())),
+            attrs,
+        }),
+        value => value,
+    }
+}
+
+impl FloatSize {
+    fn exact_check(field_name: &Ident, value: &syn::Expr, tolerance: &Option<LitFloat>, default_tolerance: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let tolerance = match tolerance {
+            Some(tolerance) => quote!(Some(#tolerance)),
+            None => default_tolerance.clone(),
+        };
+        quote!(RodFloat::rod_approx_eq(*#field_name, #value, #tolerance))
+    }
+    fn validate_float(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, default_tolerance: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            FloatSize::Exact { value, tolerance } => {
+                let path = field_path(field_name);
+                let check = Self::exact_check(field_name, value, tolerance, default_tolerance);
+                let error = match tolerance {
+                    Some(tolerance) => quote!(FloatValidation::Approx(#path, #field_name.clone().into(), (#value) as f64, #tolerance)),
+                    None => quote!(FloatValidation::Size(#path, #field_name.clone().into(), ExpectedBounds::Exact((#value) as f64))),
+                };
+                let ret = wrap_return(quote! {
+                    RodValidateError::Float(#error)
+                });
+                quote! {
+                    if !#check {
+                        #ret;
+                    }
+                }
+            }
+            FloatSize::Range(range) => range.validate_float(field_name, wrap_return),
+        }
+    }
+    fn validate_float_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage, default_tolerance: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            FloatSize::Exact { value, tolerance } => {
+                let check = Self::exact_check(field_name, value, tolerance, default_tolerance);
+                let ret = user_defined_error(wrap_return, custom_error);
+                quote! {
+                    if !#check {
+                        #ret;
+                    }
+                }
+            }
+            FloatSize::Range(range) => range.validate_float_with_custom_error(field_name, wrap_return, custom_error),
+        }
+    }
+    fn schema_rule(&self, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        match self {
+            FloatSize::Exact { value, tolerance: None } => schema_rule(quote!(__rod_schema::RuleKind::Size(__rod_schema::Bounds::Exact((#value) as f64))), custom_error),
+            FloatSize::Exact { value, tolerance: Some(tolerance) } => {
+                schema_other_rule(&format!("size {} within {}", value.to_token_stream(), tolerance), custom_error)
+            }
+            FloatSize::Range(range) => {
+                let bounds = range.schema_bounds();
+                schema_rule(quote!(__rod_schema::RuleKind::Size(#bounds)), custom_error)
+            }
+        }
+    }
+    fn fix_float(&self, field_name: &Ident, path: &str, default_tolerance: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            FloatSize::Exact { value, tolerance } => {
+                let check = Self::exact_check(field_name, value, tolerance, default_tolerance);
+                quote! {
+                    if !#check {
+                        fixes.push(RodFix { path: std::borrow::Cow::Borrowed(#path), value: RodFixValue::Float((#value) as f64) });
+                    }
+                }
+            }
+            FloatSize::Range(range) => range.fix_float(field_name, path),
+        }
+    }
+}
+
 /// `RodFloatContent` is a struct that represents the content of an float field in a Rod entity.
 /// It is used to parse and validate float attributes in the `#[rod]` attribute macro.
 /// This struct includes optional fields for size, sign, and type, which are used in validation checks.
 /// # Attributes
-/// - `size`: An optional attribute that specifies the a range for the float to be in, or an exact value for the float.
+/// - `size`: An optional attribute that specifies the a range for the float to be in, or an exact value for the float, compared within a small epsilon.
 /// - `sign`: An optional attribute that specifies the sign of the float, see [`NumberSign`][crate::types::NumberSign] enum.
 /// - `ftype`: An optional attribute that specifies the type of the float, see [`FloatType`][crate::types::float::FloatType] enum.
+///   It can be negated with `!` or `not(...)`, e.g. `ftype: !NaN` accepts infinities but not NaN.
+/// - `non_zero`: An optional attribute that specifies, with `non_zero: true`, that the float must not be zero.
+/// - `decimal_places`: An optional attribute that specifies the maximum number of decimal digits of the float, compared within a small epsilon.
+/// - `step`: An optional attribute that specifies that the float must be a multiple of the given step, compared within a small epsilon.
+/// - `tolerance`: An optional attribute that replaces the default epsilon of `step` and exact `size` comparisons with an absolute tolerance.
+/// - `eq_field`, `ne_field`, `lt_field`, `le_field`, `gt_field`, `ge_field`: Optional attributes that compare the field to a sibling field of the struct, e.g. `le_field: max_capacity`.
+///   An exact size can also carry its own tolerance, e.g. `size: (3.14, tolerance: 1e-6)`.
 /// # Usage
 /// ```
 /// use rod::prelude::*;
@@ -71,20 +253,67 @@ impl ToTokens for FloatType {
 /// assert!(entity.validate().is_ok());
 /// ```
 pub struct RodFloatContent {
-    size: Option<LengthOrSize>,
+    size: Option<FloatSize>,
     sign: Option<NumberSign>,
-    r#type: Option<FloatType>,
-    custom_errors: [Option<LitStr>; 3], // size, sign, type
+    r#type: Option<FloatTypeRule>,
+    non_zero: bool,
+    decimal_places: Option<u32>,
+    step: Option<LitFloat>,
+    tolerance: Option<LitFloat>,
+    field_comparisons: Vec<FieldComparison>,
+    custom_errors: [Option<RuleMessage>; 6], // size, sign, type, non_zero, decimal_places, step
 }
 
 impl RodFloatContent {
+    /// Removes the comparisons to other fields, for validations that run without the rest of the struct.
+    pub(crate) fn strip_field_comparisons(&mut self) {
+        self.field_comparisons.clear();
+    }
+    /// Returns the first comparison to another field, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        self.field_comparisons.first()
+    }
+    fn tolerance_tokens(&self) -> proc_macro2::TokenStream {
+        match self.tolerance.as_ref() {
+            Some(tolerance) => quote!(Some(#tolerance)),
+            None => quote!(None),
+        }
+    }
+    pub(crate) fn get_fixes(&self, field_name: &Ident, path: &str) -> Option<proc_macro2::TokenStream> {
+        self.size.as_ref().map(|size| size.fix_float(field_name, path, &self.tolerance_tokens()))
+    }
+    /// Returns the `schema::TypeSchema` describing the rules of the field. Rules other than `size`, `sign`, `type` and `step` are described in words.
+    pub(crate) fn get_schema(&self) -> proc_macro2::TokenStream {
+        let mut rules = Vec::new();
+        if let Some(size) = self.size.as_ref() {
+            rules.push(size.schema_rule(self.custom_errors[0].as_ref()));
+        }
+        if let Some(sign) = self.sign.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Sign(#sign)), self.custom_errors[1].as_ref()));
+        }
+        if let Some(r#type) = self.r#type.as_ref() {
+            let description = r#type.describe();
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::FloatType(#description)), self.custom_errors[2].as_ref()));
+        }
+        if self.non_zero {
+            rules.push(schema_other_rule("non-zero", self.custom_errors[3].as_ref()));
+        }
+        if let Some(decimal_places) = self.decimal_places {
+            rules.push(schema_other_rule(&format!("at most {} decimal places", decimal_places), self.custom_errors[4].as_ref()));
+        }
+        if let Some(step) = self.step.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Step(#step as f64)), self.custom_errors[5].as_ref()));
+        }
+        rules.extend(self.field_comparisons.iter().map(FieldComparison::schema_rule));
+        quote!(__rod_schema::TypeSchema::Float(vec![#(#rules),*]))
+    }
     pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
+        let path = field_path(field_name);
         let size_opt = self.size.as_ref().map(|size| {
             if let Some(msg) = self.custom_errors[0].as_ref() {
-                size.validate_float_with_custom_error(field_name, wrap_return, msg)
+                size.validate_float_with_custom_error(field_name, wrap_return, msg, &self.tolerance_tokens())
             } else {
-                size.validate_float(field_name, wrap_return)
+                size.validate_float(field_name, wrap_return, &self.tolerance_tokens())
             }
         });
         let sign_opt = self.sign.as_ref().map(|sign| {
@@ -108,18 +337,13 @@ impl RodFloatContent {
             }
         });
         let type_opt = self.r#type.as_ref().map(|r#type| {
-            let type_check = match r#type {
-                FloatType::Nan => quote!(#field_name.is_nan()),
-                FloatType::Finite => quote!(#field_name.is_finite()),
-                FloatType::Infinite => quote!(#field_name.is_infinite()),
-                FloatType::Normal => quote!(#field_name.is_normal()),
-                FloatType::Subnormal => quote!(#field_name.is_subnormal()),
-            };
+            let type_check = r#type.check(field_name);
+            let description = r#type.describe();
             let ret = if let Some(msg) = self.custom_errors[2].as_ref() {
                 user_defined_error(wrap_return, msg)
             } else {
                 wrap_return(quote! {
-                    RodValidateError::Float(FloatValidation::Type(#path, #field_name.clone().into(), #r#type))
+                    RodValidateError::Float(FloatValidation::Type(#path, #field_name.clone().into(), #description))
                 })
             };
             quote! {
@@ -128,19 +352,67 @@ impl RodFloatContent {
                 }
             }
         });
+        let non_zero_opt = self.non_zero.then(|| {
+            let ret = if let Some(msg) = self.custom_errors[3].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Float(FloatValidation::NonZero(#path))
+                })
+            };
+            quote! {
+                if *#field_name == 0.0 {
+                    #ret;
+                }
+            }
+        });
+        let decimal_places_opt = self.decimal_places.map(|places| {
+            let ret = if let Some(msg) = self.custom_errors[4].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Float(FloatValidation::DecimalPlaces(#path, #field_name.clone().into(), #places))
+                })
+            };
+            quote! {
+                if !RodFloat::rod_has_decimal_places(*#field_name, #places) {
+                    #ret;
+                }
+            }
+        });
+        let step_opt = self.step.as_ref().map(|step| {
+            let tolerance = self.tolerance_tokens();
+            let ret = if let Some(msg) = self.custom_errors[5].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Float(FloatValidation::Step(#path, #field_name.clone().into(), #step as f64))
+                })
+            };
+            quote! {
+                if !RodFloat::rod_is_multiple_of(*#field_name, #step, #tolerance) {
+                    #ret;
+                }
+            }
+        });
+        let comparisons_opt = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, None)).collect::<proc_macro2::TokenStream>();
         quote! {
             #size_opt
             #sign_opt
             #type_opt
+            #non_zero_opt
+            #decimal_places_opt
+            #step_opt
+            #comparisons_opt
         }
     }
 
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let size_opt = self.size.as_ref().map(|size| {
             if let Some(msg) = self.custom_errors[0].as_ref() {
-                size.validate_float_with_custom_error(field_name, wrap_return, msg)
+                size.validate_float_with_custom_error(field_name, wrap_return, msg, &self.tolerance_tokens())
             } else {
-                size.validate_float_with_custom_error(field_name, wrap_return, custom_error)
+                size.validate_float_with_custom_error(field_name, wrap_return, custom_error, &self.tolerance_tokens())
             }
         });
         let sign_opt = self.sign.as_ref().map(|sign| {
@@ -162,13 +434,7 @@ impl RodFloatContent {
             }
         });
         let type_opt = self.r#type.as_ref().map(|r#type| {
-            let type_check = match r#type {
-                FloatType::Nan => quote!(#field_name.is_nan()),
-                FloatType::Finite => quote!(#field_name.is_finite()),
-                FloatType::Infinite => quote!(#field_name.is_infinite()),
-                FloatType::Normal => quote!(#field_name.is_normal()),
-                FloatType::Subnormal => quote!(#field_name.is_subnormal()),
-            };
+            let type_check = r#type.check(field_name);
             let ret = if let Some(msg) = self.custom_errors[2].as_ref() {
                 user_defined_error(wrap_return, msg)
             } else {
@@ -180,14 +446,67 @@ impl RodFloatContent {
                 }
             }
         });
+        let non_zero_opt = self.non_zero.then(|| {
+            let ret = if let Some(msg) = self.custom_errors[3].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if *#field_name == 0.0 {
+                    #ret;
+                }
+            }
+        });
+        let decimal_places_opt = self.decimal_places.map(|places| {
+            let ret = if let Some(msg) = self.custom_errors[4].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !RodFloat::rod_has_decimal_places(*#field_name, #places) {
+                    #ret;
+                }
+            }
+        });
+        let step_opt = self.step.as_ref().map(|step| {
+            let tolerance = self.tolerance_tokens();
+            let ret = if let Some(msg) = self.custom_errors[5].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !RodFloat::rod_is_multiple_of(*#field_name, #step, #tolerance) {
+                    #ret;
+                }
+            }
+        });
+        let comparisons_opt = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, Some(custom_error))).collect::<proc_macro2::TokenStream>();
         quote! {
             #size_opt
             #sign_opt
             #type_opt
+            #non_zero_opt
+            #decimal_places_opt
+            #step_opt
+            #comparisons_opt
         }
     }
 }
 
+pub(super) fn parse_float_lit(input: syn::parse::ParseStream, name: &str) -> syn::Result<LitFloat> {
+    if input.peek(syn::LitInt) {
+        let lit: syn::LitInt = input.parse()?;
+        abort!(
+            lit.span(), "Expected `{}` to be a float literal", name;
+            help = "Try `{}.0` instead", lit
+        );
+    }
+    input.parse()
+}
+
 impl Parse for RodFloatContent {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let opt = optional_braced(input)?;
@@ -197,14 +516,24 @@ impl Parse for RodFloatContent {
                 size: None,
                 sign: None,
                 r#type: None,
-                custom_errors: [None, None, None],
+                non_zero: false,
+                decimal_places: None,
+                step: None,
+                tolerance: None,
+                field_comparisons: Vec::new(),
+                custom_errors: [None, None, None, None, None, None],
             })
         };
         let mut size = None;
         let mut sign = None;
         let mut r#type = None;
-        let mut message: Option<LitStr> = None;
-        let mut custom_errors: [Option<LitStr>; 3] = [None, None, None];
+        let mut non_zero = false;
+        let mut decimal_places = None;
+        let mut step: Option<LitFloat> = None;
+        let mut tolerance: Option<LitFloat> = None;
+        let mut field_comparisons = Vec::new();
+        let mut message: Option<RuleMessage> = None;
+        let mut custom_errors: [Option<RuleMessage>; 6] = [None, None, None, None, None, None];
         while !inner.is_empty() {
             let lookahead = inner.lookahead1();
             if lookahead.peek(syn::Ident) {
@@ -230,6 +559,44 @@ impl Parse for RodFloatContent {
                     if let Some(msg) = message.take() {
                         custom_errors[2] = Some(msg);
                     }
+                } else if ident == "non_zero" {
+                    inner.parse::<syn::Token![:]>()?;
+                    let lit: syn::LitBool = inner.parse()?;
+                    if !lit.value {
+                        abort!(
+                            lit.span(), "`non_zero: false` has no effect";
+                            help = "Remove the `non_zero` attribute"
+                        );
+                    }
+                    non_zero = true;
+                    if let Some(msg) = message.take() {
+                        custom_errors[3] = Some(msg);
+                    }
+                } else if ident == "decimal_places" {
+                    check_already_used_attr!(decimal_places, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    let places: syn::LitInt = inner.parse()?;
+                    decimal_places = Some(places.base10_parse::<u32>()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[4] = Some(msg);
+                    }
+                } else if ident == "step" {
+                    check_already_used_attr!(step, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    let lit = parse_float_lit(&inner, "step")?;
+                    if lit.base10_parse::<f64>()? == 0.0 {
+                        abort!(lit.span(), "`step` must not be zero");
+                    }
+                    step = Some(lit);
+                    if let Some(msg) = message.take() {
+                        custom_errors[5] = Some(msg);
+                    }
+                } else if ident == "tolerance" {
+                    check_already_used_attr!(tolerance, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    tolerance = Some(parse_float_lit(&inner, "tolerance")?);
+                } else if let Some(comparison) = FieldComparison::parse_rule(&ident, &inner, &mut message, true)? {
+                    field_comparisons.push(comparison);
                 } else {
                     abort!(
                         ident.span(),
@@ -238,8 +605,7 @@ impl Parse for RodFloatContent {
                 }
                 _ = inner.parse::<syn::Token![,]>();
             } else if lookahead.peek(syn::Token![?]) {
-                let _q: syn::Token![?] = inner.parse()?;
-                let result: LitStr = inner.parse()?;
+                let result: RuleMessage = inner.parse()?;
                 message = Some(result);
             } else {
                 abort!(
@@ -248,10 +614,22 @@ impl Parse for RodFloatContent {
                 );
             }
         }
+        let has_exact_size = matches!(size, Some(FloatSize::Exact { .. }));
+        if let (Some(tolerance), None, false) = (tolerance.as_ref(), step.as_ref(), has_exact_size) {
+            abort!(
+                tolerance.span(), "`tolerance` has no effect without `step` or an exact `size`";
+                help = "Remove the `tolerance` attribute"
+            );
+        }
         Ok(RodFloatContent {
             size,
             sign,
             r#type,
+            non_zero,
+            decimal_places,
+            step,
+            tolerance,
+            field_comparisons,
             custom_errors,
         })
     }
@@ -1,9 +1,211 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, Ident, LitInt, LitStr};
-use quote::quote;
+use syn::{parse::Parse, Ident, LitInt};
+use quote::{quote, ToTokens};
 
 
-use super::{optional_braced, user_defined_error, LengthOrSize, NumberSign};
+use super::{field_path, optional_braced, schema_other_rule, schema_rule, user_defined_error, FieldComparison, LengthOrSize, NumberSign, RuleMessage};
+
+/// `NumberParity` is an enum that represents whether an integer should be even or odd.
+pub(crate) enum NumberParity {
+    Even,
+    Odd,
+}
+
+impl NumberParity {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        match self {
+            NumberParity::Even => quote!(*#field_name % 2 == 0),
+            // The remainder of a negative odd number is -1, so compare against 0 rather than 1
+            NumberParity::Odd => quote!(*#field_name % 2 != 0),
+        }
+    }
+}
+
+impl ToTokens for NumberParity {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let parity = match self {
+            NumberParity::Even => "even",
+            NumberParity::Odd => "odd",
+        };
+        tokens.extend(quote!(#parity));
+    }
+}
+
+impl Parse for NumberParity {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "Even" => Ok(NumberParity::Even),
+            "Odd" => Ok(NumberParity::Odd),
+            _ => Err(input.error("Expected `parity` to be one of Even, Odd")),
+        }
+    }
+}
+
+/// `IntegerStep` is the value of the `step` attribute, either `step: 5` or `step: (5, 1)`.
+/// With an offset, the integer must leave that remainder when divided by the step, e.g. `x.rem_euclid(5) == 1`.
+pub(crate) struct IntegerStep {
+    step: LitInt,
+    offset: Option<LitInt>,
+}
+
+impl IntegerStep {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        let step = &self.step;
+        match &self.offset {
+            None => quote!(#field_name % #step == 0),
+            // `rem_euclid` keeps the remainder of negative integers in `0..step`
+            Some(offset) => quote!((*#field_name).rem_euclid(#step) == #offset),
+        }
+    }
+    fn error(&self, path: &proc_macro2::TokenStream, field_name: &Ident) -> proc_macro2::TokenStream {
+        let step = &self.step;
+        match &self.offset {
+            None => quote! {
+                RodValidateError::Integer(IntegerValidation::Step(#path, #field_name.clone().into(), #step.into()))
+            },
+            Some(offset) => quote! {
+                RodValidateError::Integer(IntegerValidation::StepOffset(#path, #field_name.clone().into(), Box::new((#step.into(), #offset.into()))))
+            },
+        }
+    }
+}
+
+impl Parse for IntegerStep {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let Some(content) = super::optional_paren(input)? else {
+            return Ok(IntegerStep { step: input.parse()?, offset: None });
+        };
+        let step: LitInt = content.parse()?;
+        content.parse::<syn::Token![,]>()?;
+        let offset: LitInt = content.parse()?;
+        _ = content.parse::<syn::Token![,]>();
+        let step_value = step.base10_parse::<i128>()?;
+        if step_value == 0 {
+            abort!(step.span(), "Expected `step` to be non-zero");
+        }
+        // Normalize the offset into `0..step`, so that e.g. `(5, -1)` means the same as `(5, 4)`
+        let offset_value = offset.base10_parse::<i128>()?.rem_euclid(step_value.abs());
+        let offset = (offset_value != 0).then(|| LitInt::new(&offset_value.to_string(), offset.span()));
+        Ok(IntegerStep { step, offset })
+    }
+}
+
+/// `IntegerList` is the list of values of the `one_of` and `not_one_of` attributes, e.g. `[80, 443, 8080]`.
+pub(crate) struct IntegerList {
+    values: Vec<LitInt>,
+}
+
+impl IntegerList {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        let values = &self.values;
+        quote!(matches!(*#field_name, #(#values)|*))
+    }
+    fn describe(&self) -> String {
+        let values = self.values.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+        format!("[{}]", values.join(", "))
+    }
+}
+
+impl Parse for IntegerList {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        let bracket = syn::bracketed!(content in input);
+        let values = content.parse_terminated(<LitInt as Parse>::parse, syn::Token![,])?;
+        if values.is_empty() {
+            abort!(
+                bracket.span.join(), "Expected at least one value";
+                help = "Example: `one_of: [80, 443, 8080]`"
+            );
+        }
+        Ok(IntegerList { values: values.into_iter().collect() })
+    }
+}
+
+/// `IntegerDigits` is the number of decimal digits allowed by the `digits` and `max_digits` attributes.
+/// The sign of negative integers is not counted, and zero has one digit.
+pub(crate) enum IntegerDigits {
+    Exact(u32),
+    Max(u32),
+}
+
+impl IntegerDigits {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        match self {
+            IntegerDigits::Exact(digits) => quote!(Integer::from(*#field_name).digits() == #digits),
+            IntegerDigits::Max(digits) => quote!(Integer::from(*#field_name).digits() <= #digits),
+        }
+    }
+    fn describe(&self) -> String {
+        match self {
+            IntegerDigits::Exact(digits) => format!("exactly {} digits", digits),
+            IntegerDigits::Max(digits) => format!("at most {} digits", digits),
+        }
+    }
+    fn expected(&self) -> proc_macro2::TokenStream {
+        match self {
+            IntegerDigits::Exact(digits) => quote!(ExpectedBounds::Exact(#digits)),
+            IntegerDigits::Max(digits) => quote!(ExpectedBounds::from_range(&(..=#digits))),
+        }
+    }
+}
+
+/// The smallest and largest values of an integer type.
+/// `isize` and `usize` are assumed to be 64 bits wide; larger literals are rejected by the compiler on smaller targets anyway.
+fn integer_type_bounds(ty: &str) -> Option<(i128, u128)> {
+    let bounds = match ty {
+        "i8" => (i8::MIN as i128, i8::MAX as u128),
+        "i16" => (i16::MIN as i128, i16::MAX as u128),
+        "i32" => (i32::MIN as i128, i32::MAX as u128),
+        "i64" | "isize" => (i64::MIN as i128, i64::MAX as u128),
+        "i128" => (i128::MIN, i128::MAX as u128),
+        "u8" => (0, u8::MAX as u128),
+        "u16" => (0, u16::MAX as u128),
+        "u32" => (0, u32::MAX as u128),
+        "u64" | "usize" => (0, u64::MAX as u128),
+        "u128" => (0, u128::MAX),
+        _ => return None,
+    };
+    Some(bounds)
+}
+
+/// Aborts if an integer literal, possibly negated, does not fit in the integer type `ty`.
+/// Other expressions, e.g. constants, are left to the compiler.
+fn check_literal_fits(expr: &syn::Expr, ty: &Ident) {
+    let Some((min, max)) = integer_type_bounds(&ty.to_string()) else {
+        return;
+    };
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => check_lit_fits(lit, false, ty, min, max),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => {
+            if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) = expr.as_ref() {
+                check_lit_fits(lit, true, ty, min, max);
+            }
+        }
+        syn::Expr::Paren(paren) => check_literal_fits(&paren.expr, ty),
+        syn::Expr::Group(group) => check_literal_fits(&group.expr, ty),
+        _ => {}
+    }
+}
+
+fn check_lit_fits(lit: &LitInt, negative: bool, ty: &Ident, min: i128, max: u128) {
+    let Ok(magnitude) = lit.base10_parse::<u128>() else {
+        return;
+    };
+    if negative {
+        if magnitude > min.unsigned_abs() {
+            abort!(
+                lit.span(), "`-{}` is below `{}::MIN` ({})", magnitude, ty, min;
+                help = "Use a bound that fits in `{}`, or a wider integer type", ty
+            );
+        }
+    } else if magnitude > max {
+        abort!(
+            lit.span(), "`{}` exceeds `{}::MAX` ({})", magnitude, ty, max;
+            help = "Use a bound that fits in `{}`, or a wider integer type", ty
+        );
+    }
+}
 
 /// `RodIntegerContent` is a struct that represents the content of an integer field in a Rod entity.
 /// It is used to parse and validate integer attributes in the `#[rod]` attribute macro.
@@ -12,6 +214,16 @@ use super::{optional_braced, user_defined_error, LengthOrSize, NumberSign};
 /// - `size`: An optional attribute that specifies a range for the integer to be in, or an exact value for the integer.
 /// - `sign`: An optional attribute that specifies the sign of the integer, see [`NumberSign`][crate::types::NumberSign] enum.
 /// - `step`: An optional attribute that specifies that the integer must be a multiple of this value.
+///   A tuple `(step, offset)` specifies a multiple of `step` shifted by `offset`, e.g. `step: (5, 1)` accepts 1, 6, 11, ... and -4. See [`IntegerStep`][crate::types::integer::IntegerStep] struct.
+/// - `one_of`: An optional attribute that specifies a list of allowed values, e.g. `one_of: [80, 443, 8080]`.
+/// - `not_one_of`: An optional attribute that specifies a list of forbidden values.
+/// - `bits_set`: An optional attribute that specifies a mask of bits that must all be set, e.g. `bits_set: 0b0000_1111`.
+/// - `bits_clear`: An optional attribute that specifies a mask of bits that must all be clear, e.g. `bits_clear: 0x80`.
+/// - `non_zero`: An optional attribute that specifies, with `non_zero: true`, that the integer must not be zero.
+/// - `digits`: An optional attribute that specifies the exact number of decimal digits, e.g. `digits: 4` for a PIN code.
+/// - `max_digits`: An optional attribute that specifies the maximum number of decimal digits. See [`IntegerDigits`][crate::types::integer::IntegerDigits] enum.
+/// - `parity`: An optional attribute that specifies whether the integer must be `Even` or `Odd`, see [`NumberParity`][crate::types::integer::NumberParity] enum.
+/// - `eq_field`, `ne_field`, `lt_field`, `le_field`, `gt_field`, `ge_field`: Optional attributes that compare the field to a sibling field of the struct, e.g. `le_field: max_capacity`.
 /// # Usage
 /// ```
 /// use rod::prelude::*;
@@ -34,13 +246,88 @@ use super::{optional_braced, user_defined_error, LengthOrSize, NumberSign};
 pub struct RodIntegerContent {
     size: Option<LengthOrSize>,
     sign: Option<NumberSign>,
-    step: Option<LitInt>,
-    custom_errors: [Option<LitStr>; 3], // size, sign, step
+    step: Option<IntegerStep>,
+    parity: Option<NumberParity>,
+    one_of: Option<IntegerList>,
+    not_one_of: Option<IntegerList>,
+    bits_set: Option<LitInt>,
+    bits_clear: Option<LitInt>,
+    non_zero: bool,
+    digits: Option<IntegerDigits>,
+    field_comparisons: Vec<FieldComparison>,
+    custom_errors: [Option<RuleMessage>; 10], // size, sign, step, parity, one_of, not_one_of, bits_set, bits_clear, non_zero, digits
 }
 
 impl RodIntegerContent {
+    /// Removes the comparisons to other fields, for validations that run without the rest of the struct.
+    pub(crate) fn strip_field_comparisons(&mut self) {
+        self.field_comparisons.clear();
+    }
+    /// Returns the first comparison to another field, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        self.field_comparisons.first()
+    }
+    /// Aborts if a literal bound of `size` does not fit in the integer type `ty`, e.g. `u8 { size: 0..=300 }`.
+    pub(crate) fn check_size_fits(&self, ty: &Ident) {
+        match self.size.as_ref() {
+            Some(LengthOrSize::Exact(exact)) => check_literal_fits(exact, ty),
+            Some(LengthOrSize::Range(range)) => {
+                for bound in range.start.iter().chain(range.end.iter()) {
+                    check_literal_fits(bound, ty);
+                }
+            }
+            None => {}
+        }
+    }
+    pub(crate) fn get_fixes(&self, field_name: &Ident, path: &str) -> Option<proc_macro2::TokenStream> {
+        self.size.as_ref().map(|size| size.fix_integer(field_name, path))
+    }
+    /// Returns the `schema::TypeSchema` describing the rules of the field. Rules other than the type, `size`, `sign` and `step` are described in words.
+    pub(crate) fn get_schema(&self, integer_type: Option<&str>) -> proc_macro2::TokenStream {
+        let mut rules = Vec::new();
+        if let Some(integer_type) = integer_type {
+            rules.push(quote!(__rod_schema::Rule::new(__rod_schema::RuleKind::IntegerType(#integer_type))));
+        }
+        if let Some(size) = self.size.as_ref() {
+            let bounds = size.schema_bounds();
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Size(#bounds)), self.custom_errors[0].as_ref()));
+        }
+        if let Some(sign) = self.sign.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Sign(#sign)), self.custom_errors[1].as_ref()));
+        }
+        if let Some(step) = self.step.as_ref() {
+            let step_value = &step.step;
+            rules.push(match &step.offset {
+                None => schema_rule(quote!(__rod_schema::RuleKind::Step((#step_value) as f64)), self.custom_errors[2].as_ref()),
+                Some(offset) => schema_other_rule(&format!("step {} offset {}", step_value, offset), self.custom_errors[2].as_ref()),
+            });
+        }
+        if let Some(parity) = self.parity.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Other(#parity)), self.custom_errors[3].as_ref()));
+        }
+        if let Some(one_of) = self.one_of.as_ref() {
+            rules.push(schema_other_rule(&format!("one of {}", one_of.describe()), self.custom_errors[4].as_ref()));
+        }
+        if let Some(not_one_of) = self.not_one_of.as_ref() {
+            rules.push(schema_other_rule(&format!("not one of {}", not_one_of.describe()), self.custom_errors[5].as_ref()));
+        }
+        if let Some(bits_set) = self.bits_set.as_ref() {
+            rules.push(schema_other_rule(&format!("bits {} set", bits_set), self.custom_errors[6].as_ref()));
+        }
+        if let Some(bits_clear) = self.bits_clear.as_ref() {
+            rules.push(schema_other_rule(&format!("bits {} clear", bits_clear), self.custom_errors[7].as_ref()));
+        }
+        if self.non_zero {
+            rules.push(schema_other_rule("non-zero", self.custom_errors[8].as_ref()));
+        }
+        if let Some(digits) = self.digits.as_ref() {
+            rules.push(schema_other_rule(&digits.describe(), self.custom_errors[9].as_ref()));
+        }
+        rules.extend(self.field_comparisons.iter().map(FieldComparison::schema_rule));
+        quote!(__rod_schema::TypeSchema::Integer(vec![#(#rules),*]))
+    }
     pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
+        let path = field_path(field_name);
         let size_opt = self.size.as_ref().map(|size| {
             if let Some(msg) = self.custom_errors[0].as_ref() {
                 size.validate_integer_with_custom_error(field_name, wrap_return, msg)
@@ -69,27 +356,140 @@ impl RodIntegerContent {
             }
         });
         let step_opt = self.step.as_ref().map(|step| {
+            let check = step.check(field_name);
             let ret = if let Some(msg) = self.custom_errors[2].as_ref() {
                 user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(step.error(&path, field_name))
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let parity_opt = self.parity.as_ref().map(|parity| {
+            let check = parity.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[3].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Integer(IntegerValidation::Parity(#path, #field_name.clone().into(), #parity))
+                })
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let one_of_opt = self.one_of.as_ref().map(|one_of| {
+            let check = one_of.check(field_name);
+            let values = one_of.describe();
+            let ret = if let Some(msg) = self.custom_errors[4].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Integer(IntegerValidation::OneOf(#path, #field_name.clone().into(), #values))
+                })
+            };
+            quote! {
+                if !#check {
+                    #ret;
+                }
+            }
+        });
+        let not_one_of_opt = self.not_one_of.as_ref().map(|not_one_of| {
+            let check = not_one_of.check(field_name);
+            let values = not_one_of.describe();
+            let ret = if let Some(msg) = self.custom_errors[5].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Integer(IntegerValidation::NotOneOf(#path, #field_name.clone().into(), #values))
+                })
+            };
+            quote! {
+                if #check {
+                    #ret;
+                }
+            }
+        });
+        let bits_set_opt = self.bits_set.as_ref().map(|mask| {
+            let ret = if let Some(msg) = self.custom_errors[6].as_ref() {
+                user_defined_error(wrap_return, msg)
             } else {
                 wrap_return(quote! {
-                    RodValidateError::Integer(IntegerValidation::Step(#path, #field_name.clone().into(), #step.into()))
+                    RodValidateError::Integer(IntegerValidation::BitsSet(#path, #field_name.clone().into(), (#mask & !*#field_name).into()))
                 })
             };
             quote! {
-                if #field_name % #step != 0 {
+                if *#field_name & #mask != #mask {
                     #ret;
                 }
             }
         });
+        let bits_clear_opt = self.bits_clear.as_ref().map(|mask| {
+            let ret = if let Some(msg) = self.custom_errors[7].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Integer(IntegerValidation::BitsClear(#path, #field_name.clone().into(), (#mask & *#field_name).into()))
+                })
+            };
+            quote! {
+                if *#field_name & #mask != 0 {
+                    #ret;
+                }
+            }
+        });
+        let non_zero_opt = self.non_zero.then(|| {
+            let ret = if let Some(msg) = self.custom_errors[8].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Integer(IntegerValidation::NonZero(#path))
+                })
+            };
+            quote! {
+                if *#field_name == 0 {
+                    #ret;
+                }
+            }
+        });
+        let digits_opt = self.digits.as_ref().map(|digits| {
+            let check = digits.check(field_name);
+            let expected = digits.expected();
+            let ret = if let Some(msg) = self.custom_errors[9].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote! {
+                    RodValidateError::Integer(IntegerValidation::Digits(#path, #field_name.clone().into(), #expected))
+                })
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let comparisons_opt = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, None)).collect::<proc_macro2::TokenStream>();
         quote! {
             #size_opt
             #sign_opt
             #step_opt
+            #parity_opt
+            #one_of_opt
+            #not_one_of_opt
+            #bits_set_opt
+            #bits_clear_opt
+            #non_zero_opt
+            #digits_opt
+            #comparisons_opt
         }
     }
 
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let size_opt = self.size.as_ref().map(|size| {
             if let Some(msg) = self.custom_errors[0].as_ref() {
                 size.validate_integer_with_custom_error(field_name, wrap_return, msg)
@@ -122,17 +522,115 @@ impl RodIntegerContent {
             } else {
                 user_defined_error(wrap_return, custom_error)
             };
+            let check = step.check(field_name);
             quote! {
-                if #field_name % #step != 0 {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let parity_opt = self.parity.as_ref().map(|parity| {
+            let check = parity.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[3].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let one_of_opt = self.one_of.as_ref().map(|one_of| {
+            let check = one_of.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[4].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !#check {
+                    #ret;
+                }
+            }
+        });
+        let not_one_of_opt = self.not_one_of.as_ref().map(|not_one_of| {
+            let check = not_one_of.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[5].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if #check {
+                    #ret;
+                }
+            }
+        });
+        let bits_set_opt = self.bits_set.as_ref().map(|mask| {
+            let ret = if let Some(msg) = self.custom_errors[6].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if *#field_name & #mask != #mask {
+                    #ret;
+                }
+            }
+        });
+        let bits_clear_opt = self.bits_clear.as_ref().map(|mask| {
+            let ret = if let Some(msg) = self.custom_errors[7].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if *#field_name & #mask != 0 {
                     #ret;
                 }
             }
         });
 
+        let non_zero_opt = self.non_zero.then(|| {
+            let ret = if let Some(msg) = self.custom_errors[8].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if *#field_name == 0 {
+                    #ret;
+                }
+            }
+        });
+        let digits_opt = self.digits.as_ref().map(|digits| {
+            let check = digits.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[9].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let comparisons_opt = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, Some(custom_error))).collect::<proc_macro2::TokenStream>();
         quote! {
             #size_opt
             #sign_opt
             #step_opt
+            #parity_opt
+            #one_of_opt
+            #not_one_of_opt
+            #bits_set_opt
+            #bits_clear_opt
+            #non_zero_opt
+            #digits_opt
+            #comparisons_opt
         }
     }
 }
@@ -146,14 +644,30 @@ impl Parse for RodIntegerContent {
                 size: None,
                 sign: None,
                 step: None,
-                custom_errors: [None, None, None],
+                parity: None,
+                one_of: None,
+                not_one_of: None,
+                bits_set: None,
+                bits_clear: None,
+                non_zero: false,
+                digits: None,
+                field_comparisons: Vec::new(),
+                custom_errors: [None, None, None, None, None, None, None, None, None, None],
             }),
         };
         let mut size = None;
         let mut sign = None;
         let mut step = None;
-        let mut message: Option<LitStr> = None;
-        let mut custom_errors: [Option<LitStr>; 3] = [None, None, None]; // size, sign, step
+        let mut parity = None;
+        let mut one_of = None;
+        let mut not_one_of = None;
+        let mut bits_set = None;
+        let mut bits_clear = None;
+        let mut non_zero = false;
+        let mut digits = None;
+        let mut field_comparisons = Vec::new();
+        let mut message: Option<RuleMessage> = None;
+        let mut custom_errors: [Option<RuleMessage>; 10] = [None, None, None, None, None, None, None, None, None, None]; // size, sign, step, parity, one_of, not_one_of, bits_set, bits_clear, non_zero, digits
         while !inner.is_empty() {
             let lookahead = inner.lookahead1();
             if lookahead.peek(Ident) {
@@ -179,6 +693,73 @@ impl Parse for RodIntegerContent {
                     if let Some(msg) = message.take() {
                         custom_errors[2] = Some(msg);
                     }
+                } else if ident == "parity" {
+                    check_already_used_attr!(parity, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    parity = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[3] = Some(msg);
+                    }
+                } else if ident == "one_of" {
+                    check_already_used_attr!(one_of, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    one_of = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[4] = Some(msg);
+                    }
+                } else if ident == "not_one_of" {
+                    check_already_used_attr!(not_one_of, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    not_one_of = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[5] = Some(msg);
+                    }
+                } else if ident == "bits_set" {
+                    check_already_used_attr!(bits_set, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    bits_set = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[6] = Some(msg);
+                    }
+                } else if ident == "bits_clear" {
+                    check_already_used_attr!(bits_clear, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    bits_clear = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[7] = Some(msg);
+                    }
+                } else if ident == "non_zero" {
+                    inner.parse::<syn::Token![:]>()?;
+                    let lit: syn::LitBool = inner.parse()?;
+                    if !lit.value {
+                        abort!(
+                            lit.span(), "`non_zero: false` has no effect";
+                            help = "Remove the `non_zero` attribute"
+                        );
+                    }
+                    non_zero = true;
+                    if let Some(msg) = message.take() {
+                        custom_errors[8] = Some(msg);
+                    }
+                } else if ident == "digits" || ident == "max_digits" {
+                    if digits.is_some() {
+                        abort!(
+                            ident.span(), "`digits` and `max_digits` cannot be used together";
+                            help = "Use `digits` for an exact number of digits, or `max_digits` for an upper bound"
+                        );
+                    }
+                    inner.parse::<syn::Token![:]>()?;
+                    let lit: LitInt = inner.parse()?;
+                    let count = lit.base10_parse::<u32>()?;
+                    if count == 0 {
+                        abort!(lit.span(), "`{}` must be at least 1, since zero has one digit", ident);
+                    }
+                    digits = Some(if ident == "digits" { IntegerDigits::Exact(count) } else { IntegerDigits::Max(count) });
+                    if let Some(msg) = message.take() {
+                        custom_errors[9] = Some(msg);
+                    }
+                } else if let Some(comparison) = FieldComparison::parse_rule(&ident, &inner, &mut message, true)? {
+                    field_comparisons.push(comparison);
                 } else {
                     abort!(
                         ident.span(),
@@ -187,8 +768,7 @@ impl Parse for RodIntegerContent {
                 }
                 _ = inner.parse::<syn::Token![,]>();
             } else if lookahead.peek(syn::Token![?]) {
-                let _q: syn::Token![?] = inner.parse()?;
-                let result: LitStr = inner.parse()?;
+                let result: RuleMessage = inner.parse()?;
                 message = Some(result);
             } else {
                 abort!(
@@ -201,6 +781,14 @@ impl Parse for RodIntegerContent {
             size,
             sign,
             step,
+            parity,
+            one_of,
+            not_one_of,
+            bits_set,
+            bits_clear,
+            non_zero,
+            digits,
+            field_comparisons,
             custom_errors,
         })
     }
@@ -1,10 +1,10 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, Ident, LitStr};
+use syn::{parse::Parse, Expr, Ident, LitFloat};
 use quote::{format_ident, quote};
 
-use crate::{RodAttr, RodAttrContent};
+use crate::{RodAttr, RodAttrContent, RodAttrType, TypeEnum};
 
-use super::{optional_braced, LengthOrSize};
+use super::{bind_field_path, field_path, float::{exact_float, parse_float_lit}, optional_braced, schema_other_rule, schema_rule, user_defined_error, FieldComparison, LengthOrSize, RuleMessage};
 
 macro_rules! rod_content_match {
     ($content:expr, $field_access:expr, $wrap_return:expr, [ $( $variant:ident ),* ]) => {
@@ -23,11 +23,133 @@ macro_rules! rod_content_match {
     };
 }
 
+/// `IterableSum` is the expected total of a numeric iterable, set with `sum_to: 1.0 ~ 1e-6`.
+/// The tolerance is only allowed for float items.
+struct IterableSum {
+    value: Expr,
+    tolerance: Option<LitFloat>,
+}
+
+impl Parse for IterableSum {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let value = input.parse()?;
+        let tolerance = if input.peek(syn::Token![~]) {
+            input.parse::<syn::Token![~]>()?;
+            Some(parse_float_lit(input, "tolerance")?)
+        } else {
+            None
+        };
+        Ok(IterableSum { value, tolerance })
+    }
+}
+
+/// Turns the integer literals of a `sum` or `mean` bound into float literals, so that `mean: 1..=5` can be compared with floats.
+fn float_bound(bound: LengthOrSize) -> LengthOrSize {
+    match bound {
+        LengthOrSize::Exact(value) => LengthOrSize::Exact(exact_float(value)),
+        LengthOrSize::Range(mut range) => {
+            range.start = range.start.map(|start| Box::new(exact_float(*start)));
+            range.end = range.end.map(|end| Box::new(exact_float(*end)));
+            LengthOrSize::Range(range)
+        }
+    }
+}
+
+/// `IterableOrder` is the order that the items of an iterable must be in, set with `sorted: Ascending`.
+/// The strict orders also reject equal neighbouring items.
+enum IterableOrder {
+    Ascending,
+    Descending,
+    StrictAscending,
+    StrictDescending,
+}
+
+impl Parse for IterableOrder {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "Ascending" => Ok(IterableOrder::Ascending),
+            "Descending" => Ok(IterableOrder::Descending),
+            "StrictAscending" => Ok(IterableOrder::StrictAscending),
+            "StrictDescending" => Ok(IterableOrder::StrictDescending),
+            _ => Err(syn::Error::new(ident.span(), "Expected `sorted` to be one of Ascending, Descending, StrictAscending, StrictDescending")),
+        }
+    }
+}
+
+impl IterableOrder {
+    fn name(&self) -> &'static str {
+        match self {
+            IterableOrder::Ascending => "ascending",
+            IterableOrder::Descending => "descending",
+            IterableOrder::StrictAscending => "strictly ascending",
+            IterableOrder::StrictDescending => "strictly descending",
+        }
+    }
+    /// The orderings that a pair of neighbouring items may have, comparing the previous item to the next one.
+    fn allowed(&self) -> proc_macro2::TokenStream {
+        match self {
+            IterableOrder::Ascending => quote!(std::cmp::Ordering::Less | std::cmp::Ordering::Equal),
+            IterableOrder::Descending => quote!(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal),
+            IterableOrder::StrictAscending => quote!(std::cmp::Ordering::Less),
+            IterableOrder::StrictDescending => quote!(std::cmp::Ordering::Greater),
+        }
+    }
+}
+
+/// `IterableLengthBy` selects how the length of an iterable is measured, set with `length_by: Count`.
+/// `Len` calls `.len()`, which most collections have. `Count` counts the items of `.into_iter()`, for iterables without `len()`.
+enum IterableLengthBy {
+    Len,
+    Count,
+}
+
+impl Parse for IterableLengthBy {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "Len" => Ok(IterableLengthBy::Len),
+            "Count" => Ok(IterableLengthBy::Count),
+            _ => Err(syn::Error::new(ident.span(), "Expected `length_by` to be one of Len, Count")),
+        }
+    }
+}
+
+/// `RodIterableContent` validates collections. `item` applies to every item, while `items: [...]` applies a rule to each position,
+/// like `Tuple` does for tuples. Positions past the end of the collection are not checked, so combine `items` with `length` for fixed layouts.
+/// `first` and `last` apply a rule to the first and last item only, and are not checked for empty collections.
 pub struct RodIterableContent {
-    pub(crate) item: Box<RodAttr>,
+    item: Option<Box<RodAttr>>,
+    items: Vec<RodAttr>,
+    first: Option<Box<RodAttr>>,
+    last: Option<Box<RodAttr>>,
     pub(crate) length: Option<LengthOrSize>,
-    custom_item_error: Option<LitStr>,
-    custom_length_error: Option<LitStr>,
+    min_length: Option<LengthOrSize>,
+    max_length: Option<LengthOrSize>,
+    length_by: Option<IterableLengthBy>,
+    allow_empty: Option<bool>,
+    sum_to: Option<IterableSum>,
+    sum: Option<LengthOrSize>,
+    mean: Option<LengthOrSize>,
+    unique: bool,
+    unique_by: Option<Expr>,
+    sorted: Option<IterableOrder>,
+    contains: Option<Expr>,
+    not_contains: Option<Expr>,
+    custom_item_error: Option<RuleMessage>,
+    custom_items_error: Option<RuleMessage>,
+    custom_first_error: Option<RuleMessage>,
+    custom_last_error: Option<RuleMessage>,
+    custom_length_error: Option<RuleMessage>,
+    custom_min_length_error: Option<RuleMessage>,
+    custom_max_length_error: Option<RuleMessage>,
+    custom_empty_error: Option<RuleMessage>,
+    custom_sum_error: Option<RuleMessage>,
+    custom_mean_error: Option<RuleMessage>,
+    custom_unique_error: Option<RuleMessage>,
+    custom_sorted_error: Option<RuleMessage>,
+    custom_contains_error: Option<RuleMessage>,
+    custom_not_contains_error: Option<RuleMessage>,
 }
 
 impl Parse for RodIterableContent {
@@ -38,16 +160,44 @@ impl Parse for RodIterableContent {
             None => {
                 abort!(
                     input.span(),
-                    "Type Iterable must have an `item` attribute";
+                    "Type Iterable must have an `item` or `items` attribute";
                     help = "Example: `#[rod(Iterable { item: String, length: 10 })]`"
                 );
             }
         };
-        let mut item = None;
+        let mut item: Option<RodAttr> = None;
+        let mut items: Option<Vec<RodAttr>> = None;
+        let mut first: Option<RodAttr> = None;
+        let mut last: Option<RodAttr> = None;
         let mut length = None;
-        let mut custom_item_error: Option<LitStr> = None;
-        let mut custom_length_error: Option<LitStr> = None;
-        let mut message: Option<LitStr> = None;
+        let mut min_length = None;
+        let mut max_length = None;
+        let mut length_by: Option<IterableLengthBy> = None;
+        let mut length_by_span = None;
+        let mut allow_empty = None;
+        let mut sum_to: Option<IterableSum> = None;
+        let mut sum: Option<LengthOrSize> = None;
+        let mut mean: Option<LengthOrSize> = None;
+        let mut unique: Option<Ident> = None;
+        let mut unique_by: Option<Expr> = None;
+        let mut sorted: Option<IterableOrder> = None;
+        let mut contains: Option<Expr> = None;
+        let mut not_contains: Option<Expr> = None;
+        let mut custom_item_error: Option<RuleMessage> = None;
+        let mut custom_items_error: Option<RuleMessage> = None;
+        let mut custom_first_error: Option<RuleMessage> = None;
+        let mut custom_last_error: Option<RuleMessage> = None;
+        let mut custom_length_error: Option<RuleMessage> = None;
+        let mut custom_min_length_error: Option<RuleMessage> = None;
+        let mut custom_max_length_error: Option<RuleMessage> = None;
+        let mut custom_empty_error: Option<RuleMessage> = None;
+        let mut custom_sum_error: Option<RuleMessage> = None;
+        let mut custom_mean_error: Option<RuleMessage> = None;
+        let mut custom_unique_error: Option<RuleMessage> = None;
+        let mut custom_sorted_error: Option<RuleMessage> = None;
+        let mut custom_contains_error: Option<RuleMessage> = None;
+        let mut custom_not_contains_error: Option<RuleMessage> = None;
+        let mut message: Option<RuleMessage> = None;
         while !inner.is_empty() {
             let lookahead = inner.lookahead1();
             if lookahead.peek(Ident) {
@@ -59,6 +209,36 @@ impl Parse for RodIterableContent {
                     if let Some(msg) = message.take() {
                         custom_item_error = Some(msg);
                     }
+                } else if ident == "items" {
+                    check_already_used_attr!(items, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    let content;
+                    syn::bracketed!(content in inner);
+                    let positions = syn::punctuated::Punctuated::<RodAttr, syn::Token![,]>::parse_terminated(&content)?;
+                    if positions.is_empty() {
+                        abort!(
+                            ident.span(), "`items` must contain at least one rule";
+                            help = "Example: `#[rod(Iterable { items: [i32 { size: 0..10 }, i32 { sign: Positive }] })]`"
+                        );
+                    }
+                    items = Some(positions.into_iter().collect());
+                    if let Some(msg) = message.take() {
+                        custom_items_error = Some(msg);
+                    }
+                } else if ident == "first" {
+                    check_already_used_attr!(first, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    first = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_first_error = Some(msg);
+                    }
+                } else if ident == "last" {
+                    check_already_used_attr!(last, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    last = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_last_error = Some(msg);
+                    }
                 } else if ident == "length" || ident == "size" {
                     check_already_used_attr!(length, ident.span());
                     inner.parse::<syn::Token![:]>()?;
@@ -66,6 +246,88 @@ impl Parse for RodIterableContent {
                     if let Some(msg) = message.take() {
                         custom_length_error = Some(msg);
                     }
+                } else if ident == "min_length" {
+                    check_already_used_attr!(min_length, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    min_length = Some(LengthOrSize::parse_bound(&inner, &ident)?);
+                    if let Some(msg) = message.take() {
+                        custom_min_length_error = Some(msg);
+                    }
+                } else if ident == "max_length" {
+                    check_already_used_attr!(max_length, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    max_length = Some(LengthOrSize::parse_bound(&inner, &ident)?);
+                    if let Some(msg) = message.take() {
+                        custom_max_length_error = Some(msg);
+                    }
+                } else if ident == "length_by" {
+                    check_already_used_attr!(length_by, ident.span());
+                    length_by_span = Some(ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    length_by = Some(inner.parse()?);
+                } else if ident == "allow_empty" {
+                    check_already_used_attr!(allow_empty, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    let value: syn::LitBool = inner.parse()?;
+                    allow_empty = Some(value.value);
+                    if let Some(msg) = message.take() {
+                        custom_empty_error = Some(msg);
+                    }
+                } else if ident == "sum_to" {
+                    check_already_used_attr!(sum_to, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    sum_to = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_sum_error = Some(msg);
+                    }
+                } else if ident == "sum" {
+                    check_already_used_attr!(sum, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    sum = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_sum_error = Some(msg);
+                    }
+                } else if ident == "mean" {
+                    check_already_used_attr!(mean, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    mean = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_mean_error = Some(msg);
+                    }
+                } else if ident == "unique" {
+                    check_already_used_attr!(unique, ident.span());
+                    unique = Some(ident);
+                    if let Some(msg) = message.take() {
+                        custom_unique_error = Some(msg);
+                    }
+                } else if ident == "unique_by" {
+                    check_already_used_attr!(unique_by, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    unique_by = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_unique_error = Some(msg);
+                    }
+                } else if ident == "sorted" {
+                    check_already_used_attr!(sorted, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    sorted = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_sorted_error = Some(msg);
+                    }
+                } else if ident == "contains" {
+                    check_already_used_attr!(contains, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    contains = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_contains_error = Some(msg);
+                    }
+                } else if ident == "not_contains" {
+                    check_already_used_attr!(not_contains, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    not_contains = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_not_contains_error = Some(msg);
+                    }
                 } else {
                     abort!(
                         ident.span(),
@@ -74,8 +336,7 @@ impl Parse for RodIterableContent {
                 }
                 _ = inner.parse::<syn::Token![,]>();
             } else if lookahead.peek(syn::Token![?]) {
-                let _q: syn::Token![?] = inner.parse()?;
-                let result: LitStr = inner.parse()?;
+                let result: RuleMessage = inner.parse()?;
                 message = Some(result);
             } else {
                 abort!(
@@ -85,85 +346,587 @@ impl Parse for RodIterableContent {
             }
         }
 
-        if let Some(item) = item {
-            Ok(RodIterableContent {
-                item: Box::new(item),
-                length,
-                custom_item_error,
-                custom_length_error,
-            })
-        } else {
+        if length.is_some() && (min_length.is_some() || max_length.is_some()) {
+            abort!(
+                input.span(), "`length` cannot be combined with `min_length` or `max_length`";
+                help = "Use either `length: 1..=10` or `min_length: 1, max_length: 10`"
+            );
+        }
+        if sum.is_some() && sum_to.is_some() {
+            abort!(
+                input.span(), "`sum` cannot be combined with `sum_to`";
+                help = "Use either `sum: 0..=100` or `sum_to: 100`"
+            );
+        }
+        if let Some(span) = length_by_span && length.is_none() && min_length.is_none() && max_length.is_none() {
+            proc_macro_error::emit_warning!(span, "`length_by` has no effect without `length`, `min_length` or `max_length`");
+        }
+        let items = items.unwrap_or_default();
+        // A `Literal` rule can be used for items of any type, so it is only used for the item type if there is no other rule
+        let rules = || item.iter().chain(items.iter()).chain(first.as_ref()).chain(last.as_ref());
+        let Some(element) = rules().find(|rule| !matches!(rule.ty, RodAttrType::Literal(_))).or(rules().next()) else {
             abort!(
-                input.span(), "Type Iterable must have an `item` attribute";
+                input.span(), "Type Iterable must have an `item`, `items`, `first` or `last` attribute";
                 help = "Example: `#[rod(Iterable { item: String, length: 10 })]`"
             );
+        };
+        if let Some(position) = rules().find(|rule| rule.ty != element.ty && !matches!(rule.ty, RodAttrType::Literal(_))) {
+            abort!(
+                position.span, "Expected every rule of the iterable to be for {}, found {}", element.ty, position.ty;
+                help = "All items of a collection have the same type"
+            );
+        }
+        if let Some(sum) = sum_to.as_mut() {
+            match &element.content {
+                RodAttrContent::Float(_) => sum.value = exact_float(sum.value.clone()),
+                RodAttrContent::Integer(_) => {
+                    if let Some(tolerance) = sum.tolerance.as_ref() {
+                        abort!(
+                            tolerance.span(), "A tolerance can only be used for the sum of float items";
+                            help = "Remove the tolerance, integer sums are compared exactly"
+                        );
+                    }
+                }
+                _ => abort!(
+                    element.span, "`sum_to` can only be used with integer or float items";
+                    help = "Example: `#[rod(Iterable { item: f64, sum_to: 1.0 ~ 1e-6 })]`"
+                ),
+            }
         }
+        if sum.is_some() || mean.is_some() {
+            let is_float = match &element.content {
+                RodAttrContent::Float(_) => true,
+                RodAttrContent::Integer(_) => false,
+                _ => abort!(
+                    element.span, "`sum` and `mean` can only be used with integer or float items";
+                    help = "Example: `#[rod(Iterable { item: u8, sum: 0..=100 })]`"
+                ),
+            };
+            if is_float {
+                sum = sum.map(float_bound);
+            }
+            // The mean is always computed as an `f64`
+            mean = mean.map(float_bound);
+        }
+        Ok(RodIterableContent {
+            item: item.map(Box::new),
+            items,
+            first: first.map(Box::new),
+            last: last.map(Box::new),
+            length,
+            min_length,
+            max_length,
+            length_by,
+            allow_empty,
+            sum_to,
+            sum,
+            mean,
+            unique: unique.is_some(),
+            unique_by,
+            sorted,
+            contains,
+            not_contains,
+            custom_item_error,
+            custom_items_error,
+            custom_first_error,
+            custom_last_error,
+            custom_length_error,
+            custom_min_length_error,
+            custom_max_length_error,
+            custom_empty_error,
+            custom_sum_error,
+            custom_mean_error,
+            custom_unique_error,
+            custom_sorted_error,
+            custom_contains_error,
+            custom_not_contains_error,
+        })
     }
 }
 
 impl RodIterableContent {
-    pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let inner_validation = if let Some(msg) = self.custom_item_error.as_ref() {
-            rod_content_match!(
-                &self.item.content,
-                &format_ident!("item"),
-                wrap_return,
-                msg,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
-            )
+    /// Removes the comparisons to other fields from the rules of the items, for validations that run without the rest of the struct.
+    pub(crate) fn strip_field_comparisons(&mut self) {
+        let items = self.item.iter_mut().chain(self.first.iter_mut()).chain(self.last.iter_mut()).map(|item| &mut **item);
+        items.chain(self.items.iter_mut()).for_each(crate::strip_field_comparisons);
+    }
+    /// Returns the first comparison to another field in the rules of the items, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        let items = self.item.iter().chain(self.first.iter()).chain(self.last.iter()).map(|item| &**item);
+        items.chain(self.items.iter()).find_map(crate::find_field_comparison)
+    }
+    /// Returns the `schema::TypeSchema` describing the rules of the field, where `item_type` is the type of its items, if it is known.
+    /// Rules other than the lengths and `item` are described in words.
+    pub(crate) fn get_schema(&self, item_type: Option<&syn::Type>) -> proc_macro2::TokenStream {
+        let item = match self.item.as_ref() {
+            Some(item) => crate::schema::get_attr_schema(item, item_type),
+            None => quote!(__rod_schema::TypeSchema::Skip),
+        };
+        let mut rules = [
+            (&self.length, &self.custom_length_error),
+            (&self.min_length, &self.custom_min_length_error),
+            (&self.max_length, &self.custom_max_length_error),
+        ].into_iter().filter_map(|(length, custom_error)| {
+            length.as_ref().map(|length| {
+                let bounds = length.schema_bounds();
+                schema_rule(quote!(__rod_schema::RuleKind::Length(#bounds)), custom_error.as_ref())
+            })
+        }).collect::<Vec<_>>();
+        if self.allow_empty == Some(false) {
+            rules.push(schema_other_rule("not empty", self.custom_empty_error.as_ref()));
+        }
+        if !self.items.is_empty() {
+            rules.push(schema_other_rule(&format!("{} items validated by position", self.items.len()), self.custom_items_error.as_ref()));
+        }
+        if self.first.is_some() {
+            rules.push(schema_other_rule("first item validated", self.custom_first_error.as_ref()));
+        }
+        if self.last.is_some() {
+            rules.push(schema_other_rule("last item validated", self.custom_last_error.as_ref()));
+        }
+        if let Some(sum_to) = self.sum_to.as_ref() {
+            let value = &sum_to.value;
+            rules.push(schema_other_rule(&format!("sums to {}", quote!(#value)), self.custom_sum_error.as_ref()));
+        }
+        if let Some(sum) = self.sum.as_ref() {
+            rules.push(schema_other_rule(&format!("sum {}", sum.describe()), self.custom_sum_error.as_ref()));
+        }
+        if let Some(mean) = self.mean.as_ref() {
+            rules.push(schema_other_rule(&format!("mean {}", mean.describe()), self.custom_mean_error.as_ref()));
+        }
+        if self.unique || self.unique_by.is_some() {
+            rules.push(schema_other_rule("unique", self.custom_unique_error.as_ref()));
+        }
+        if let Some(sorted) = self.sorted.as_ref() {
+            rules.push(schema_other_rule(&format!("sorted {}", sorted.name()), self.custom_sorted_error.as_ref()));
+        }
+        if let Some(contains) = self.contains.as_ref() {
+            rules.push(schema_other_rule(&format!("contains {}", quote!(#contains)), self.custom_contains_error.as_ref()));
+        }
+        if let Some(not_contains) = self.not_contains.as_ref() {
+            rules.push(schema_other_rule(&format!("does not contain {}", quote!(#not_contains)), self.custom_not_contains_error.as_ref()));
+        }
+        quote!(__rod_schema::TypeSchema::Iterable { item: Box::new(#item), rules: vec![#(#rules),*] })
+    }
+    /// The rule that determines the item type: the first of `item`, `items`, `first` and `last` that is not a `Literal`.
+    /// Parsing ensures that at least one of them is set.
+    pub(crate) fn element(&self) -> &RodAttr {
+        let rules = || self.item.as_deref().into_iter().chain(self.items.iter()).chain(self.first.as_deref()).chain(self.last.as_deref());
+        rules()
+            .find(|rule| !matches!(rule.ty, RodAttrType::Literal(_)))
+            .or(rules().next())
+            .expect("Iterable has no item rule")
+    }
+    fn validate_length(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let length = match self.length_by {
+            Some(IterableLengthBy::Count) => quote!(#field_name.into_iter().count()),
+            Some(IterableLengthBy::Len) | None => quote!(#field_name.len()),
+        };
+        [
+            (&self.length, &self.custom_length_error),
+            (&self.min_length, &self.custom_min_length_error),
+            (&self.max_length, &self.custom_max_length_error),
+        ].into_iter().filter_map(|(bound, msg)| {
+            bound.as_ref().map(|bound| match msg.as_ref().or(custom_error) {
+                Some(msg) => bound.validate_iterable_with_custom_error(&length, wrap_return, msg),
+                None => bound.validate_iterable(field_name, &length, wrap_return),
+            })
+        }).collect()
+    }
+    /// An empty iterable passes every `item` rule vacuously, so `allow_empty: false` is the only way to require at least one item.
+    fn validate_non_empty(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> Option<proc_macro2::TokenStream> {
+        if self.allow_empty != Some(false) {
+            return None;
+        }
+        let path = field_path(field_name);
+        let ret = match self.custom_empty_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Empty(#path))
+            }),
+        };
+        Some(quote! {
+            if #field_name.into_iter().next().is_none() {
+                #ret;
+            }
+        })
+    }
+    /// Float items are summed and compared with `RodFloat::rod_approx_eq`. Integer items are summed with overflow checks,
+    /// so an overflowing sum fails instead of panicking.
+    fn validate_sum(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> Option<proc_macro2::TokenStream> {
+        let IterableSum { value, tolerance } = self.sum_to.as_ref()?;
+        let ty = match &self.element().ty {
+            RodAttrType::Float(TypeEnum::Type(ty)) | RodAttrType::Integer(TypeEnum::Type(ty)) => ty,
+            _ => unreachable!(),
+        };
+        let path = field_path(field_name);
+        let is_float = matches!(self.element().content, RodAttrContent::Float(_));
+        let expected = match tolerance {
+            Some(tolerance) => quote!(format!("{} ~ {}", #value, #tolerance)),
+            None => quote!(format!("{}", #value)),
+        };
+        let ret = match self.custom_sum_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None if is_float => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Sum(#path, rod_sum.to_string(), #expected))
+            }),
+            None => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Sum(#path, rod_sum.map_or_else(|| String::from("an overflow"), |sum| sum.to_string()), #expected))
+            }),
+        };
+        Some(if is_float {
+            let tolerance = match tolerance {
+                Some(tolerance) => quote!(Some(#tolerance)),
+                None => quote!(None),
+            };
+            quote! {
+                {
+                    let rod_sum = #field_name.into_iter().sum::<#ty>();
+                    if !RodFloat::rod_approx_eq(rod_sum, #value, #tolerance) {
+                        #ret;
+                    }
+                }
+            }
         } else {
-            rod_content_match!(
-                &self.item.content,
-                &format_ident!("item"),
-                wrap_return,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
-            )
+            quote! {
+                {
+                    let rod_sum = #field_name.into_iter().try_fold(0 as #ty, |sum, item| sum.checked_add(*item));
+                    if rod_sum != Some(#value) {
+                        #ret;
+                    }
+                }
+            }
+        })
+    }
+    /// Like `validate_sum`, but the total is checked against a value or a range. Exact float totals are compared with `RodFloat::rod_approx_eq`.
+    fn validate_sum_bound(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> Option<proc_macro2::TokenStream> {
+        let bound = self.sum.as_ref()?;
+        let ty = match &self.element().ty {
+            RodAttrType::Float(TypeEnum::Type(ty)) | RodAttrType::Integer(TypeEnum::Type(ty)) => ty,
+            _ => unreachable!(),
         };
-        let length_opt = self.length.as_ref().map(|length| {
-            if let Some(msg) = self.custom_length_error.as_ref() {
-                length.validate_iterable_with_custom_error(field_name, wrap_return, msg)
-            } else {
-                length.validate_iterable(field_name, wrap_return)
+        let path = field_path(field_name);
+        let is_float = matches!(self.element().content, RodAttrContent::Float(_));
+        let expected = match bound {
+            LengthOrSize::Exact(value) => quote!(format!("{}", #value)),
+            LengthOrSize::Range(range) => quote!(format!("a value in the range {:?}", #range)),
+        };
+        let ret = match self.custom_sum_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None if is_float => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Sum(#path, rod_sum.to_string(), #expected))
+            }),
+            None => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Sum(#path, rod_sum.map_or_else(|| String::from("an overflow"), |sum| sum.to_string()), #expected))
+            }),
+        };
+        let (sum, check) = match (is_float, bound) {
+            (true, LengthOrSize::Exact(value)) => (
+                quote!(#field_name.into_iter().sum::<#ty>()),
+                quote!(RodFloat::rod_approx_eq(rod_sum, #value, None)),
+            ),
+            (true, LengthOrSize::Range(range)) => (
+                quote!(#field_name.into_iter().sum::<#ty>()),
+                quote!((#range).contains(&rod_sum)),
+            ),
+            (false, LengthOrSize::Exact(value)) => (
+                quote!(#field_name.into_iter().try_fold(0 as #ty, |sum, item| sum.checked_add(*item))),
+                quote!(rod_sum == Some(#value)),
+            ),
+            (false, LengthOrSize::Range(range)) => (
+                quote!(#field_name.into_iter().try_fold(0 as #ty, |sum, item| sum.checked_add(*item))),
+                quote!(rod_sum.is_some_and(|sum| (#range).contains(&sum))),
+            ),
+        };
+        Some(quote! {
+            {
+                let rod_sum = #sum;
+                if !(#check) {
+                    #ret;
+                }
+            }
+        })
+    }
+    /// The mean is computed as an `f64` for integer and float items alike. An empty iterable has no mean and is not checked.
+    fn validate_mean(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> Option<proc_macro2::TokenStream> {
+        let bound = self.mean.as_ref()?;
+        let path = field_path(field_name);
+        let (check, expected) = match bound {
+            LengthOrSize::Exact(value) => (
+                quote!(RodFloat::rod_approx_eq(rod_mean, #value, None)),
+                quote!(ExpectedBounds::Exact(#value)),
+            ),
+            LengthOrSize::Range(range) => (
+                quote!((#range).contains(&rod_mean)),
+                quote!(ExpectedBounds::from_range(&(#range))),
+            ),
+        };
+        let ret = match self.custom_mean_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Mean(#path, rod_mean, #expected))
+            }),
+        };
+        Some(quote! {
+            {
+                let (rod_total, rod_count) = #field_name.into_iter().fold((0f64, 0usize), |(total, count), item| (total + *item as f64, count + 1));
+                if rod_count > 0 {
+                    let rod_mean = rod_total / rod_count as f64;
+                    if !(#check) {
+                        #ret;
+                    }
+                }
+            }
+        })
+    }
+    /// Items, or the keys returned by `unique_by`, are compared through a `HashMap`, so they must implement `Hash` and `Eq`.
+    /// Only the first duplicate is reported.
+    fn validate_unique(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
+        let unique_opt = self.unique.then(|| {
+            let ret = match self.custom_unique_error.as_ref().or(custom_error) {
+                Some(msg) => user_defined_error(wrap_return, msg),
+                None => wrap_return(quote! {
+                    RodValidateError::Iterable(IterableValidation::Duplicate(#path, format!("{:?}", item), first, index))
+                }),
+            };
+            quote! {
+                {
+                    let mut rod_seen = std::collections::HashMap::new();
+                    let rod_duplicate = #field_name.into_iter().enumerate().find_map(|(index, item)| {
+                        rod_seen.insert(item, index).map(|first| (item, first, index))
+                    });
+                    if let Some((item, first, index)) = rod_duplicate {
+                        #ret;
+                    }
+                }
+            }
+        });
+        let unique_by_opt = self.unique_by.as_ref().map(|key| {
+            let ret = match self.custom_unique_error.as_ref().or(custom_error) {
+                Some(msg) => user_defined_error(wrap_return, msg),
+                None => wrap_return(quote! {
+                    RodValidateError::Iterable(IterableValidation::DuplicateKey(#path, key, first, index))
+                }),
+            };
+            // The key function is passed to `map` directly, so that its argument type is inferred from the items
+            quote! {
+                {
+                    let mut rod_seen = std::collections::HashMap::new();
+                    let rod_duplicate = #field_name.into_iter().map(#key).enumerate().find_map(|(index, key)| {
+                        match rod_seen.entry(key) {
+                            std::collections::hash_map::Entry::Occupied(entry) => Some((format!("{:?}", entry.key()), *entry.get(), index)),
+                            std::collections::hash_map::Entry::Vacant(entry) => {
+                                entry.insert(index);
+                                None
+                            }
+                        }
+                    });
+                    if let Some((key, first, index)) = rod_duplicate {
+                        #ret;
+                    }
+                }
             }
         });
         quote! {
-            #length_opt
-            for item in #field_name.into_iter() {
-                #inner_validation
+            #unique_opt
+            #unique_by_opt
+        }
+    }
+    /// Neighbouring items are compared with `PartialOrd`, so items that cannot be compared, such as `NaN`, are out of order.
+    fn validate_sorted(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> Option<proc_macro2::TokenStream> {
+        let order = self.sorted.as_ref()?;
+        let path = field_path(field_name);
+        let name = order.name();
+        let allowed = order.allowed();
+        let ret = match self.custom_sorted_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None => wrap_return(quote! {
+                RodValidateError::Iterable(IterableValidation::Sorted(#path, #name, index, format!("{:?}", item)))
+            }),
+        };
+        Some(quote! {
+            {
+                let mut rod_previous = None;
+                let rod_unsorted = #field_name.into_iter().enumerate().find(|&(_, item)| {
+                    let unsorted = rod_previous.is_some_and(|previous| !matches!(PartialOrd::partial_cmp(previous, item), Some(#allowed)));
+                    rod_previous = Some(item);
+                    unsorted
+                });
+                if let Some((index, item)) = rod_unsorted {
+                    #ret;
+                }
+            }
+        })
+    }
+    /// Items are compared to the value with `==`, so `contains: "admin"` works for `String` and `&str` items alike.
+    fn validate_contains(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
+        let contains_opt = self.contains.as_ref().map(|value| {
+            let ret = match self.custom_contains_error.as_ref().or(custom_error) {
+                Some(msg) => user_defined_error(wrap_return, msg),
+                None => wrap_return(quote! {
+                    RodValidateError::Iterable(IterableValidation::Contains(#path, format!("{:?}", #value)))
+                }),
+            };
+            quote! {
+                if !#field_name.into_iter().any(|item| *item == #value) {
+                    #ret;
+                }
+            }
+        });
+        let not_contains_opt = self.not_contains.as_ref().map(|value| {
+            let ret = match self.custom_not_contains_error.as_ref().or(custom_error) {
+                Some(msg) => user_defined_error(wrap_return, msg),
+                None => wrap_return(quote! {
+                    RodValidateError::Iterable(IterableValidation::NotContains(#path, format!("{:?}", #value), index))
+                }),
+            };
+            quote! {
+                if let Some(index) = #field_name.into_iter().position(|item| *item == #value) {
+                    #ret;
+                }
             }
+        });
+        quote! {
+            #contains_opt
+            #not_contains_opt
         }
     }
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
-        let inner_validation_with_custom_error = if let Some(msg) = self.custom_item_error.as_ref() {
-            rod_content_match!(
-                &self.item.content,
+    /// Item errors are collected per item, then wrapped in `IterableValidation::Item` with the index of the item,
+    /// so that the error path reads like `tags[3]`.
+    fn validate_items(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        fn push_item_error(ret: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+            quote! {
+                rod_item_errors.push(#ret);
+            }
+        }
+        let item_validation = |attr: &RodAttr, msg: Option<&RuleMessage>| match msg {
+            Some(msg) => rod_content_match!(
+                &attr.content,
                 &format_ident!("item"),
-                wrap_return,
+                push_item_error,
                 msg,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
-            )
-        } else {
-            rod_content_match!(
-                &self.item.content,
+                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
+            ),
+            None => rod_content_match!(
+                &attr.content,
                 &format_ident!("item"),
-                wrap_return,
-                custom_error,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
-            )
-        };
-        let length_opt = self.length.as_ref().map(|length| {
-            if let Some(msg) = self.custom_length_error.as_ref() {
-                length.validate_iterable_with_custom_error(field_name, wrap_return, msg)
-            } else {
-                length.validate_iterable_with_custom_error(field_name, wrap_return, custom_error)
+                push_item_error,
+                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
+            ),
+        };
+        let inner_validation = self.item.as_ref().map(|item| item_validation(item, self.custom_item_error.as_ref().or(custom_error)));
+        let position_validations = self.items.iter().enumerate().map(|(index, position)| {
+            let validation = item_validation(position, self.custom_items_error.as_ref().or(custom_error));
+            quote! {
+                #index => {
+                    #validation
+                }
+            }
+        }).collect::<Vec<_>>();
+        let position_validation = (!position_validations.is_empty()).then(|| quote! {
+            match rod_index {
+                #( #position_validations )*
+                _ => {}
+            }
+        });
+        let first_validation = self.first.as_ref().map(|first| item_validation(first, self.custom_first_error.as_ref().or(custom_error)));
+        let last_validation = self.last.as_ref().map(|last| item_validation(last, self.custom_last_error.as_ref().or(custom_error)));
+        let path = field_path(field_name);
+        let ret = wrap_return(quote! {
+            RodValidateError::Iterable(IterableValidation::Item(#path, rod_index, Box::new(error)))
+        });
+        let item_path = bind_field_path(&format_ident!("item"), quote!(format!("{}[{}]", #path, rod_index)));
+        // Expects `item` and `rod_index` in scope
+        let collect_item_errors = |validations: proc_macro2::TokenStream| quote! {
+            let rod_errors = {
+                #item_path
+                #[allow(unused_mut)]
+                let mut rod_item_errors: Vec<RodValidateError> = Vec::new();
+                #validations
+                rod_item_errors
+            };
+            for error in rod_errors {
+                #ret;
+            }
+        };
+        let each_opt = (inner_validation.is_some() || position_validation.is_some()).then(|| {
+            let validations = collect_item_errors(quote! {
+                #inner_validation
+                #position_validation
+            });
+            quote! {
+                for (rod_index, item) in #field_name.into_iter().enumerate() {
+                    #validations
+                }
+            }
+        });
+        let first_opt = first_validation.map(|validation| {
+            let validations = collect_item_errors(validation);
+            quote! {
+                if let Some(item) = #field_name.into_iter().next() {
+                    let rod_index = 0usize;
+                    #validations
+                }
+            }
+        });
+        let last_opt = last_validation.map(|validation| {
+            let validations = collect_item_errors(validation);
+            quote! {
+                if let Some((rod_index, item)) = #field_name.into_iter().enumerate().last() {
+                    #validations
+                }
             }
         });
         quote! {
+            #first_opt
+            #last_opt
+            #each_opt
+        }
+    }
+    pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let length_opt = self.validate_length(field_name, wrap_return, None);
+        let empty_opt = self.validate_non_empty(field_name, wrap_return, None);
+        let sum_opt = self.validate_sum(field_name, wrap_return, None);
+        let sum_bound_opt = self.validate_sum_bound(field_name, wrap_return, None);
+        let mean_opt = self.validate_mean(field_name, wrap_return, None);
+        let unique_opt = self.validate_unique(field_name, wrap_return, None);
+        let sorted_opt = self.validate_sorted(field_name, wrap_return, None);
+        let contains_opt = self.validate_contains(field_name, wrap_return, None);
+        let items = self.validate_items(field_name, wrap_return, None);
+        quote! {
+            #empty_opt
             #length_opt
-            for item in #field_name.into_iter() {
-                #inner_validation_with_custom_error
-            }
+            #sum_opt
+            #sum_bound_opt
+            #mean_opt
+            #unique_opt
+            #sorted_opt
+            #contains_opt
+            #items
+        }
+    }
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        let length_opt = self.validate_length(field_name, wrap_return, Some(custom_error));
+        let empty_opt = self.validate_non_empty(field_name, wrap_return, Some(custom_error));
+        let sum_opt = self.validate_sum(field_name, wrap_return, Some(custom_error));
+        let sum_bound_opt = self.validate_sum_bound(field_name, wrap_return, Some(custom_error));
+        let mean_opt = self.validate_mean(field_name, wrap_return, Some(custom_error));
+        let unique_opt = self.validate_unique(field_name, wrap_return, Some(custom_error));
+        let sorted_opt = self.validate_sorted(field_name, wrap_return, Some(custom_error));
+        let contains_opt = self.validate_contains(field_name, wrap_return, Some(custom_error));
+        let items = self.validate_items(field_name, wrap_return, Some(custom_error));
+        quote! {
+            #empty_opt
+            #length_opt
+            #sum_opt
+            #sum_bound_opt
+            #mean_opt
+            #unique_opt
+            #sorted_opt
+            #contains_opt
+            #items
         }
-    
     }
-}
\ No newline at end of file
+}
@@ -1,15 +1,21 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, Ident, LitStr, PatLit};
-use quote::quote;
+use syn::{parse::Parse, spanned::Spanned, Ident, PatLit};
+use quote::{quote, ToTokens};
 
-use super::{optional_braced, user_defined_error};
+use super::{field_path, optional_braced, schema_other_rule, user_defined_error, RuleMessage};
 
 
 /// `RodLiteralContent` is a struct that represents the content of a literal field in a Rod entity.
 /// It is used to parse and validate literal attributes in the `#[rod]` attribute macro.
-/// This struct includes a single field `value`, which is used to check if the literal value of the field matches the expected value.
+/// It checks that the field matches the expected literal value, or one of several accepted values.
 /// # Attributes
-/// - `value`: A required attribute that specifies the expected literal value of the field.
+/// - `value`: Specifies the expected literal value of the field.
+/// - `one_of`: Specifies a list of accepted literal values instead, e.g. `one_of: ["draft", "published"]`.
+/// - `not`: Specifies a literal value that the field must differ from, e.g. `not: ""`.
+///
+/// - `case_insensitive`: A flag that compares string literals with `eq_ignore_ascii_case` instead of `==`.
+///
+/// Exactly one of `value`, `one_of` and `not` must be given.
 /// # Usage
 /// ```
 /// use rod::prelude::*;
@@ -28,8 +34,61 @@ use super::{optional_braced, user_defined_error};
 /// assert!(entity.validate().is_ok());
 /// ```
 pub struct RodLiteralContent {
-    value: PatLit,
-    custom_error: Option<LitStr>,
+    value: LiteralValue,
+    case_insensitive: bool,
+    custom_error: Option<RuleMessage>,
+}
+
+/// `LiteralValue` is the value or values that a literal field is compared to with `==`, or with `eq_ignore_ascii_case` for `case_insensitive`.
+enum LiteralValue {
+    Exact(PatLit),
+    OneOf(Vec<PatLit>),
+    Not(PatLit),
+}
+
+/// The source text of a literal as it is written in JSON, e.g. `"draft"` or `42`, for the runtime schema.
+fn literal_source(value: &PatLit) -> String {
+    match &value.lit {
+        syn::Lit::Str(lit) => format!("{:?}", lit.value()),
+        syn::Lit::Int(lit) => lit.base10_digits().to_string(),
+        syn::Lit::Float(lit) => lit.base10_digits().to_string(),
+        lit => lit.to_token_stream().to_string(),
+    }
+}
+
+impl LiteralValue {
+    fn values(&self) -> &[PatLit] {
+        match self {
+            LiteralValue::Exact(value) | LiteralValue::Not(value) => std::slice::from_ref(value),
+            LiteralValue::OneOf(values) => values,
+        }
+    }
+    /// The condition under which the field does not match.
+    fn mismatch(&self, field_name: &Ident, case_insensitive: bool) -> proc_macro2::TokenStream {
+        let eq = |value: &PatLit| {
+            let value = &value.lit;
+            if case_insensitive {
+                quote!(#field_name.eq_ignore_ascii_case(#value))
+            } else {
+                quote!(#field_name.clone() == #value)
+            }
+        };
+        match self {
+            LiteralValue::Exact(value) if !case_insensitive => {
+                let value = &value.lit;
+                quote!(#field_name.clone() != #value)
+            }
+            LiteralValue::Exact(value) => {
+                let eq = eq(value);
+                quote!(!#eq)
+            }
+            LiteralValue::OneOf(values) => {
+                let eqs = values.iter().map(eq);
+                quote!(!(#( #eqs )||*))
+            }
+            LiteralValue::Not(value) => eq(value),
+        }
+    }
 }
 
 impl Parse for RodLiteralContent {
@@ -46,8 +105,11 @@ impl Parse for RodLiteralContent {
             }
         };
     let mut value = None;
-    let mut message: Option<LitStr> = None;
-    let mut custom_error: Option<LitStr> = None;
+    let mut one_of: Option<Vec<PatLit>> = None;
+    let mut not: Option<PatLit> = None;
+    let mut case_insensitive: Option<Ident> = None;
+    let mut message: Option<RuleMessage> = None;
+    let mut custom_error: Option<RuleMessage> = None;
         while !inner.is_empty() {
             let lookahead = inner.lookahead1();
             if lookahead.peek(syn::Ident) {
@@ -59,6 +121,32 @@ impl Parse for RodLiteralContent {
                     if let Some(msg) = message.take() {
                         custom_error = Some(msg);
                     }
+                } else if ident == "case_insensitive" {
+                    check_already_used_attr!(case_insensitive, ident.span());
+                    case_insensitive = Some(ident);
+                } else if ident == "not" {
+                    check_already_used_attr!(not, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    not = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_error = Some(msg);
+                    }
+                } else if ident == "one_of" {
+                    check_already_used_attr!(one_of, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    let content;
+                    let bracket = syn::bracketed!(content in inner);
+                    let values = content.parse_terminated(<PatLit as Parse>::parse, syn::Token![,])?;
+                    if values.is_empty() {
+                        abort!(
+                            bracket.span.join(), "Expected at least one value";
+                            help = "Example: `#[rod(Literal { one_of: [\"draft\", \"published\"] })]`"
+                        );
+                    }
+                    one_of = Some(values.into_iter().collect());
+                    if let Some(msg) = message.take() {
+                        custom_error = Some(msg);
+                    }
                 } else {
                     abort!(
                         ident.span(),
@@ -67,8 +155,7 @@ impl Parse for RodLiteralContent {
                 }
                 _ = inner.parse::<syn::Token![,]>();
             } else if lookahead.peek(syn::Token![?]) {
-                let _q: syn::Token![?] = inner.parse()?;
-                let result: LitStr = inner.parse()?;
+                let result: RuleMessage = inner.parse()?;
                 message = Some(result);
             } else {
                 abort!(
@@ -77,45 +164,96 @@ impl Parse for RodLiteralContent {
                 );
             }
         }
-        if let Some(value) = value {
-            let custom_error = custom_error.or(message);
-            Ok(RodLiteralContent { value, custom_error })
-        } else {
-            abort!(
+        let custom_error = custom_error.or(message);
+        let value = match (value, one_of, not) {
+            (Some(value), None, None) => LiteralValue::Exact(value),
+            (None, Some(values), None) => LiteralValue::OneOf(values),
+            (None, None, Some(value)) => LiteralValue::Not(value),
+            (None, None, None) => abort!(
                 input.span(),
                 "Must specify a literal value using `value: <literal>` inside the `literal` attribute.";
                 help = "Example: `#[rod(Literal { value: 42 })]`"
-            )
+            ),
+            _ => abort!(
+                input.span(),
+                "Only one of `value`, `one_of` and `not` can be used";
+                help = "Use `one_of` to accept several values"
+            ),
+        };
+        if let Some(flag) = case_insensitive.as_ref() && let Some(value) = value.values().iter().find(|value| !matches!(value.lit, syn::Lit::Str(_))) {
+            abort!(
+                value.span(), "`case_insensitive` can only be used with string literals";
+                note = "`{}` is used here", flag
+            );
         }
+        Ok(RodLiteralContent { value, case_insensitive: case_insensitive.is_some(), custom_error })
     }
 }
 
 impl RodLiteralContent {
+    /// Returns the `schema::TypeSchema` of the field. Only an exact, case-sensitive `value` has a runtime counterpart;
+    /// `one_of`, `not` and `case_insensitive` are described in words.
+    pub(crate) fn get_schema(&self) -> proc_macro2::TokenStream {
+        let description = match &self.value {
+            LiteralValue::Exact(value) if !self.case_insensitive => {
+                let literal = literal_source(value);
+                return quote!(__rod_schema::TypeSchema::Literal(#literal));
+            }
+            LiteralValue::Exact(value) => format!("equals {}", literal_source(value)),
+            LiteralValue::OneOf(values) => format!("one of [{}]", values.iter().map(literal_source).collect::<Vec<_>>().join(", ")),
+            LiteralValue::Not(value) => format!("not {}", literal_source(value)),
+        };
+        let description = if self.case_insensitive {
+            format!("{}, ignoring case", description)
+        } else {
+            description
+        };
+        let rule = schema_other_rule(&description, self.custom_error.as_ref());
+        quote!(__rod_schema::TypeSchema::Other { name: "literal", rules: vec![#rule] })
+    }
     pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
-        let value = &self.value.lit;
+        let path = field_path(field_name);
         let ret = if let Some(msg) = self.custom_error.as_ref() {
             user_defined_error(wrap_return, msg)
         } else {
-            wrap_return(quote! {
-                RodValidateError::Literal(LiteralValidation::Value(#path, #field_name.clone().to_string(), format!("to be {}", #value)))
-            })
+            match &self.value {
+                LiteralValue::Exact(value) => {
+                    let value = &value.lit;
+                    wrap_return(quote! {
+                        RodValidateError::Literal(LiteralValidation::Value(#path, #field_name.clone().to_string(), #value.to_string()))
+                    })
+                }
+                LiteralValue::OneOf(values) => {
+                    let values = values.iter().map(|value| value.lit.to_token_stream().to_string()).collect::<Vec<_>>();
+                    let values = format!("[{}]", values.join(", "));
+                    wrap_return(quote! {
+                        RodValidateError::Literal(LiteralValidation::OneOf(#path, #field_name.clone().to_string(), #values))
+                    })
+                }
+                LiteralValue::Not(value) => {
+                    let value = &value.lit;
+                    wrap_return(quote! {
+                        RodValidateError::Literal(LiteralValidation::Not(#path, format!("{:?}", #value)))
+                    })
+                }
+            }
         };
+        let mismatch = self.value.mismatch(field_name, self.case_insensitive);
         quote! {
-            if #field_name.clone() != #value {
+            if #mismatch {
                 #ret;
             }
         }
     }
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
-        let value = &self.value.lit;
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let ret = if let Some(msg) = self.custom_error.as_ref() {
             user_defined_error(wrap_return, msg)
         } else {
             user_defined_error(wrap_return, custom_error)
         };
+        let mismatch = self.value.mismatch(field_name, self.case_insensitive);
         quote! {
-            if #field_name.clone() != #value {
+            if #mismatch {
                 #ret;
             }
         }
@@ -1,6 +1,6 @@
 use proc_macro_error::abort;
-use syn::{parse::{Parse, ParseBuffer}, ExprRange, Ident, LitInt, LitStr, Token};
-use quote::{quote, ToTokens};
+use syn::{ext::IdentExt, parse::{Parse, ParseBuffer}, parse_quote, spanned::Spanned, Expr, ExprLit, ExprRange, Ident, LitStr, Token};
+use quote::{format_ident, quote, ToTokens};
 
 macro_rules! check_already_used_attr {
     ($attr:ident, $span:expr) => {
@@ -14,32 +14,50 @@ macro_rules! check_already_used_attr {
 
 /// `LengthOrSize` is an enum that represents either an exact value or a range.
 /// It is used to specify the length (for strings) or size (for integers and floats) of a field.
+/// Values and bounds can be literals or constant expressions, e.g. `MIN_AGE..=MAX_AGE` or `crate::limits::NAME_LEN`,
+/// which are passed through into the generated checks.
 pub(crate) enum LengthOrSize {
-    Exact(LitInt),
+    Exact(Expr),
     Range(ExprRange),
 }
 
 impl Parse for LengthOrSize {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        if input.peek2(Token![..]) {
-            let range: ExprRange = input.parse()?;
-            Ok(LengthOrSize::Range(range))
-        } else if input.peek(LitInt) {
-            let length: LitInt = input.parse()?;
-            Ok(LengthOrSize::Exact(length))
-        } else {
-            abort!(input.span(), "Expected a number or a range")
+        if input.is_empty() || input.peek(Token![,]) {
+            abort!(input.span(), "Expected a number or a range");
+        }
+        match input.parse::<Expr>()? {
+            Expr::Range(range) => Ok(LengthOrSize::Range(range)),
+            Expr::Lit(ExprLit { lit: syn::Lit::Str(lit), .. }) => {
+                abort!(lit.span(), "Expected a number or a range, found a string")
+            }
+            exact => Ok(LengthOrSize::Exact(exact)),
         }
     }
 }
 
 impl LengthOrSize {
+    /// Parses the single bound of `min_length` or `max_length` into the equivalent range, `min..` or `..=max`.
+    pub(crate) fn parse_bound(input: syn::parse::ParseStream, name: &Ident) -> syn::Result<Self> {
+        let bound: Expr = input.parse()?;
+        if let Expr::Range(range) = &bound {
+            abort!(
+                range.span(), "Expected `{}` to be a single bound, found a range", name;
+                help = "Use `length: {}` instead", range.to_token_stream()
+            );
+        }
+        if name == "min_length" {
+            Ok(LengthOrSize::Range(parse_quote!(#bound..)))
+        } else {
+            Ok(LengthOrSize::Range(parse_quote!(..=#bound)))
+        }
+    }
     pub(crate) fn validate_string(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
+        let path = field_path(field_name);
         match self {
             LengthOrSize::Exact(exact) => {
                 let ret = wrap_return(quote! {
-                    RodValidateError::String(StringValidation::Length(#path, #field_name.to_string(), format!("to be exactly {}", #exact)))
+                    RodValidateError::String(StringValidation::Length(#path, #field_name.to_string(), #field_name.len(), ExpectedBounds::Exact(#exact)))
                 });
                 quote! {
                     if #field_name.len() != #exact {
@@ -49,7 +67,7 @@ impl LengthOrSize {
             }
             LengthOrSize::Range(range) => {
                 let ret = wrap_return(quote! {
-                    RodValidateError::String(StringValidation::Length(#path, #field_name.to_string(), format!("to be in the range {:?}", #range)))
+                    RodValidateError::String(StringValidation::Length(#path, #field_name.to_string(), #field_name.len(), ExpectedBounds::from_range(&(#range))))
                 });
                 quote! {
                     if !(#range).contains(&#field_name.len()) {
@@ -59,7 +77,7 @@ impl LengthOrSize {
             }
         }
     }
-    pub (crate) fn validate_string_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub (crate) fn validate_string_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let ret = user_defined_error(wrap_return, custom_error);
         match self {
             LengthOrSize::Exact(exact) => {
@@ -79,36 +97,44 @@ impl LengthOrSize {
         }
     }
     pub(crate) fn validate_integer(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
+        let path = field_path(field_name);
         match self {
             LengthOrSize::Exact(exact) => {
+                // The value is bound once so that it has the field's type rather than the default `i32`
                 let ret = wrap_return(quote! {
-                    RodValidateError::Integer(IntegerValidation::Size(#path, #field_name.clone().into(), format!("to be exactly {}", #exact)))
+                    RodValidateError::Integer(IntegerValidation::Size(#path, #field_name.clone().into(), Box::new(ExpectedBounds::Exact(rod_exact.into()))))
                 });
                 quote! {
-                    if #field_name != #exact {
-                        #ret;
+                    {
+                        let rod_exact = #exact;
+                        if *#field_name != rod_exact {
+                            #ret;
+                        }
                     }
                 }
             }
             LengthOrSize::Range(range) => {
+                // The range is bound once so that its bounds have the field's type rather than the default `i32`
                 let ret = wrap_return(quote! {
-                    RodValidateError::Integer(IntegerValidation::Size(#path, #field_name.clone().into(), format!("to be in the range {:?}", #range)))
+                    RodValidateError::Integer(IntegerValidation::Size(#path, #field_name.clone().into(), Box::new(ExpectedBounds::from_range(&rod_range).map(Integer::from))))
                 });
                 quote! {
-                    if !(#range).contains(#field_name) {
-                        #ret;
+                    {
+                        let rod_range = #range;
+                        if !rod_range.contains(#field_name) {
+                            #ret;
+                        }
                     }
                 }
             }
         }
     }
-    pub(crate) fn validate_integer_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn validate_integer_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let ret = user_defined_error(wrap_return, custom_error);
         match self {
             LengthOrSize::Exact(exact) => {
                 quote! {
-                    if #field_name != #exact {
+                    if *#field_name != #exact {
                         #ret;
                     }
                 }
@@ -123,21 +149,13 @@ impl LengthOrSize {
         }
     }
     pub(crate) fn validate_float(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
+        let path = field_path(field_name);
         match self {
-            LengthOrSize::Exact(exact) => {
-                let ret = wrap_return(quote! {
-                    RodValidateError::Float(FloatValidation::Size(#path, #field_name.clone().into(), format!("to be exactly {}", #exact)))
-                });
-                quote! {
-                    if #field_name != #exact as f64 {
-                        #ret;
-                    }
-                }
-            }
+            // Exact float sizes are parsed as `FloatSize::Exact`, which compares with a tolerance
+            LengthOrSize::Exact(_) => unreachable!(),
             LengthOrSize::Range(range) => {
                 let ret = wrap_return(quote! {
-                    RodValidateError::Float(FloatValidation::Size(#path, #field_name.clone().into(), format!("to be in the range {:?}", #range)))
+                    RodValidateError::Float(FloatValidation::Size(#path, #field_name.clone().into(), ExpectedBounds::from_range(&(#range)).map(f64::from)))
                 });
                 quote! {
                     if !(#range).contains(#field_name) {
@@ -147,16 +165,10 @@ impl LengthOrSize {
             }
         }
     }
-    pub(crate) fn validate_float_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn validate_float_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let ret = user_defined_error(wrap_return, custom_error);
         match self {
-            LengthOrSize::Exact(exact) => {
-                quote! {
-                    if #field_name != #exact as f64 {
-                        #ret;
-                    }
-                }
-            }
+            LengthOrSize::Exact(_) => unreachable!(),
             LengthOrSize::Range(range) => {
                 quote! {
                     if !(#range).contains(#field_name) {
@@ -166,44 +178,51 @@ impl LengthOrSize {
             }
         }
     }
-    pub(crate) fn validate_iterable(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
+    /// `length` is the expression that computes the length of the iterable, e.g. `field.len()`. It is evaluated once.
+    pub(crate) fn validate_iterable(&self, field_name: &Ident, length: &proc_macro2::TokenStream, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
         match self {
             LengthOrSize::Exact(exact) => {
                 let ret = wrap_return(quote! {
-                    RodValidateError::Iterable(IterableValidation::Length(#path, #field_name.len(), format!("to be exactly {}", #exact)))
+                    RodValidateError::Iterable(IterableValidation::Length(#path, rod_length, ExpectedBounds::Exact(#exact)))
                 });
                 quote! {
-                    if #field_name.len() != #exact {
-                        #ret;
+                    {
+                        let rod_length = #length;
+                        if rod_length != #exact {
+                            #ret;
+                        }
                     }
                 }
             }
             LengthOrSize::Range(range) => {
                 let ret = wrap_return(quote! {
-                    RodValidateError::Iterable(IterableValidation::Length(#path, #field_name.len(), format!("to be in the range {:?}", #range)))
+                    RodValidateError::Iterable(IterableValidation::Length(#path, rod_length, ExpectedBounds::from_range(&(#range))))
                 });
                 quote! {
-                    if !(#range).contains(&#field_name.len()) {
-                        #ret;
+                    {
+                        let rod_length = #length;
+                        if !(#range).contains(&rod_length) {
+                            #ret;
+                        }
                     }
                 }
             }
         }
     }
-    pub(crate) fn validate_iterable_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn validate_iterable_with_custom_error(&self, length: &proc_macro2::TokenStream, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         let ret = user_defined_error(wrap_return, custom_error);
         match self {
             LengthOrSize::Exact(exact) => {
                 quote! {
-                    if #field_name.len() != #exact {
+                    if #length != #exact {
                         #ret;
                     }
                 }
             }
             LengthOrSize::Range(range) => {
                 quote! {
-                    if !(#range).contains(&#field_name.len()) {
+                    if !(#range).contains(&#length) {
                         #ret;
                     }
                 }
@@ -212,14 +231,187 @@ impl LengthOrSize {
     }
 }
 
+/// A bound of a range as an `f64`. Literals are converted here, since an unsuffixed literal such as `u64::MAX` would not fit
+/// the `i32` it defaults to in a cast.
+fn schema_bound(bound: &Expr) -> proc_macro2::TokenStream {
+    let literal = match bound {
+        Expr::Lit(syn::ExprLit { lit, .. }) => Some((lit, false)),
+        Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match expr.as_ref() {
+            Expr::Lit(syn::ExprLit { lit, .. }) => Some((lit, true)),
+            _ => None,
+        },
+        _ => None,
+    };
+    let value = literal.and_then(|(lit, negated)| {
+        let value = match lit {
+            syn::Lit::Int(int) => int.base10_digits().parse::<f64>().ok(),
+            syn::Lit::Float(float) => float.base10_digits().parse::<f64>().ok(),
+            _ => None,
+        }?;
+        Some(if negated { -value } else { value })
+    });
+    match value {
+        Some(value) => quote!(#value),
+        None => quote!((#bound) as f64),
+    }
+}
+
+impl LengthOrSize {
+    /// The `schema::Bounds` of the value or range, whose bounds are converted to `f64` like in the runtime schema.
+    pub(crate) fn schema_bounds(&self) -> proc_macro2::TokenStream {
+        match self {
+            LengthOrSize::Exact(exact) => {
+                let exact = schema_bound(exact);
+                quote!(__rod_schema::Bounds::Exact(#exact))
+            }
+            LengthOrSize::Range(range) => {
+                let bound = |bound: Option<&Expr>| match bound {
+                    Some(bound) => {
+                        let bound = schema_bound(bound);
+                        quote!(Some(#bound))
+                    }
+                    None => quote!(None),
+                };
+                let start = bound(range.start.as_deref());
+                let end = bound(range.end.as_deref());
+                let inclusive = matches!(range.limits, syn::RangeLimits::Closed(_));
+                quote!(__rod_schema::Bounds::range(#start, #end, #inclusive))
+            }
+        }
+    }
+    /// The value or range as it is written, e.g. `1..=10`, for rules that are described in words.
+    pub(crate) fn describe(&self) -> String {
+        let tokens = match self {
+            LengthOrSize::Exact(exact) => exact.to_token_stream(),
+            LengthOrSize::Range(range) => range.to_token_stream(),
+        };
+        tokens.to_string().replace(' ', "")
+    }
+    fn as_range(&self) -> proc_macro2::TokenStream {
+        match self {
+            LengthOrSize::Exact(exact) => quote!((#exact)..=(#exact)),
+            LengthOrSize::Range(range) => quote!(#range),
+        }
+    }
+    pub(crate) fn fix_string(&self, field_name: &Ident, path: &str) -> proc_macro2::TokenStream {
+        let range = self.as_range();
+        quote! {
+            if let Some(fixed) = #field_name.rod_truncate(&(#range)) {
+                fixes.push(RodFix { path: std::borrow::Cow::Borrowed(#path), value: RodFixValue::String(fixed) });
+            }
+        }
+    }
+    pub(crate) fn fix_integer(&self, field_name: &Ident, path: &str) -> proc_macro2::TokenStream {
+        let range = self.as_range();
+        quote! {
+            if let Some(fixed) = RodClamp::rod_clamp(*#field_name, &(#range)) {
+                fixes.push(RodFix { path: std::borrow::Cow::Borrowed(#path), value: RodFixValue::Integer(fixed.into()) });
+            }
+        }
+    }
+    pub(crate) fn fix_float(&self, field_name: &Ident, path: &str) -> proc_macro2::TokenStream {
+        let range = match self {
+            LengthOrSize::Exact(_) => unreachable!(),
+            LengthOrSize::Range(range) => quote!(#range),
+        };
+        quote! {
+            if let Some(fixed) = RodClamp::rod_clamp(*#field_name, &(#range)) {
+                fixes.push(RodFix { path: std::borrow::Cow::Borrowed(#path), value: RodFixValue::Float(fixed.into()) });
+            }
+        }
+    }
+}
+
+/// `RuleMessage` is a `?"message"` that replaces the error of the rule it precedes.
+/// With `?warn "message"`, a failure of the rule is reported as a warning instead, which does not fail validation.
+#[derive(Clone)]
+pub(crate) struct RuleMessage {
+    pub(crate) message: LitStr,
+    pub(crate) warn: bool,
+}
+
+impl Parse for RuleMessage {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![?]>()?;
+        let warn = if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident != "warn" {
+                abort!(
+                    ident.span(), "Unknown severity `{}`", ident;
+                    help = "Use `?\"<message>\"` for an error or `?warn \"<message>\"` for a warning"
+                );
+            }
+            true
+        } else {
+            false
+        };
+        let message: LitStr = input.parse()?;
+        Ok(RuleMessage { message, warn })
+    }
+}
+
+impl From<LitStr> for RuleMessage {
+    fn from(message: LitStr) -> Self {
+        RuleMessage { message, warn: false }
+    }
+}
+
+impl ToTokens for RuleMessage {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.message.to_tokens(tokens);
+    }
+}
+
+/// A `schema::Rule` of `kind`, with the `?"message"` of the rule if it has one.
+pub(crate) fn schema_rule(kind: proc_macro2::TokenStream, message: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+    match message {
+        Some(message) => quote!(__rod_schema::Rule::new(#kind).with_message(#message)),
+        None => quote!(__rod_schema::Rule::new(#kind)),
+    }
+}
+
+/// A `schema::Rule` for a rule without a runtime counterpart, described in words, e.g. `unique`.
+pub(crate) fn schema_other_rule(description: &str, message: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+    schema_rule(quote!(__rod_schema::RuleKind::Other(#description)), message)
+}
+
+/// Reports `message` through `wrap_return`, or adds it to the warnings of the validation if it is a `?warn` message.
 pub(crate) fn user_defined_error(
     wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
-    message: &LitStr,
+    message: &RuleMessage,
 ) -> proc_macro2::TokenStream {
-    let msg = message.clone();
-    wrap_return(quote! {
-        RodValidateError::UserDefined(#msg.to_string())
-    })
+    let msg = &message.message;
+    if message.warn {
+        quote! {
+            rod_warnings.push(rod_wrap_error(RodValidateError::UserDefined(#msg.to_string())))
+        }
+    } else {
+        wrap_return(quote! {
+            RodValidateError::UserDefined(#msg.to_string())
+        })
+    }
+}
+
+/// The name of the closure that builds the path of the value bound to `field_name`.
+fn path_ident(field_name: &Ident) -> Ident {
+    format_ident!("rod_path_{}", field_name.unraw())
+}
+
+/// The path of the value bound to `field_name` in errors, like `addresses[2].zip`, as a `String` expression.
+/// It refers to the closure bound by `bind_field_path`, so the path is only built when an error is reported.
+pub(crate) fn field_path(field_name: &Ident) -> proc_macro2::TokenStream {
+    let ident = path_ident(field_name);
+    quote!(#ident())
+}
+
+/// Binds the path of the value bound to `field_name`, given as an expression that evaluates to a `String`.
+/// Values nested in an `Option`, a tuple or an iterable build their paths from the path of the value that holds them.
+pub(crate) fn bind_field_path(field_name: &Ident, path: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let ident = path_ident(field_name);
+    quote! {
+        #[allow(unused_variables)]
+        let #ident = || -> String { #path };
+    }
 }
 
 /// `NumberSign` is an enum that represents the sign of an integer.
@@ -256,6 +448,106 @@ impl Parse for NumberSign {
     }
 }
 
+/// `FieldComparison` compares a field to a sibling field of the same struct, e.g. `eq_field: password` or `le_field: max_capacity`.
+/// The sibling is read through `self`, so comparisons can only be used on the fields of structs with named fields.
+pub(crate) struct FieldComparison {
+    rule: Ident,
+    op: ComparisonOp,
+    other: Ident,
+    custom_error: Option<RuleMessage>,
+}
+
+enum ComparisonOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl ComparisonOp {
+    fn from_ident(ident: &Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "eq_field" => Some(ComparisonOp::Eq),
+            "ne_field" => Some(ComparisonOp::Ne),
+            "lt_field" => Some(ComparisonOp::Lt),
+            "le_field" => Some(ComparisonOp::Le),
+            "gt_field" => Some(ComparisonOp::Gt),
+            "ge_field" => Some(ComparisonOp::Ge),
+            _ => None,
+        }
+    }
+    /// How the relation reads in the error, e.g. "Expected `password_confirm` to equal `password`".
+    fn relation(&self) -> &'static str {
+        match self {
+            ComparisonOp::Eq => "equal",
+            ComparisonOp::Ne => "differ from",
+            ComparisonOp::Lt => "be less than",
+            ComparisonOp::Le => "be at most",
+            ComparisonOp::Gt => "be greater than",
+            ComparisonOp::Ge => "be at least",
+        }
+    }
+    fn is_ordering(&self) -> bool {
+        !matches!(self, ComparisonOp::Eq | ComparisonOp::Ne)
+    }
+}
+
+impl FieldComparison {
+    /// Parses a comparison such as `eq_field: other` after `ident`, taking the pending `?"message"` if there is one.
+    /// Returns `None` if `ident` is not a comparison, so that the caller can try its other attributes.
+    /// The ordering comparisons `lt_field`, `le_field`, `gt_field` and `ge_field` are only allowed if `ordered` is set.
+    pub(crate) fn parse_rule(ident: &Ident, input: syn::parse::ParseStream, message: &mut Option<RuleMessage>, ordered: bool) -> syn::Result<Option<Self>> {
+        let Some(op) = ComparisonOp::from_ident(ident) else {
+            return Ok(None);
+        };
+        if op.is_ordering() && !ordered {
+            abort!(
+                ident.span(), "`{}` can only be used with numbers and custom types", ident;
+                help = "Use `eq_field` or `ne_field` instead"
+            );
+        }
+        input.parse::<Token![:]>()?;
+        let other: Ident = input.parse()?;
+        Ok(Some(FieldComparison { rule: ident.clone(), op, other, custom_error: message.take() }))
+    }
+    /// The comparison as written, e.g. `eq_field`, for diagnostics.
+    pub(crate) fn rule(&self) -> &Ident {
+        &self.rule
+    }
+    /// Describes the comparison in the schema, e.g. "be at most `max_capacity`".
+    pub(crate) fn schema_rule(&self) -> proc_macro2::TokenStream {
+        let description = format!("{} `{}`", self.op.relation(), self.other.unraw());
+        schema_other_rule(&description, self.custom_error.as_ref())
+    }
+    pub(crate) fn validate(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let other = &self.other;
+        let path = field_path(field_name);
+        let other_path = other.unraw().to_string();
+        let relation = self.op.relation();
+        let ret = match self.custom_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None => wrap_return(quote! {
+                RodValidateError::Struct(StructValidation::Compare(#path, #relation, rod_field_name(#other_path).to_string()))
+            }),
+        };
+        let holds = match self.op {
+            ComparisonOp::Eq => quote!(*#field_name == self.#other),
+            ComparisonOp::Ne => quote!(*#field_name != self.#other),
+            ComparisonOp::Lt => quote!(*#field_name < self.#other),
+            ComparisonOp::Le => quote!(*#field_name <= self.#other),
+            ComparisonOp::Gt => quote!(*#field_name > self.#other),
+            ComparisonOp::Ge => quote!(*#field_name >= self.#other),
+        };
+        quote! {
+            if !(#holds) {
+                #ret;
+            }
+        }
+    }
+}
+
 pub(super) fn optional_braced(input: syn::parse::ParseStream) -> syn::Result<Option<ParseBuffer>> {
     if input.peek(syn::token::Brace) {
         let content;
@@ -304,4 +596,7 @@ mod custom;
 pub use custom::CustomContent;
 
 mod iterable;
-pub use iterable::RodIterableContent;
\ No newline at end of file
+pub use iterable::RodIterableContent;
+
+mod enum_tag;
+pub use enum_tag::RodEnumTagContent;
\ No newline at end of file
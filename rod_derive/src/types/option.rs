@@ -1,10 +1,10 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, Ident, LitStr};
+use syn::{parse::Parse, spanned::Spanned, ExprClosure, Ident};
 use quote::{format_ident, quote};
 
 use crate::{RodAttr, RodAttrContent};
 
-use super::{optional_braced, user_defined_error};
+use super::{bind_field_path, field_path, optional_braced, user_defined_error, FieldComparison, RuleMessage};
 
 macro_rules! rod_content_match {
     ($content:expr, $field_access:expr, $wrap_return:expr, [ $( $variant:ident ),* ]) => {
@@ -29,6 +29,10 @@ macro_rules! rod_content_match {
 /// # Attributes
 /// None, as `inner` is not meant to be set directly. If you want to validate the content of an option, you should place the validation type inside the `Option` attribute.
 /// if you want to validate that the option is `None`, you can use `Option {}`.
+/// By default, `None` is an error when an inner validation is given. Add `optional` (or `required: false`) to only validate the inner value when it is `Some`.
+/// `required: true` requires the option to be `Some`, with or without an inner validation, which can also be given as `inner: ...`.
+/// `allow_none: true` is the same as `required: false`, and reads better on optional collections, e.g. `Option { Iterable { ... }, allow_none: true }`.
+/// `required_if: |s: &Self| ...` requires the option to be `Some` only when the closure returns `true` for the whole struct, and otherwise allows `None`.
 /// # Usage
 /// ```
 /// use rod::prelude::*;
@@ -45,17 +49,29 @@ macro_rules! rod_content_match {
 ///     my_field: Option<String>,
 ///     #[rod(Option {})]
 ///     none_field: Option<String>,
+///     #[rod(
+///         Option {
+///             optional,
+///             String {
+///                 length: 5,
+///             }
+///         }
+///     )]
+///     maybe_field: Option<String>,
 /// }
 /// 
 /// let entity = MyEntity {
 ///    my_field: Some("12345".to_string()),
 ///    none_field: None,
+///    maybe_field: None,
 /// };
 /// assert!(entity.validate().is_ok());
 /// ```
 pub struct RodOptionContent {
     pub(crate) inner: Option<Box<RodAttr>>,
-    custom_none_error: Option<LitStr>,
+    required: Option<bool>,
+    required_if: Option<ExprClosure>,
+    custom_none_error: Option<RuleMessage>,
 }
 
 impl Parse for RodOptionContent {
@@ -64,19 +80,47 @@ impl Parse for RodOptionContent {
         let inner = match opt {
             Some(inner) => inner,
             None => {
-                return Ok(RodOptionContent { inner: None, custom_none_error: None });
+                return Ok(RodOptionContent { inner: None, required: None, required_if: None, custom_none_error: None });
             }
         };
         if inner.is_empty() {
-            Ok(RodOptionContent { inner: None, custom_none_error: None })
+            Ok(RodOptionContent { inner: None, required: None, required_if: None, custom_none_error: None })
         } else {
             let mut rod_attr: Option<RodAttr> = None;
-            let mut message: Option<LitStr> = None;
+            let mut message: Option<RuleMessage> = None;
+            let mut required: Option<bool> = None;
+            let mut required_if: Option<ExprClosure> = None;
             while !inner.is_empty() {
                 let lookahead = inner.lookahead1();
-                if lookahead.peek(syn::Token![?]) {
-                    let _q: syn::Token![?] = inner.parse()?;
-                    let msg: LitStr = inner.parse()?;
+                let keyword = inner.fork().parse::<Ident>().ok().filter(|ident| ident == "optional" || ident == "required" || ident == "allow_none" || ident == "required_if" || ident == "inner");
+                if let Some(keyword) = keyword {
+                    inner.parse::<Ident>()?;
+                    if keyword == "optional" {
+                        check_already_used_attr!(required, keyword.span());
+                        required = Some(false);
+                    } else if keyword == "required" {
+                        check_already_used_attr!(required, keyword.span());
+                        inner.parse::<syn::Token![:]>()?;
+                        let value: syn::LitBool = inner.parse()?;
+                        required = Some(value.value);
+                    } else if keyword == "required_if" {
+                        check_already_used_attr!(required_if, keyword.span());
+                        inner.parse::<syn::Token![:]>()?;
+                        required_if = Some(inner.parse()?);
+                    } else if keyword == "allow_none" {
+                        check_already_used_attr!(required, keyword.span());
+                        inner.parse::<syn::Token![:]>()?;
+                        let value: syn::LitBool = inner.parse()?;
+                        required = Some(!value.value);
+                    } else {
+                        if rod_attr.is_some() {
+                            abort!(keyword.span(), "Option attribute can only contain a single inner validation");
+                        }
+                        inner.parse::<syn::Token![:]>()?;
+                        rod_attr = Some(inner.parse()?);
+                    }
+                } else if lookahead.peek(syn::Token![?]) {
+                    let msg: RuleMessage = inner.parse()?;
                     message = Some(msg);
                 } else {
                     if rod_attr.is_some() {
@@ -86,8 +130,22 @@ impl Parse for RodOptionContent {
                 }
                 _ = inner.parse::<syn::Token![,]>();
             }
+            if let Some(condition) = required_if.as_ref() {
+                if required.is_some() {
+                    abort!(
+                        condition.span(), "`required_if` cannot be combined with `optional`, `required` or `allow_none`";
+                        help = "`required_if` already allows `None` when the condition does not hold"
+                    );
+                }
+                // The condition is checked separately, so the Option is otherwise optional
+                required = Some(false);
+            } else if required == Some(false) && let Some(msg) = message.as_ref() {
+                proc_macro_error::emit_warning!(msg.span(), "The custom message for `None` is never used, because the Option is not required");
+            }
             Ok(RodOptionContent {
                 inner: rod_attr.map(Box::new),
+                required,
+                required_if,
                 custom_none_error: message,
             })
         }
@@ -95,86 +153,163 @@ impl Parse for RodOptionContent {
 }
 
 impl RodOptionContent {
-    pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
-        if self.inner.is_none() {
-            let ret = if let Some(msg) = self.custom_none_error.as_ref() {
-                user_defined_error(wrap_return, msg)
-            } else {
-                wrap_return(quote! {
-                    RodValidateError::Option(OptionValidation::Some(
-                        #path,
-                        format!("{:?}", #field_name)
-                    ))
-                })
-            };
-            quote! {
-                if #field_name.is_some() {
+    /// Removes the comparisons to other fields from the rules of the value, for validations that run without the rest of the struct.
+    pub(crate) fn strip_field_comparisons(&mut self) {
+        if let Some(inner) = self.inner.as_mut() {
+            crate::strip_field_comparisons(inner);
+        }
+    }
+    /// Returns the first comparison to another field in the rules of the value, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        self.inner.as_ref().and_then(|inner| crate::find_field_comparison(inner))
+    }
+    /// Returns the `schema::TypeSchema` of the field, where `inner_type` is the type the `Option` holds, if it is known.
+    pub(crate) fn get_schema(&self, inner_type: Option<&syn::Type>) -> proc_macro2::TokenStream {
+        let inner = match self.inner.as_ref() {
+            Some(inner) => crate::schema::get_attr_schema(inner, inner_type),
+            None => quote!(__rod_schema::TypeSchema::Skip),
+        };
+        match (self.inner.is_some(), self.required) {
+            (false, None) => quote!(__rod_schema::TypeSchema::Option(None)),
+            (_, Some(false)) => quote!(__rod_schema::TypeSchema::Optional(Box::new(#inner))),
+            _ => quote!(__rod_schema::TypeSchema::Option(Some(Box::new(#inner)))),
+        }
+    }
+    /// Checks the `required_if` condition, which needs `self` to be the struct that holds the field.
+    fn validate_required_if(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let Some(condition) = self.required_if.as_ref() else {
+            return quote! {};
+        };
+        let path = field_path(field_name);
+        let ret = match self.custom_none_error.as_ref().or(custom_error) {
+            Some(msg) => user_defined_error(wrap_return, msg),
+            None => wrap_return(quote! {
+                RodValidateError::Option(OptionValidation::RequiredIf(#path))
+            }),
+        };
+        quote! {
+            {
+                let condition: fn(&Self) -> bool = #condition;
+                if #field_name.is_none() && condition(self) {
                     #ret;
                 }
             }
-        } else {
-            let inner_validation = rod_content_match!(
-                &self.inner.as_ref().unwrap().content,
-                &format_ident!("opt"),
-                wrap_return,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
-            );
-            let ty = self.inner.as_ref().unwrap().ty.to_string();
-            let ret = if let Some(msg) = self.custom_none_error.as_ref() {
-                user_defined_error(wrap_return, msg)
-            } else {
-                wrap_return(quote! {
-                    RodValidateError::Option(OptionValidation::None(#path, #ty))
-                })
-            };
-            quote! {
-                match &#field_name {
-                    Some(opt) => {
-                        #inner_validation
+        }
+    }
+    /// Without an inner validation, the Option must be `None` unless `required` is set explicitly.
+    fn validate_without_inner(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
+        let custom_error = self.custom_none_error.as_ref().or(custom_error);
+        match self.required {
+            None => {
+                let ret = if let Some(msg) = custom_error {
+                    user_defined_error(wrap_return, msg)
+                } else {
+                    wrap_return(quote! {
+                        RodValidateError::Option(OptionValidation::Some(
+                            #path,
+                            format!("{:?}", #field_name)
+                        ))
+                    })
+                };
+                quote! {
+                    if #field_name.is_some() {
+                        #ret;
                     }
-                    None => {
+                }
+            }
+            Some(true) => {
+                let ret = if let Some(msg) = custom_error {
+                    user_defined_error(wrap_return, msg)
+                } else {
+                    wrap_return(quote! {
+                        RodValidateError::Option(OptionValidation::Required(#path))
+                    })
+                };
+                quote! {
+                    if #field_name.is_none() {
                         #ret;
                     }
                 }
             }
+            Some(false) => quote! {},
         }
     }
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
-        if self.inner.is_none() {
-            let ret = if let Some(msg) = self.custom_none_error.as_ref() {
-                user_defined_error(wrap_return, msg)
-            } else {
-                user_defined_error(wrap_return, custom_error)
-            };
+    /// The inner value has the same path as the Option itself.
+    fn validate_with_inner(&self, field_name: &Ident, inner_validation: proc_macro2::TokenStream, none_ret: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let inner_path = bind_field_path(&format_ident!("opt"), field_path(field_name));
+        if self.required == Some(false) {
             quote! {
-                if #field_name.is_some() {
-                    #ret;
+                if let Some(opt) = &#field_name {
+                    #inner_path
+                    #inner_validation
                 }
             }
         } else {
-            let inner_validation = rod_content_match!(
-                &self.inner.as_ref().unwrap().content,
-                &format_ident!("opt"),
-                wrap_return,
-                custom_error,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
-            );
-            let ret = if let Some(msg) = self.custom_none_error.as_ref() {
-                user_defined_error(wrap_return, msg)
-            } else {
-                user_defined_error(wrap_return, custom_error)
-            };
             quote! {
                 match &#field_name {
                     Some(opt) => {
+                        #inner_path
                         #inner_validation
                     }
                     None => {
-                        #ret;
+                        #none_ret;
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+    pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
+        let required_if = self.validate_required_if(field_name, wrap_return, None);
+        let Some(inner) = self.inner.as_ref() else {
+            let validation = self.validate_without_inner(field_name, wrap_return, None);
+            return quote! {
+                #required_if
+                #validation
+            };
+        };
+        let inner_validation = rod_content_match!(
+            &inner.content,
+            &format_ident!("opt"),
+            wrap_return,
+            [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
+        );
+        let ty = inner.ty.to_string();
+        let ret = if let Some(msg) = self.custom_none_error.as_ref() {
+            user_defined_error(wrap_return, msg)
+        } else {
+            wrap_return(quote! {
+                RodValidateError::Option(OptionValidation::None(#path, #ty))
+            })
+        };
+        let validation = self.validate_with_inner(field_name, inner_validation, ret);
+        quote! {
+            #required_if
+            #validation
+        }
+    }
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        let required_if = self.validate_required_if(field_name, wrap_return, Some(custom_error));
+        let Some(inner) = self.inner.as_ref() else {
+            let validation = self.validate_without_inner(field_name, wrap_return, Some(custom_error));
+            return quote! {
+                #required_if
+                #validation
+            };
+        };
+        let inner_validation = rod_content_match!(
+            &inner.content,
+            &format_ident!("opt"),
+            wrap_return,
+            custom_error,
+            [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
+        );
+        let ret = user_defined_error(wrap_return, self.custom_none_error.as_ref().unwrap_or(custom_error));
+        let validation = self.validate_with_inner(field_name, inner_validation, ret);
+        quote! {
+            #required_if
+            #validation
+        }
+    }
+}
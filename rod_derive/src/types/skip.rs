@@ -1,8 +1,8 @@
 use proc_macro_error::abort;
-use syn::{parse::Parse, LitStr};
+use syn::parse::Parse;
 use quote::quote;
 
-use super::optional_braced;
+use super::{optional_braced, RuleMessage};
 
 /// Represents the content for a `#[rod(skip)]` field attribute.
 ///
@@ -47,7 +47,7 @@ impl RodSkipContent {
     pub(crate) fn get_validations(&self, _field_name: &syn::Ident, _wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         quote! {}
     }
-    pub(crate) fn get_validations_with_custom_error(&self, _field_name: &syn::Ident, _wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, _custom_error: &LitStr) -> proc_macro2::TokenStream {
+    pub(crate) fn get_validations_with_custom_error(&self, _field_name: &syn::Ident, _wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, _custom_error: &RuleMessage) -> proc_macro2::TokenStream {
         quote! {}
     }
 }
\ No newline at end of file
@@ -2,13 +2,13 @@ use proc_macro_error::abort;
 use quote::quote;
 use quote::ToTokens;
 
-use syn::{parse::Parse, LitStr};
+use syn::{parse::Parse, LitInt, LitStr, Path};
 use syn::Ident;
 
 
-use super::{optional_braced, user_defined_error, LengthOrSize};
+use super::{field_path, optional_braced, schema_other_rule, schema_rule, user_defined_error, FieldComparison, LengthOrSize, RodFloatContent, RodIntegerContent, RuleMessage};
 
-#[cfg(feature = "regex")]
+#[cfg(any(feature = "regex", feature = "regex-lite"))]
 mod regex_literals {
     pub(crate) const EMAIL_REGEX: &str = r#"(?:[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*|"(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x21\x23-\x5b\x5d-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])*")@(?:(?:[a-z0-9](?:[a-z0-9-]*[a-z0-9])?\.)+[a-z0-9](?:[a-z0-9-]*[a-z0-9])?|\[(?:(?:(2(5[0-5]|[0-4][0-9])|1[0-9][0-9]|[1-9]?[0-9]))\.){3}(?:(2(5[0-5]|[0-4][0-9])|1[0-9][0-9]|[1-9]?[0-9])|[a-z0-9-]*[a-z0-9]:(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x21-\x5a\x53-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])+)\])"#;
     pub(crate) const URL_REGEX: &str = r#"^[-a-zA-Z0-9@:%._\+~#=]{1,256}\.[a-zA-Z0-9()]{1,6}\b(?:[-a-zA-Z0-9()@:%_\+.~#?&//=]*)$"#;
@@ -18,9 +18,20 @@ mod regex_literals {
     pub(crate) const DATETIME_REGEX: &str = r#"^(?:\d{4})-(?:\d{2})-(?:\d{2})T(?:\d{2}):(?:\d{2}):(?:\d{2}(?:\.\d*)?)(?:(?:-(?:\d{2}):(?:\d{2})|Z)?)$"#;
 }
 
+/// Returns the path of the `Regex` type of the enabled regex backend.
+/// `regex` takes precedence over `regex-lite` when both features are enabled.
+#[cfg(any(feature = "regex", feature = "regex-lite"))]
+fn regex_type() -> proc_macro2::TokenStream {
+    #[cfg(feature = "regex")]
+    return quote!(regex::Regex);
+    #[cfg(not(feature = "regex"))]
+    return quote!(regex_lite::Regex);
+}
+
 /// `StringFormat` is an enum that represents the format of a string field.
 /// It includes variants for common formats such as email, URL, UUID, and IP addresses.
 /// The `Regex` variant allows for custom regex patterns.
+/// The `Named` variant refers to a format registered with `rod::formats!`, i.e. any type implementing `RodFormat`.
 pub(crate) enum StringFormat {
     Regex(LitStr),
     Email,
@@ -29,6 +40,25 @@ pub(crate) enum StringFormat {
     Ipv4,
     Ipv6,
     DateTime,
+    Named(Path),
+}
+
+impl StringFormat {
+    /// Returns the tokens evaluating to the regex pattern of this format.
+    #[cfg(any(feature = "regex", feature = "regex-lite"))]
+    fn pattern(&self) -> proc_macro2::TokenStream {
+        let regex = match self {
+            StringFormat::Regex(lit_str) => lit_str.value(),
+            StringFormat::Email => String::from(regex_literals::EMAIL_REGEX),
+            StringFormat::Url => String::from(regex_literals::URL_REGEX),
+            StringFormat::Uuid => String::from(regex_literals::UUID_REGEX),
+            StringFormat::Ipv4 => String::from(regex_literals::IPV4_REGEX),
+            StringFormat::Ipv6 => String::from(regex_literals::IPV6_REGEX),
+            StringFormat::DateTime => String::from(regex_literals::DATETIME_REGEX),
+            StringFormat::Named(path) => return quote!(<#path as RodFormat>::PATTERN),
+        };
+        quote!(#regex)
+    }
 }
 
 impl ToTokens for StringFormat {
@@ -41,6 +71,7 @@ impl ToTokens for StringFormat {
             StringFormat::Ipv4 => tokens.extend(quote!("Ipv4")),
             StringFormat::Ipv6 => tokens.extend(quote!("Ipv6")),
             StringFormat::DateTime => tokens.extend(quote!("DateTime")),
+            StringFormat::Named(path) => tokens.extend(quote!(<#path as RodFormat>::NAME)),
         }
     }
 }
@@ -51,8 +82,16 @@ impl Parse for StringFormat {
         if lookahead.peek(LitStr) {
             let format: LitStr = input.parse()?;
             Ok(StringFormat::Regex(format))
-        } else if lookahead.peek(Ident) {
-            let ident: Ident = input.parse()?;
+        } else if lookahead.peek(Ident)
+            || lookahead.peek(syn::Token![::])
+            || lookahead.peek(syn::Token![crate])
+            || lookahead.peek(syn::Token![self])
+            || lookahead.peek(syn::Token![super])
+        {
+            let path: Path = input.parse()?;
+            let Some(ident) = path.get_ident() else {
+                return Ok(StringFormat::Named(path));
+            };
             match ident.to_string().as_str() {
                 "Email" => Ok(StringFormat::Email),
                 "Url" => Ok(StringFormat::Url),
@@ -60,10 +99,17 @@ impl Parse for StringFormat {
                 "Ipv4" => Ok(StringFormat::Ipv4),
                 "Ipv6" => Ok(StringFormat::Ipv6),
                 "DateTime" => Ok(StringFormat::DateTime),
-                _ => abort!(
-                    ident.span(), "Unknown string format `{}`", ident;
-                    help = "Valid string formats are: Email, Url, Uuid, Ipv4, Ipv6, DateTime, or a custom regex string literal.";
-                ),
+                // A name that is close to a built-in format is most likely a typo of it
+                name => if let Some(builtin) = BUILTIN_FORMATS.iter().find(|builtin| is_near_miss(name, builtin)) {
+                    abort!(
+                        ident.span(), "Unknown string format `{}`. Did you mean `{}`?", ident, builtin;
+                        help = "Valid string formats are: Email, Url, Uuid, Ipv4, Ipv6, DateTime, a custom regex string literal, or a format registered with `formats!`.\nWrite the path of a registered format with a similar name, e.g. `self::{}`.", ident
+                    );
+                } else {
+                    // Anything else is assumed to be a format registered with `rod::formats!`.
+                    // If it is not, the generated `<T as RodFormat>` bound will point at this path.
+                    Ok(StringFormat::Named(path))
+                },
             }
         } else {
             abort!(input.span(), "Expected identifier or string literal for attribute `format`");
@@ -71,16 +117,346 @@ impl Parse for StringFormat {
     }
 }
 
+const BUILTIN_FORMATS: [&str; 6] = ["Email", "Url", "Uuid", "Ipv4", "Ipv6", "DateTime"];
+
+/// Returns `true` if `name` differs from the built-in format `builtin` only in case, or, for names of five or more letters, by one edit.
+/// Shorter names like `Ulid` or `Uri` are too close to the built-ins to be told apart from typos, so they must match exactly.
+fn is_near_miss(name: &str, builtin: &str) -> bool {
+    let name = name.to_lowercase().chars().collect::<Vec<_>>();
+    let builtin = builtin.to_lowercase().chars().collect::<Vec<_>>();
+    if name == builtin {
+        return true;
+    }
+    if name.len() < 5 {
+        return false;
+    }
+    // Edit distance where swapping two adjacent letters counts as one edit, e.g. `Emial`
+    let mut distances = vec![(0..=builtin.len()).collect::<Vec<_>>()];
+    for (i, a) in name.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in builtin.iter().enumerate() {
+            let previous = &distances[i];
+            let mut distance = (previous[j] + usize::from(a != b)).min(previous[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && *a == builtin[j - 1] && name[i - 1] == *b {
+                distance = distance.min(distances[i - 1][j - 1] + 1);
+            }
+            current.push(distance);
+        }
+        distances.push(current);
+    }
+    distances[name.len()][builtin.len()] <= 1
+}
+
+/// `StringTransform` is a normalization applied to the field by `sanitize`, written as a bare `trim`, `lowercase` or `uppercase`.
+/// Transforms run in the order they are written, and only on owned `String` fields.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum StringTransform {
+    Trim,
+    Lowercase,
+    Uppercase,
+}
+
+impl StringTransform {
+    fn from_ident(ident: &Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "trim" => Some(StringTransform::Trim),
+            "lowercase" => Some(StringTransform::Lowercase),
+            "uppercase" => Some(StringTransform::Uppercase),
+            _ => None,
+        }
+    }
+    fn apply(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        match self {
+            StringTransform::Trim => quote! {
+                if #field_name.trim().len() != #field_name.len() {
+                    *#field_name = #field_name.trim().to_string();
+                }
+            },
+            StringTransform::Lowercase => quote!(*#field_name = #field_name.to_lowercase();),
+            StringTransform::Uppercase => quote!(*#field_name = #field_name.to_uppercase();),
+        }
+    }
+}
+
+/// `StringPrintable` is the set of characters allowed by the `printable` attribute.
+/// `printable: true` rejects control characters, `printable: Ascii` also rejects non-ASCII characters.
+pub(crate) enum StringPrintable {
+    Unicode,
+    Ascii,
+}
+
+impl StringPrintable {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        match self {
+            StringPrintable::Unicode => quote!(!#field_name.chars().any(char::is_control)),
+            StringPrintable::Ascii => quote!(#field_name.chars().all(|c| c.is_ascii_graphic() || c == ' ')),
+        }
+    }
+}
+
+impl ToTokens for StringPrintable {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            StringPrintable::Unicode => tokens.extend(quote!("printable characters")),
+            StringPrintable::Ascii => tokens.extend(quote!("printable ASCII characters")),
+        }
+    }
+}
+
+impl Parse for StringPrintable {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(syn::LitBool) {
+            let lit: syn::LitBool = input.parse()?;
+            if !lit.value {
+                abort!(
+                    lit.span(), "`printable: false` has no effect";
+                    help = "Remove the `printable` attribute"
+                );
+            }
+            Ok(StringPrintable::Unicode)
+        } else if lookahead.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident == "Ascii" {
+                Ok(StringPrintable::Ascii)
+            } else {
+                abort!(ident.span(), "Expected `printable` to be `true` or `Ascii`");
+            }
+        } else {
+            abort!(input.span(), "Expected `printable` to be `true` or `Ascii`");
+        }
+    }
+}
+
+/// `StringCharsIn` is the set of characters allowed by the `chars_in` attribute,
+/// written as a character, a range of characters like `'a'..='z'`, or a list of these like `['a'..='z', '_']`.
+pub(crate) struct StringCharsIn {
+    patterns: Vec<proc_macro2::TokenStream>,
+    description: String,
+}
+
+impl StringCharsIn {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        let patterns = &self.patterns;
+        quote!(#field_name.chars().all(|c| matches!(c, #(#patterns)|*)))
+    }
+    fn parse_item(input: syn::parse::ParseStream) -> syn::Result<(proc_macro2::TokenStream, String)> {
+        let start: syn::LitChar = input.parse()?;
+        if input.peek(syn::Token![..=]) {
+            input.parse::<syn::Token![..=]>()?;
+            let end: syn::LitChar = input.parse()?;
+            Ok((quote!(#start..=#end), format!("{:?}..={:?}", start.value(), end.value())))
+        } else if input.peek(syn::Token![..]) {
+            input.parse::<syn::Token![..]>()?;
+            let end: syn::LitChar = input.parse()?;
+            Ok((quote!(#start..#end), format!("{:?}..{:?}", start.value(), end.value())))
+        } else {
+            Ok((quote!(#start), format!("{:?}", start.value())))
+        }
+    }
+}
+
+impl Parse for StringCharsIn {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let items = if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            let items = syn::punctuated::Punctuated::<_, syn::Token![,]>::parse_terminated_with(&content, Self::parse_item)?;
+            if items.is_empty() {
+                abort!(
+                    input.span(), "`chars_in` must allow at least one character";
+                    help = "Example: `chars_in: ['a'..='z', '_']`"
+                );
+            }
+            items.into_iter().collect::<Vec<_>>()
+        } else if input.peek(syn::LitChar) {
+            vec![Self::parse_item(input)?]
+        } else {
+            abort!(
+                input.span(), "Expected `chars_in` to be a character, a range of characters or a list of these";
+                help = "Example: `chars_in: ['a'..='z', '_']`"
+            );
+        };
+        let (patterns, descriptions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        Ok(StringCharsIn {
+            patterns,
+            description: format!("characters in [{}]", descriptions.join(", ")),
+        })
+    }
+}
+
+/// `StringWhitespace` is the whitespace policy of the `whitespace` attribute.
+/// - `None`: no whitespace at all.
+/// - `NoLeadingTrailing`: no whitespace at the start or end of the string.
+/// - `SingleSpacesOnly`: words separated by single spaces, with no other whitespace.
+pub(crate) enum StringWhitespace {
+    None,
+    NoLeadingTrailing,
+    SingleSpacesOnly,
+}
+
+impl StringWhitespace {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        match self {
+            StringWhitespace::None => quote!(!#field_name.chars().any(char::is_whitespace)),
+            StringWhitespace::NoLeadingTrailing => quote!(#field_name.trim().len() == #field_name.len()),
+            StringWhitespace::SingleSpacesOnly => quote! {
+                !#field_name.starts_with(' ')
+                    && !#field_name.ends_with(' ')
+                    && !#field_name.contains("  ")
+                    && !#field_name.chars().any(|c| c != ' ' && c.is_whitespace())
+            },
+        }
+    }
+}
+
+impl ToTokens for StringWhitespace {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            StringWhitespace::None => tokens.extend(quote!("no whitespace")),
+            StringWhitespace::NoLeadingTrailing => tokens.extend(quote!("no leading or trailing whitespace")),
+            StringWhitespace::SingleSpacesOnly => tokens.extend(quote!("single spaces between words only")),
+        }
+    }
+}
+
+impl Parse for StringWhitespace {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "None" => Ok(StringWhitespace::None),
+            "NoLeadingTrailing" => Ok(StringWhitespace::NoLeadingTrailing),
+            "SingleSpacesOnly" => Ok(StringWhitespace::SingleSpacesOnly),
+            _ => Err(input.error("Expected `whitespace` to be one of None, NoLeadingTrailing, SingleSpacesOnly")),
+        }
+    }
+}
+
+/// `StringNumeric` holds the `numeric: true` attribute and its modifiers `leading_zeros` and `max_digits`.
+/// A numeric string is non-empty and contains only ASCII digits.
+pub(crate) struct StringNumeric {
+    leading_zeros: bool,
+    max_digits: Option<LitInt>,
+}
+
+impl StringNumeric {
+    fn check(&self, field_name: &Ident) -> proc_macro2::TokenStream {
+        let leading_zeros = (!self.leading_zeros).then(|| quote! {
+            && (#field_name.len() == 1 || !#field_name.starts_with('0'))
+        });
+        let max_digits = self.max_digits.as_ref().map(|max_digits| quote! {
+            && #field_name.len() <= #max_digits
+        });
+        quote! {
+            !#field_name.is_empty() && #field_name.bytes().all(|b| b.is_ascii_digit())
+            #leading_zeros
+            #max_digits
+        }
+    }
+    fn describe(&self) -> String {
+        let mut description = String::from("only digits");
+        if !self.leading_zeros {
+            description.push_str(" without leading zeros");
+        }
+        if let Some(max_digits) = &self.max_digits {
+            description.push_str(&format!(", at most {} of them", max_digits));
+        }
+        description
+    }
+}
+
+/// `ParsesTo` is the type a string field must parse to with `str::parse`, used by the `parses_to` attribute.
+/// Integer and float targets accept the same rules as integer and float fields, which are checked on the parsed value.
+pub(crate) enum ParsesTo {
+    Integer(Ident, RodIntegerContent),
+    Float(Ident, RodFloatContent),
+    Other(syn::Type),
+}
+
+impl ParsesTo {
+    fn ty(&self) -> proc_macro2::TokenStream {
+        match self {
+            ParsesTo::Integer(ident, _) | ParsesTo::Float(ident, _) => quote!(#ident),
+            ParsesTo::Other(ty) => quote!(#ty),
+        }
+    }
+    /// Returns the validations run on the parsed value, which shadows the field.
+    fn inner_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> Option<proc_macro2::TokenStream> {
+        match (self, custom_error) {
+            (ParsesTo::Integer(_, content), None) => Some(content.get_validations(field_name, wrap_return)),
+            (ParsesTo::Integer(_, content), Some(custom_error)) => Some(content.get_validations_with_custom_error(field_name, wrap_return, custom_error)),
+            (ParsesTo::Float(_, content), None) => Some(content.get_validations(field_name, wrap_return)),
+            (ParsesTo::Float(_, content), Some(custom_error)) => Some(content.get_validations_with_custom_error(field_name, wrap_return, custom_error)),
+            (ParsesTo::Other(_), _) => None,
+        }
+    }
+    fn validate(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, ret: proc_macro2::TokenStream, custom_error: Option<&RuleMessage>) -> proc_macro2::TokenStream {
+        let ty = self.ty();
+        match self.inner_validations(field_name, wrap_return, custom_error) {
+            Some(validations) => quote! {
+                match #field_name.parse::<#ty>() {
+                    Ok(parsed) => {
+                        let #field_name = &parsed;
+                        #validations
+                    }
+                    Err(_) => {
+                        #ret;
+                    }
+                }
+            },
+            None => quote! {
+                if #field_name.parse::<#ty>().is_err() {
+                    #ret;
+                }
+            },
+        }
+    }
+}
+
+impl Parse for ParsesTo {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) {
+            let fork = input.fork();
+            let ident: Ident = fork.parse()?;
+            match ident.to_string().as_str() {
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+                    let ident: Ident = input.parse()?;
+                    let content: RodIntegerContent = input.parse()?;
+                    content.check_size_fits(&ident);
+                    return Ok(ParsesTo::Integer(ident, content));
+                }
+                "f32" | "f64" => {
+                    let ident: Ident = input.parse()?;
+                    return Ok(ParsesTo::Float(ident, input.parse()?));
+                }
+                _ => {}
+            }
+        }
+        Ok(ParsesTo::Other(input.parse()?))
+    }
+}
+
 /// `RodStringContent` is a struct that represents the content of a string field in a Rod entity.
 /// It is used to parse and validate string attributes in the `#[rod]` attribute macro.
 /// This struct includes optional fields for length, format, starts_with, ends_with, and includes, 
 /// which are used in validation checks.
 /// # Attributes
 /// - `length`: An optional attribute that specifies the length of the string.
-/// - `format`: An optional attribute that specifies the format of the string, such as email, URL, UUID, or any custom regex. See [`StringFormat`][crate::types::string::StringFormat] enum. Note that this attribute requires the `regex` feature to be enabled.
+/// - `min_length`, `max_length`: Optional attributes that specify a single bound on the length of the string, each with its own custom error. They cannot be combined with `length`.
+/// - `format`: An optional attribute that specifies the format of the string, such as email, URL, UUID, a format registered with `rod::formats!`, or any custom regex. See [`StringFormat`][crate::types::string::StringFormat] enum. Note that this attribute requires the `regex` or `regex-lite` feature to be enabled.
 /// - `starts_with`: An optional attribute that specifies the string must start with this value.
 /// - `ends_with`: An optional attribute that specifies the string must end with this value.
 /// - `includes`: An optional attribute that specifies the string must include this value.
+/// - `parses_to`: An optional attribute that specifies a type the string must parse to with `str::parse`. See [`ParsesTo`][crate::types::string::ParsesTo] enum.
+///   Integer and float types can be followed by their usual rules, e.g. `parses_to: u16 { size: 1024.. }`, which are checked on the parsed value.
+/// - `whitespace`: An optional attribute that specifies a whitespace policy, see [`StringWhitespace`][crate::types::string::StringWhitespace] enum.
+/// - `numeric`: An optional attribute that specifies, with `numeric: true`, that the string must contain only digits.
+///   It can be refined with `leading_zeros: false` and `max_digits: <n>`. See [`StringNumeric`][crate::types::string::StringNumeric] struct.
+/// - `chars_in`: An optional attribute that specifies the characters allowed in the string, e.g. `chars_in: ['a'..='z', '_']`. See [`StringCharsIn`][crate::types::string::StringCharsIn] struct.
+/// - `printable`: An optional attribute that rejects control characters with `printable: true`, or anything but printable ASCII with `printable: Ascii`. See [`StringPrintable`][crate::types::string::StringPrintable] enum.
+/// - `eq_field`, `ne_field`: Optional attributes that specify a sibling field of the struct that the field must equal, or differ from, e.g. `eq_field: password`.
+/// - `trim`, `lowercase`, `uppercase`: Optional transforms that `sanitize` applies to the field in the order they are written. See [`StringTransform`][crate::types::string::StringTransform] enum.
+///   They do not change what `validate` checks, so call `sanitize`, or `validate_sanitized`, first.
 /// # Usage
 /// ```
 /// use rod::prelude::*;
@@ -107,47 +483,109 @@ impl Parse for StringFormat {
 /// 
 pub struct RodStringContent {
     length: Option<LengthOrSize>,
+    min_length: Option<LengthOrSize>,
+    max_length: Option<LengthOrSize>,
     format: Option<StringFormat>,
     starts_with: Option<LitStr>,
     ends_with: Option<LitStr>,
     includes: Option<LitStr>,
-    custom_errors: [Option<LitStr>; 5], // length, format, starts_with, ends_with, includes
+    parses_to: Option<ParsesTo>,
+    printable: Option<StringPrintable>,
+    whitespace: Option<StringWhitespace>,
+    numeric: Option<StringNumeric>,
+    chars_in: Option<StringCharsIn>,
+    field_comparisons: Vec<FieldComparison>,
+    transforms: Vec<StringTransform>,
+    custom_errors: [Option<RuleMessage>; 12], // length, format, starts_with, ends_with, includes, parses_to, printable, whitespace, numeric, chars_in, min_length, max_length
 }
 
 impl RodStringContent {
-    pub(crate) fn get_validations(&self, field_name: &proc_macro2::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let path = field_name.to_string();
-        let length_opt = self.length.as_ref().map(|length| {
-            if let Some(msg) = self.custom_errors[0].as_ref() {
-                length.validate_string_with_custom_error(field_name, wrap_return, msg)
-            } else {
-                length.validate_string(field_name, wrap_return)
+    /// Returns the first comparison to another field, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        self.field_comparisons.first()
+    }
+    /// Returns the transforms of the field, applied to `field_name` bound to a `&mut String`, if it has any.
+    pub(crate) fn get_sanitize(&self, field_name: &Ident) -> Option<proc_macro2::TokenStream> {
+        if self.transforms.is_empty() {
+            return None;
+        }
+        Some(self.transforms.iter().map(|transform| transform.apply(field_name)).collect())
+    }
+    pub(crate) fn get_fixes(&self, field_name: &Ident, path: &str) -> Option<proc_macro2::TokenStream> {
+        self.length.as_ref().or(self.max_length.as_ref()).map(|length| length.fix_string(field_name, path))
+    }
+    /// Returns the `schema::TypeSchema` describing the rules of the field. Rules other than lengths, formats and affixes are described in words.
+    pub(crate) fn get_schema(&self) -> proc_macro2::TokenStream {
+        let mut rules = [&self.length, &self.min_length, &self.max_length].into_iter().zip([0, 10, 11]).filter_map(|(length, i)| {
+            length.as_ref().map(|length| {
+                let bounds = length.schema_bounds();
+                schema_rule(quote!(__rod_schema::RuleKind::Length(#bounds)), self.custom_errors[i].as_ref())
+            })
+        }).collect::<Vec<_>>();
+        if let Some(format) = self.format.as_ref() {
+            #[cfg(any(feature = "regex", feature = "regex-lite"))]
+            {
+                let pattern = format.pattern();
+                rules.push(schema_rule(quote!(__rod_schema::RuleKind::Format { name: #format, pattern: #pattern }), self.custom_errors[1].as_ref()));
             }
-        });
-        #[cfg(feature = "regex")]
+            #[cfg(not(any(feature = "regex", feature = "regex-lite")))]
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Format { name: #format, pattern: "" }), self.custom_errors[1].as_ref()));
+        }
+        if let Some(starts_with) = self.starts_with.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::StartsWith(#starts_with)), self.custom_errors[2].as_ref()));
+        }
+        if let Some(ends_with) = self.ends_with.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::EndsWith(#ends_with)), self.custom_errors[3].as_ref()));
+        }
+        if let Some(includes) = self.includes.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Includes(#includes)), self.custom_errors[4].as_ref()));
+        }
+        if let Some(parses_to) = self.parses_to.as_ref() {
+            rules.push(schema_other_rule(&format!("parses to {}", parses_to.ty()), self.custom_errors[5].as_ref()));
+        }
+        if let Some(printable) = self.printable.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Other(#printable)), self.custom_errors[6].as_ref()));
+        }
+        if let Some(whitespace) = self.whitespace.as_ref() {
+            rules.push(schema_rule(quote!(__rod_schema::RuleKind::Other(#whitespace)), self.custom_errors[7].as_ref()));
+        }
+        if let Some(numeric) = self.numeric.as_ref() {
+            rules.push(schema_other_rule(&numeric.describe(), self.custom_errors[8].as_ref()));
+        }
+        if let Some(chars_in) = self.chars_in.as_ref() {
+            rules.push(schema_other_rule(&chars_in.description, self.custom_errors[9].as_ref()));
+        }
+        rules.extend(self.field_comparisons.iter().map(FieldComparison::schema_rule));
+        quote!(__rod_schema::TypeSchema::String(vec![#(#rules),*]))
+    }
+    pub(crate) fn get_validations(&self, field_name: &proc_macro2::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let path = field_path(field_name);
+        let length_opt = [&self.length, &self.min_length, &self.max_length].into_iter().zip([0, 10, 11]).filter_map(|(length, i)| {
+            length.as_ref().map(|length| {
+                if let Some(msg) = self.custom_errors[i].as_ref() {
+                    length.validate_string_with_custom_error(field_name, wrap_return, msg)
+                } else {
+                    length.validate_string(field_name, wrap_return)
+                }
+            })
+        }).collect::<proc_macro2::TokenStream>();
+        #[cfg(any(feature = "regex", feature = "regex-lite"))]
         let format_opt = self.format.as_ref().map(|format| {
-            let regex = match format {
-                StringFormat::Regex(lit_str) => lit_str.value(),
-                StringFormat::Email => String::from(regex_literals::EMAIL_REGEX),
-                StringFormat::Url => String::from(regex_literals::URL_REGEX),
-                StringFormat::Uuid => String::from(regex_literals::UUID_REGEX),
-                StringFormat::Ipv4 => String::from(regex_literals::IPV4_REGEX),
-                StringFormat::Ipv6 => String::from(regex_literals::IPV6_REGEX),
-                StringFormat::DateTime => String::from(regex_literals::DATETIME_REGEX),
-            };
+            let regex = format.pattern();
+            let regex_type = regex_type();
             let ret = if let Some(msg) = self.custom_errors[1].as_ref() {
                 user_defined_error(wrap_return, msg)
             } else {
                 wrap_return(quote!{ RodValidateError::String(StringValidation::Format(#path, name, #format)) })
             };
             quote! {
-                if !regex::Regex::new(#regex).unwrap().is_match(&#field_name) {
+                if !#regex_type::new(#regex).unwrap().is_match(&#field_name) {
                     let name = String::from(#field_name);
                     #ret;
                 }
             }
         });
-        #[cfg(not(feature = "regex"))]
+        #[cfg(not(any(feature = "regex", feature = "regex-lite")))]
         let format_opt: Option<proc_macro2::TokenStream> = None;
         let starts_with_opt = self.starts_with.as_ref().map(|starts_with| {
             let ret = if let Some(msg) = self.custom_errors[2].as_ref() {
@@ -185,6 +623,71 @@ impl RodStringContent {
                 }
             }
         });
+        let parses_to_opt = self.parses_to.as_ref().map(|parses_to| {
+            let ty = parses_to.ty().to_string();
+            let ret = if let Some(msg) = self.custom_errors[5].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote!{ RodValidateError::String(StringValidation::ParsesTo(#path, #field_name.to_string(), #ty)) })
+            };
+            parses_to.validate(field_name, wrap_return, ret, None)
+        });
+        let printable_opt = self.printable.as_ref().map(|printable| {
+            let check = printable.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[6].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote!{ RodValidateError::String(StringValidation::Printable(#path, #field_name.to_string(), #printable)) })
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let whitespace_opt = self.whitespace.as_ref().map(|whitespace| {
+            let check = whitespace.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[7].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote!{ RodValidateError::String(StringValidation::Whitespace(#path, #field_name.to_string(), #whitespace)) })
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let numeric_opt = self.numeric.as_ref().map(|numeric| {
+            let check = numeric.check(field_name);
+            let description = numeric.describe();
+            let ret = if let Some(msg) = self.custom_errors[8].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote!{ RodValidateError::String(StringValidation::Numeric(#path, #field_name.to_string(), #description)) })
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let chars_in_opt = self.chars_in.as_ref().map(|chars_in| {
+            let check = chars_in.check(field_name);
+            let description = &chars_in.description;
+            let ret = if let Some(msg) = self.custom_errors[9].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                wrap_return(quote!{ RodValidateError::String(StringValidation::CharsIn(#path, #field_name.to_string(), #description)) })
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+
+        let comparisons_opt = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, None)).collect::<proc_macro2::TokenStream>();
 
         quote! {
             #length_opt
@@ -192,39 +695,36 @@ impl RodStringContent {
             #starts_with_opt
             #ends_with_opt
             #includes_opt
+            #parses_to_opt
+            #printable_opt
+            #whitespace_opt
+            #numeric_opt
+            #chars_in_opt
+            #comparisons_opt
         }
     }
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &proc_macro2::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
-        let length_opt = self.length.as_ref().map(|length| {
-            if let Some(msg) = self.custom_errors[0].as_ref() {
-                length.validate_string_with_custom_error(field_name, wrap_return, msg)
-            } else {
-                length.validate_string_with_custom_error(field_name, wrap_return, custom_error)
-            }
-        });
-        #[cfg(feature = "regex")]
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &proc_macro2::Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        let length_opt = [&self.length, &self.min_length, &self.max_length].into_iter().zip([0, 10, 11]).filter_map(|(length, i)| {
+            length.as_ref().map(|length| {
+                length.validate_string_with_custom_error(field_name, wrap_return, self.custom_errors[i].as_ref().unwrap_or(custom_error))
+            })
+        }).collect::<proc_macro2::TokenStream>();
+        #[cfg(any(feature = "regex", feature = "regex-lite"))]
         let format_opt = self.format.as_ref().map(|format| {
-            let regex = match format {
-                StringFormat::Regex(lit_str) => lit_str.value(),
-                StringFormat::Email => String::from(regex_literals::EMAIL_REGEX),
-                StringFormat::Url => String::from(regex_literals::URL_REGEX),
-                StringFormat::Uuid => String::from(regex_literals::UUID_REGEX),
-                StringFormat::Ipv4 => String::from(regex_literals::IPV4_REGEX),
-                StringFormat::Ipv6 => String::from(regex_literals::IPV6_REGEX),
-                StringFormat::DateTime => String::from(regex_literals::DATETIME_REGEX),
-            };
+            let regex = format.pattern();
+            let regex_type = regex_type();
             let ret = if let Some(msg) = self.custom_errors[1].as_ref() {
                 user_defined_error(wrap_return, msg)
             } else {
                 user_defined_error(wrap_return, custom_error)
             };
             quote! {
-                if !regex::Regex::new(#regex).unwrap().is_match(&#field_name) {
+                if !#regex_type::new(#regex).unwrap().is_match(&#field_name) {
                     #ret;
                 }
             }
         });
-        #[cfg(not(feature = "regex"))]
+        #[cfg(not(any(feature = "regex", feature = "regex-lite")))]
         let format_opt: Option<proc_macro2::TokenStream> = None;
         let starts_with_opt = self.starts_with.as_ref().map(|starts_with| {
             let ret = if let Some(msg) = self.custom_errors[2].as_ref() {
@@ -262,6 +762,68 @@ impl RodStringContent {
                 }
             }
         });
+        let parses_to_opt = self.parses_to.as_ref().map(|parses_to| {
+            let ret = if let Some(msg) = self.custom_errors[5].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            parses_to.validate(field_name, wrap_return, ret, Some(custom_error))
+        });
+        let printable_opt = self.printable.as_ref().map(|printable| {
+            let check = printable.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[6].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let whitespace_opt = self.whitespace.as_ref().map(|whitespace| {
+            let check = whitespace.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[7].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let numeric_opt = self.numeric.as_ref().map(|numeric| {
+            let check = numeric.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[8].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+        let chars_in_opt = self.chars_in.as_ref().map(|chars_in| {
+            let check = chars_in.check(field_name);
+            let ret = if let Some(msg) = self.custom_errors[9].as_ref() {
+                user_defined_error(wrap_return, msg)
+            } else {
+                user_defined_error(wrap_return, custom_error)
+            };
+            quote! {
+                if !(#check) {
+                    #ret;
+                }
+            }
+        });
+
+        let comparisons_opt = self.field_comparisons.iter().map(|comparison| comparison.validate(field_name, wrap_return, Some(custom_error))).collect::<proc_macro2::TokenStream>();
 
         quote! {
             #length_opt
@@ -269,6 +831,12 @@ impl RodStringContent {
             #starts_with_opt
             #ends_with_opt
             #includes_opt
+            #parses_to_opt
+            #printable_opt
+            #whitespace_opt
+            #numeric_opt
+            #chars_in_opt
+            #comparisons_opt
         }
     }
 }
@@ -280,21 +848,41 @@ impl Parse for RodStringContent {
             Some(buffer) => buffer,
             None => return Ok(RodStringContent {
                 length: None,
+                min_length: None,
+                max_length: None,
                 format: None,
                 starts_with: None,
                 ends_with: None,
                 includes: None,
-                custom_errors: [None, None, None, None, None],
+                parses_to: None,
+                printable: None,
+                whitespace: None,
+                numeric: None,
+                chars_in: None,
+                field_comparisons: Vec::new(),
+                transforms: Vec::new(),
+                custom_errors: [None, None, None, None, None, None, None, None, None, None, None, None],
             }),
         };
 
         let mut length = None;
+        let mut min_length = None;
+        let mut max_length = None;
         let mut format = None;
         let mut starts_with = None;
         let mut ends_with = None;
         let mut includes = None;
-        let mut message: Option<LitStr> = None;
-        let mut custom_errors: [Option<LitStr>; 5] = [None, None, None, None, None];
+        let mut parses_to = None;
+        let mut printable = None;
+        let mut whitespace = None;
+        let mut numeric: Option<syn::LitBool> = None;
+        let mut leading_zeros: Option<syn::LitBool> = None;
+        let mut max_digits: Option<LitInt> = None;
+        let mut chars_in = None;
+        let mut field_comparisons = Vec::new();
+        let mut transforms: Vec<StringTransform> = Vec::new();
+        let mut message: Option<RuleMessage> = None;
+        let mut custom_errors: [Option<RuleMessage>; 12] = [None, None, None, None, None, None, None, None, None, None, None, None];
 
         while !inner.is_empty() {
             let lookahead = inner.lookahead1();
@@ -307,8 +895,22 @@ impl Parse for RodStringContent {
                     if let Some(msg) = message.take() {
                         custom_errors[0] = Some(msg);
                     }
+                } else if ident == "min_length" {
+                    check_already_used_attr!(min_length, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    min_length = Some(LengthOrSize::parse_bound(&inner, &ident)?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[10] = Some(msg);
+                    }
+                } else if ident == "max_length" {
+                    check_already_used_attr!(max_length, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    max_length = Some(LengthOrSize::parse_bound(&inner, &ident)?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[11] = Some(msg);
+                    }
                 } else if ident == "format" {
-                    #[cfg(feature = "regex")]
+                    #[cfg(any(feature = "regex", feature = "regex-lite"))]
                     {
                         check_already_used_attr!(format, ident.span());
                         inner.parse::<syn::Token![:]>()?;
@@ -317,9 +919,9 @@ impl Parse for RodStringContent {
                             custom_errors[1] = Some(msg);
                         }
                     }
-                    #[cfg(not(feature = "regex"))]
+                    #[cfg(not(any(feature = "regex", feature = "regex-lite")))]
                     {
-                        abort!(ident.span(), "The `format` attribute is not available. Please enable the `regex` feature.");
+                        abort!(ident.span(), "The `format` attribute is not available. Please enable the `regex` or `regex-lite` feature.");
                     }
                 } else if ident == "includes" {
                     check_already_used_attr!(includes, ident.span());
@@ -342,6 +944,65 @@ impl Parse for RodStringContent {
                     if let Some(msg) = message.take() {
                         custom_errors[3] = Some(msg);
                     }
+                } else if ident == "parses_to" {
+                    check_already_used_attr!(parses_to, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    parses_to = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[5] = Some(msg);
+                    }
+                } else if ident == "printable" {
+                    check_already_used_attr!(printable, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    printable = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[6] = Some(msg);
+                    }
+                } else if ident == "whitespace" {
+                    check_already_used_attr!(whitespace, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    whitespace = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[7] = Some(msg);
+                    }
+                } else if ident == "numeric" || ident == "leading_zeros" || ident == "max_digits" {
+                    inner.parse::<syn::Token![:]>()?;
+                    if ident == "numeric" {
+                        check_already_used_attr!(numeric, ident.span());
+                        numeric = Some(inner.parse()?);
+                    } else if ident == "leading_zeros" {
+                        check_already_used_attr!(leading_zeros, ident.span());
+                        leading_zeros = Some(inner.parse()?);
+                    } else {
+                        check_already_used_attr!(max_digits, ident.span());
+                        max_digits = Some(inner.parse()?);
+                    }
+                    if let Some(msg) = message.take() {
+                        custom_errors[8] = Some(msg);
+                    }
+                } else if ident == "chars_in" {
+                    check_already_used_attr!(chars_in, ident.span());
+                    inner.parse::<syn::Token![:]>()?;
+                    chars_in = Some(inner.parse()?);
+                    if let Some(msg) = message.take() {
+                        custom_errors[9] = Some(msg);
+                    }
+                } else if let Some(comparison) = FieldComparison::parse_rule(&ident, &inner, &mut message, false)? {
+                    field_comparisons.push(comparison);
+                } else if let Some(transform) = StringTransform::from_ident(&ident) {
+                    if transforms.contains(&transform) {
+                        abort!(
+                            ident.span(), "The transform `{}` is used multiple times", ident;
+                            help = "Remove the extra `{}`", ident
+                        );
+                    }
+                    if let Some(msg) = message.as_ref() {
+                        abort!(
+                            msg.message.span(), "Transforms cannot have a message, since they do not fail";
+                            help = "Move the message in front of a rule"
+                        );
+                    }
+                    transforms.push(transform);
                 } else {
                     abort!(
                         ident.span(),
@@ -350,8 +1011,7 @@ impl Parse for RodStringContent {
                 }
             } else {
                 if lookahead.peek(syn::Token![?]) {
-                    let _q: syn::Token![?] = inner.parse()?;
-                    let result: LitStr = inner.parse()?;
+                    let result: RuleMessage = inner.parse()?;
                     message = Some(result);
                 } else {
                     abort!(
@@ -364,12 +1024,57 @@ impl Parse for RodStringContent {
             _ = inner.parse::<syn::Token![,]>();
         }
 
+        let numeric = match numeric {
+            Some(numeric) if numeric.value => Some(StringNumeric {
+                leading_zeros: leading_zeros.is_none_or(|leading_zeros| leading_zeros.value),
+                max_digits,
+            }),
+            Some(numeric) => {
+                abort!(
+                    numeric.span(), "`numeric: false` has no effect";
+                    help = "Remove the `numeric` attribute"
+                );
+            }
+            None => {
+                if let Some(span) = leading_zeros.as_ref().map(|lit| lit.span()).or(max_digits.as_ref().map(|lit| lit.span())) {
+                    abort!(
+                        span, "`leading_zeros` and `max_digits` require `numeric: true`";
+                        help = "Add `numeric: true`"
+                    );
+                }
+                None
+            }
+        };
+
+        if transforms.contains(&StringTransform::Lowercase) && transforms.contains(&StringTransform::Uppercase) {
+            abort!(
+                input.span(), "`lowercase` cannot be combined with `uppercase`";
+                help = "Keep the one the field should end up with"
+            );
+        }
+
+        if length.is_some() && (min_length.is_some() || max_length.is_some()) {
+            abort!(
+                input.span(), "`length` cannot be combined with `min_length` or `max_length`";
+                help = "Use either `length: 1..=10` or `min_length: 1, max_length: 10`"
+            );
+        }
+
         Ok(RodStringContent { 
             length, 
+            min_length,
+            max_length,
             format,
             starts_with,
             ends_with,
             includes,
+            parses_to,
+            printable,
+            whitespace,
+            numeric,
+            chars_in,
+            field_comparisons,
+            transforms,
             custom_errors,
         })
     }
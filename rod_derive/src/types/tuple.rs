@@ -1,9 +1,9 @@
 use quote::{format_ident, quote};
-use syn::{parse::Parse, Ident, Index, LitStr};
+use syn::{parse::Parse, Ident, Index};
 
 use crate::{RodAttr, RodAttrContent};
 
-use super::optional_paren;
+use super::{bind_field_path, field_path, optional_paren, FieldComparison, RuleMessage};
 
 macro_rules! rod_content_match {
     ($content:expr, $field_access:expr, $wrap_return:expr, [ $( $variant:ident ),* ]) => {
@@ -53,6 +53,8 @@ macro_rules! rod_content_match {
 /// In the above example, `RodTupleContent` would contain two `RodAttr` entries,
 /// both defining validation rules for the two `i32` elements of the tuple.
 ///
+/// Elements that do not need any rules can be marked with `Skip`, which matches an element of any type.
+///
 /// This struct is used internally by the derive macro to generate validation logic
 /// for each tuple element, based on the specified attributes.
 pub struct RodTupleContent {
@@ -74,36 +76,68 @@ impl Parse for RodTupleContent {
     }
 }
 
+/// Binds the path of the element at `index`, like `point.1`.
+fn element_path(field_name: &Ident, index: &Index) -> proc_macro2::TokenStream {
+    let subfield_name = format_ident!("{}_{}", field_name, index);
+    let path = field_path(field_name);
+    let index = index.index.to_string();
+    bind_field_path(&subfield_name, quote!(format!("{}.{}", #path, #index)))
+}
+
 impl RodTupleContent {
+    /// Removes the comparisons to other fields from the rules of the elements, for validations that run without the rest of the struct.
+    pub(crate) fn strip_field_comparisons(&mut self) {
+        self.fields.iter_mut().for_each(crate::strip_field_comparisons);
+    }
+    /// Returns the first comparison to another field in the rules of the elements, if there is one.
+    pub(crate) fn field_comparison(&self) -> Option<&FieldComparison> {
+        self.fields.iter().find_map(crate::find_field_comparison)
+    }
+    /// Returns the `schema::TypeSchema` of the field, where `element_types` are the types of its elements, if they are known.
+    pub(crate) fn get_schema(&self, element_types: Option<Vec<&syn::Type>>) -> proc_macro2::TokenStream {
+        let elements = self.fields.iter().enumerate().map(|(i, field)| {
+            let element_type = element_types.as_ref().and_then(|types| types.get(i).copied());
+            crate::schema::get_attr_schema(field, element_type)
+        });
+        quote!(__rod_schema::TypeSchema::Tuple(vec![#(#elements),*]))
+    }
+    /// The elements that have rules, with their positions. `Skip` elements are left out, so no unused binding is generated for them.
+    fn validated_fields(&self) -> impl Iterator<Item = (usize, &RodAttr)> {
+        self.fields.iter().enumerate().filter(|(_, field)| !matches!(field.content, RodAttrContent::Skip(_)))
+    }
     pub(crate) fn get_validations(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        self.fields.iter().enumerate().map(|(i, field)| {
+        self.validated_fields().map(|(i, field)| {
             let i = Index::from(i);
             let subfield_name = format_ident!("{}_{}", field_name, i);
+            let subfield_path = element_path(field_name, &i);
             let inner_validation = rod_content_match!(
                 &field.content,
                 &subfield_name,
                 wrap_return,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
+                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
             );
             quote! {
                 let #subfield_name = &#field_name.#i;
+                #subfield_path
                 #inner_validation
             }
         }).collect()
     }
-    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &LitStr) -> proc_macro2::TokenStream {
-        self.fields.iter().enumerate().map(|(i, field)| {
+    pub(crate) fn get_validations_with_custom_error(&self, field_name: &Ident, wrap_return: fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, custom_error: &RuleMessage) -> proc_macro2::TokenStream {
+        self.validated_fields().map(|(i, field)| {
             let i = Index::from(i);
             let subfield_name = format_ident!("{}_{}", field_name, i);
+            let subfield_path = element_path(field_name, &i);
             let inner_validation_with_custom_error = rod_content_match!(
                 &field.content,
                 &subfield_name,
                 wrap_return,
                 custom_error,
-                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable]
+                [String, Integer, Literal, Boolean, Option, Float, Tuple, Skip, Custom, Iterable, EnumTag]
             );
             quote! {
                 let #subfield_name = &#field_name.#i;
+                #subfield_path
                 #inner_validation_with_custom_error
             }
         }).collect()
@@ -0,0 +1,110 @@
+use std::ops::Deref;
+
+use crate::errors::{RodValidateError, RodValidateErrorList, RodValidateReport};
+use crate::schema::StructSchema;
+use crate::{RodFix, RodValidate, Valid};
+
+/// A value that is valid for as long as it lives. Unlike [`Valid`], it can be changed, but only through [`Checked::set`]
+/// and [`Checked::update`], which validate the new value with `validate_all` and keep the old one if it is invalid.
+/// This keeps long-lived state, like the settings of a running service, valid after every change.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Checked<T>(T);
+
+impl<T: RodValidate> Checked<T> {
+    /// Validates `value` with `validate_all`, returning it wrapped in `Checked` if it is valid.
+    pub fn new(value: T) -> Result<Self, RodValidateErrorList> {
+        value.validate_all()?;
+        Ok(Checked(value))
+    }
+    /// Replaces the value with `value` if it is valid. Otherwise, the value is unchanged and the errors of `value` are returned.
+    pub fn set(&mut self, value: T) -> Result<(), RodValidateErrorList> {
+        value.validate_all()?;
+        self.0 = value;
+        Ok(())
+    }
+}
+
+impl<T: RodValidate + Clone> Checked<T> {
+    /// Changes a copy of the value with `f`, and keeps the change if the copy is valid.
+    /// Otherwise, the value is unchanged and the errors of the copy are returned.
+    pub fn update(&mut self, f: impl FnOnce(&mut T)) -> Result<(), RodValidateErrorList> {
+        let mut value = self.0.clone();
+        f(&mut value);
+        self.set(value)
+    }
+}
+
+impl<T> Checked<T> {
+    /// Returns the value, which is no longer checked once it changes.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// A `Valid<T>` already passed `validate_all`, so it is not validated again.
+impl<T> From<Valid<T>> for Checked<T> {
+    fn from(valid: Valid<T>) -> Self {
+        Checked(valid.into_inner())
+    }
+}
+
+impl<T> Deref for Checked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Checked<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// A `Checked<T>` field of a derived type is validated like the `T` it holds.
+impl<T: RodValidate> RodValidate for Checked<T> {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        self.0.validate()
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        self.0.validate_all()
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        self.0.validate_all_limited(max_errors)
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        self.0.validate_report()
+    }
+    fn validate_field(&self, path: &str) -> Result<(), RodValidateErrorList> {
+        self.0.validate_field(path)
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        self.0.validate_tagged(tags)
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        self.0.validate_all_tagged(tags)
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        self.0.suggest_fixes()
+    }
+    fn rod_schema() -> StructSchema {
+        T::rod_schema()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Checked<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes a `T` and validates it, failing with the validation errors as the message if it is invalid.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + RodValidate> serde::Deserialize<'de> for Checked<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Checked::new(value).map_err(|errors| serde::de::Error::custom(errors.to_compact_string()))
+    }
+}
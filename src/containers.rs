@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::errors::{RodValidateError, RodValidateErrorList, RodValidateReport};
+use crate::schema::StructSchema;
+use crate::{RodFix, RodValidate};
+
+// Containers of types that implement `RodValidate` validate each of their values, so fields like `Vec<Address>` or `Option<Address>`
+// need no `#[rod(...)]` attribute. The errors of a value are prefixed with its position, e.g. `[2].zip`, which the field then prefixes with its name.
+
+/// Validates `items` in order, stopping at the first item that fails.
+fn validate_items<'a, T: RodValidate + ?Sized + 'a>(
+    items: impl IntoIterator<Item = (String, &'a T)>,
+    validate: impl Fn(&T) -> Result<(), RodValidateError>,
+) -> Result<(), RodValidateError> {
+    for (path, item) in items {
+        validate(item).map_err(|error| error.with_path_prefix(&path))?;
+    }
+    Ok(())
+}
+
+/// Validates every item in order, collecting at most `max_errors` errors. The list is truncated if there were more.
+fn validate_all_items<'a, T: RodValidate + ?Sized + 'a>(
+    items: impl IntoIterator<Item = (String, &'a T)>,
+    max_errors: usize,
+    validate: impl Fn(&T) -> Result<(), RodValidateErrorList>,
+) -> Result<(), RodValidateErrorList> {
+    let mut errors = RodValidateErrorList::new();
+    for (path, item) in items {
+        if let Err(item_errors) = validate(item) {
+            errors.merge(prefixed(item_errors, &path));
+        }
+        if errors.len() > max_errors {
+            errors.truncate(max_errors);
+            break;
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Collects the errors and warnings of every item in order.
+fn report_items<'a, T: RodValidate + ?Sized + 'a>(items: impl IntoIterator<Item = (String, &'a T)>) -> RodValidateReport {
+    let mut report = RodValidateReport::new();
+    for (path, item) in items {
+        let item_report = item.validate_report();
+        report.errors.merge(prefixed(item_report.errors, &path));
+        report.warnings.merge(prefixed(item_report.warnings, &path));
+    }
+    report
+}
+
+fn fix_items<'a, T: RodValidate + ?Sized + 'a>(items: impl IntoIterator<Item = (String, &'a T)>) -> Vec<RodFix> {
+    items
+        .into_iter()
+        .flat_map(|(path, item)| item.suggest_fixes().into_iter().map(move |fix| fix.with_path_prefix(&path)))
+        .collect()
+}
+
+fn prefixed(errors: RodValidateErrorList, path: &str) -> RodValidateErrorList {
+    let truncated = errors.is_truncated();
+    let mut errors: RodValidateErrorList = errors.into_iter().map(|error| error.with_path_prefix(path)).collect();
+    errors.set_truncated(truncated);
+    errors
+}
+
+fn indexed<T>(items: &[T]) -> impl Iterator<Item = (String, &T)> {
+    items.iter().enumerate().map(|(index, item)| (format!("[{}]", index), item))
+}
+
+/// Each item is validated, and its errors are prefixed with its index, like `[2].zip`.
+impl<T: RodValidate> RodValidate for [T] {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        validate_items(indexed(self), T::validate)
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        validate_all_items(indexed(self), usize::MAX, T::validate_all)
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        let max_errors = max_errors.max(1);
+        validate_all_items(indexed(self), max_errors, |item| item.validate_all_limited(max_errors.saturating_add(1)))
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        report_items(indexed(self))
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        validate_items(indexed(self), |item| item.validate_tagged(tags))
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        validate_all_items(indexed(self), usize::MAX, |item| item.validate_all_tagged(tags))
+    }
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(T::sanitize);
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        fix_items(indexed(self))
+    }
+}
+
+/// Validated like a slice of its items.
+impl<T: RodValidate> RodValidate for Vec<T> {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        self.as_slice().validate()
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        self.as_slice().validate_all()
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        self.as_slice().validate_all_limited(max_errors)
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        self.as_slice().validate_report()
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        self.as_slice().validate_tagged(tags)
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        self.as_slice().validate_all_tagged(tags)
+    }
+    fn sanitize(&mut self) {
+        self.as_mut_slice().sanitize()
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        self.as_slice().suggest_fixes()
+    }
+}
+
+/// Validated like a slice of its items.
+impl<T: RodValidate, const N: usize> RodValidate for [T; N] {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        self.as_slice().validate()
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        self.as_slice().validate_all()
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        self.as_slice().validate_all_limited(max_errors)
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        self.as_slice().validate_report()
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        self.as_slice().validate_tagged(tags)
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        self.as_slice().validate_all_tagged(tags)
+    }
+    fn sanitize(&mut self) {
+        self.as_mut_slice().sanitize()
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        self.as_slice().suggest_fixes()
+    }
+}
+
+/// Each value is validated, and its errors are prefixed with its key, like `[home].zip`. Keys are not validated.
+/// The values are visited in the iteration order of the map, so `validate` reports the error of any one failing value.
+impl<K: Display, V: RodValidate, S> RodValidate for HashMap<K, V, S> {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        validate_items(keyed(self), V::validate)
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        validate_all_items(keyed(self), usize::MAX, V::validate_all)
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        let max_errors = max_errors.max(1);
+        validate_all_items(keyed(self), max_errors, |value| value.validate_all_limited(max_errors.saturating_add(1)))
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        report_items(keyed(self))
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        validate_items(keyed(self), |value| value.validate_tagged(tags))
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        validate_all_items(keyed(self), usize::MAX, |value| value.validate_all_tagged(tags))
+    }
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        fix_items(keyed(self))
+    }
+}
+
+fn keyed<K: Display, V, S>(map: &HashMap<K, V, S>) -> impl Iterator<Item = (String, &V)> {
+    map.iter().map(|(key, value)| (format!("[{}]", key), value))
+}
+
+/// `None` is valid, and `Some` is validated like the value it holds.
+impl<T: RodValidate> RodValidate for Option<T> {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        self.as_ref().map_or(Ok(()), T::validate)
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        self.as_ref().map_or(Ok(()), T::validate_all)
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        self.as_ref().map_or(Ok(()), |value| value.validate_all_limited(max_errors))
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        self.as_ref().map(T::validate_report).unwrap_or_default()
+    }
+    fn validate_field(&self, path: &str) -> Result<(), RodValidateErrorList> {
+        self.as_ref().map_or(Ok(()), |value| value.validate_field(path))
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        self.as_ref().map_or(Ok(()), |value| value.validate_tagged(tags))
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        self.as_ref().map_or(Ok(()), |value| value.validate_all_tagged(tags))
+    }
+    fn sanitize(&mut self) {
+        if let Some(value) = self {
+            value.sanitize();
+        }
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        self.as_ref().map(T::suggest_fixes).unwrap_or_default()
+    }
+    fn rod_schema() -> StructSchema {
+        T::rod_schema()
+    }
+}
+
+/// Pointers are validated like the value they point to, including unsized values like trait objects, e.g. `Box<dyn Shape>`
+/// where `Shape` is a trait with `RodValidate` as a supertrait. `sanitize` changes the value only if the pointer can change it.
+macro_rules! impl_rod_validate_pointer {
+    ($(#[$doc:meta])* $ty:ty, |$value:ident| $sanitize:expr) => {
+        $(#[$doc])*
+        impl<T: RodValidate + ?Sized> RodValidate for $ty {
+            fn validate(&self) -> Result<(), RodValidateError> {
+                (**self).validate()
+            }
+            fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+                (**self).validate_all()
+            }
+            fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+                (**self).validate_all_limited(max_errors)
+            }
+            fn validate_report(&self) -> RodValidateReport {
+                (**self).validate_report()
+            }
+            fn validate_field(&self, path: &str) -> Result<(), RodValidateErrorList> {
+                (**self).validate_field(path)
+            }
+            fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+                (**self).validate_tagged(tags)
+            }
+            fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+                (**self).validate_all_tagged(tags)
+            }
+            fn sanitize(&mut self) {
+                let $value = self;
+                $sanitize
+            }
+            fn suggest_fixes(&self) -> Vec<RodFix> {
+                (**self).suggest_fixes()
+            }
+        }
+    };
+}
+
+impl_rod_validate_pointer!(Box<T>, |value| (**value).sanitize());
+impl_rod_validate_pointer!(&mut T, |value| (**value).sanitize());
+impl_rod_validate_pointer!(
+    /// The value cannot be changed through a shared reference, so it is not sanitized.
+    &T, |_value| ()
+);
+impl_rod_validate_pointer!(
+    /// The value is sanitized only if it is not shared.
+    Rc<T>, |value| if let Some(value) = Rc::get_mut(value) { value.sanitize() }
+);
+impl_rod_validate_pointer!(
+    /// The value is sanitized only if it is not shared.
+    Arc<T>, |value| if let Some(value) = Arc::get_mut(value) { value.sanitize() }
+);
@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+
+use super::{prefix_path, Params};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BooleanValidation {
+    // The value the boolean was expected to have
+    Is(String, bool),
+}
+
+impl BooleanValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            BooleanValidation::Is(path, ..) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `boolean.is`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            BooleanValidation::Is(..) => "boolean.is",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            BooleanValidation::Is(_, expected) => Params::new().with("expected", expected),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            BooleanValidation::Is(path, ..) => prefix_path(prefix, path),
+        }
+    }
+}
+
+impl Display for BooleanValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BooleanValidation::Is(path, expected) => write!(f, "Expected `{}` to be {}, got {}", path, expected, !expected),
+        }
+    }
+}
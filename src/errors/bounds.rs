@@ -0,0 +1,74 @@
+use std::{fmt::{Display, Formatter}, ops::{Bound, RangeBounds}};
+
+/// `ExpectedBounds` is the exact value or the range a failing value was expected to be in, e.g. the `length` of a string.
+/// It mirrors the `length`/`size` attribute, with the bounds converted to the type the error reports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExpectedBounds<T> {
+    Exact(T),
+    Range {
+        start: Option<T>,
+        end: Option<T>,
+        inclusive: bool,
+    },
+}
+
+impl<T: Clone> ExpectedBounds<T> {
+    pub fn from_range(range: &impl RangeBounds<T>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(start) | Bound::Excluded(start) => Some(start.clone()),
+            Bound::Unbounded => None,
+        };
+        let (end, inclusive) = match range.end_bound() {
+            Bound::Included(end) => (Some(end.clone()), true),
+            Bound::Excluded(end) => (Some(end.clone()), false),
+            Bound::Unbounded => (None, false),
+        };
+        ExpectedBounds::Range { start, end, inclusive }
+    }
+}
+
+impl<T> ExpectedBounds<T> {
+    /// The smallest expected value, if there is one.
+    pub fn min(&self) -> Option<&T> {
+        match self {
+            ExpectedBounds::Exact(exact) => Some(exact),
+            ExpectedBounds::Range { start, .. } => start.as_ref(),
+        }
+    }
+    /// The largest expected value, if there is one. It is excluded from the range unless the range is inclusive.
+    pub fn max(&self) -> Option<&T> {
+        match self {
+            ExpectedBounds::Exact(exact) => Some(exact),
+            ExpectedBounds::Range { end, .. } => end.as_ref(),
+        }
+    }
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> ExpectedBounds<U> {
+        match self {
+            ExpectedBounds::Exact(exact) => ExpectedBounds::Exact(f(exact)),
+            ExpectedBounds::Range { start, end, inclusive } => ExpectedBounds::Range {
+                start: start.map(&mut f),
+                end: end.map(&mut f),
+                inclusive,
+            },
+        }
+    }
+}
+
+impl<T: Display> Display for ExpectedBounds<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpectedBounds::Exact(exact) => write!(f, "exactly {}", exact),
+            ExpectedBounds::Range { start, end, inclusive } => {
+                write!(f, "in the range ")?;
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                write!(f, "{}", if *inclusive { "..=" } else { ".." })?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter};
+
+use super::{prefix_path, Params, REDACTED};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CustomValidation {
+    // The actual and the expected value, formatted with `Debug`
+    Equals(String, String, String),
+}
+
+impl CustomValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            CustomValidation::Equals(path, ..) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `custom.equals`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CustomValidation::Equals(..) => "custom.equals",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            CustomValidation::Equals(_, value, expected) => Params::new().with("value", value).with("expected", expected),
+        }
+    }
+    pub(crate) fn redact(&mut self) {
+        match self {
+            CustomValidation::Equals(_, value, _) => *value = REDACTED.to_string(),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            CustomValidation::Equals(path, ..) => prefix_path(prefix, path),
+        }
+    }
+}
+
+impl Display for CustomValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomValidation::Equals(path, value, expected) => write!(f, "Expected `{}` to equal {}, got {}", path, expected, value),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+
+use super::{prefix_path, Params};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EnumTagValidation {
+    OneOf(String, &'static str),
+}
+
+impl EnumTagValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            EnumTagValidation::OneOf(path, ..) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `enum_tag.one_of`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            EnumTagValidation::OneOf(..) => "enum_tag.one_of",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            EnumTagValidation::OneOf(_, allowed) => Params::new().with("allowed", allowed),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            EnumTagValidation::OneOf(path, ..) => prefix_path(prefix, path),
+        }
+    }
+}
+
+impl Display for EnumTagValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnumTagValidation::OneOf(path, allowed) => write!(f, "Expected `{}` to be one of {}", path, allowed),
+        }
+    }
+}
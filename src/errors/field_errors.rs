@@ -0,0 +1,59 @@
+use std::collections::{btree_map, BTreeMap};
+
+/// The messages of an error list grouped by field, in the `{ "field": ["message", ...] }` shape of REST API error bodies.
+/// Fields are sorted by path, and errors without a path, like user-defined messages, are under `""`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RodFieldErrors {
+    fields: BTreeMap<String, Vec<String>>,
+}
+
+impl RodFieldErrors {
+    /// The HTTP status code that fits a body of validation errors, `422 Unprocessable Entity`.
+    pub const STATUS: u16 = 422;
+
+    pub fn new() -> Self {
+        RodFieldErrors::default()
+    }
+    pub fn push(&mut self, field: String, message: String) {
+        self.fields.entry(field).or_default().push(message);
+    }
+    /// Returns the messages of `field`, if it has any.
+    pub fn get(&self, field: &str) -> Option<&[String]> {
+        self.fields.get(field).map(Vec::as_slice)
+    }
+    /// Returns the number of fields with errors.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+    pub fn iter(&self) -> btree_map::Iter<'_, String, Vec<String>> {
+        self.fields.iter()
+    }
+    /// Returns [`RodFieldErrors::STATUS`], as a hint for the status code of the response.
+    pub fn status(&self) -> u16 {
+        Self::STATUS
+    }
+    pub fn into_inner(self) -> BTreeMap<String, Vec<String>> {
+        self.fields
+    }
+}
+
+impl IntoIterator for RodFieldErrors {
+    type Item = (String, Vec<String>);
+    type IntoIter = btree_map::IntoIter<String, Vec<String>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a RodFieldErrors {
+    type Item = (&'a String, &'a Vec<String>);
+    type IntoIter = btree_map::Iter<'a, String, Vec<String>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.iter()
+    }
+}
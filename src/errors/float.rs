@@ -1,16 +1,139 @@
-use std::fmt::{Display, Formatter};
+use std::{fmt::{Display, Formatter}, hash::{Hash, Hasher}};
+
+use super::{prefix_path, ExpectedBounds, Params};
 
 #[derive(Debug, Clone)]
 pub enum FloatValidation {
-    Size(&'static str, f64, String),
-    Sign(&'static str, f64, &'static str),
+    Size(String, f64, ExpectedBounds<f64>),
+    // The actual value, then the expected value and the tolerance it can be off by
+    Approx(String, f64, f64, f64),
+    Sign(String, f64, &'static str),
+    Type(String, f64, &'static str),
+    NonZero(String),
+    DecimalPlaces(String, f64, u32),
+    Step(String, f64, f64),
+}
+
+impl FloatValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            FloatValidation::Size(path, ..)
+            | FloatValidation::Approx(path, ..)
+            | FloatValidation::Sign(path, ..)
+            | FloatValidation::Type(path, ..)
+            | FloatValidation::NonZero(path)
+            | FloatValidation::DecimalPlaces(path, ..)
+            | FloatValidation::Step(path, ..) => path,
+        }
+    }
+    /// The float that failed validation. `NonZero` errors have none, as the value can only be zero.
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            FloatValidation::Size(_, value, _)
+            | FloatValidation::Approx(_, value, ..)
+            | FloatValidation::Sign(_, value, _)
+            | FloatValidation::Type(_, value, _)
+            | FloatValidation::DecimalPlaces(_, value, _)
+            | FloatValidation::Step(_, value, _) => Some(*value),
+            FloatValidation::NonZero(_) => None,
+        }
+    }
+    /// The expected size. For `Approx` errors, this is the range the tolerance allows around the expected value.
+    pub fn expected_size(&self) -> Option<ExpectedBounds<f64>> {
+        match self {
+            FloatValidation::Size(_, _, size) => Some(size.clone()),
+            FloatValidation::Approx(_, _, expected, tolerance) => Some(ExpectedBounds::from_range(&((expected - tolerance)..=(expected + tolerance)))),
+            _ => None,
+        }
+    }
+    /// A stable identifier of the kind of error, like `float.size`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            FloatValidation::Size(..) => "float.size",
+            FloatValidation::Approx(..) => "float.approx",
+            FloatValidation::Sign(..) => "float.sign",
+            FloatValidation::Type(..) => "float.type",
+            FloatValidation::NonZero(..) => "float.non_zero",
+            FloatValidation::DecimalPlaces(..) => "float.decimal_places",
+            FloatValidation::Step(..) => "float.step",
+        }
+    }
+    pub fn params(&self) -> Params {
+        let params = match self.value() {
+            Some(value) => Params::new().with("value", value),
+            None => Params::new(),
+        };
+        match self {
+            FloatValidation::Size(_, _, size) => params.with_bounds(size),
+            FloatValidation::Approx(_, _, expected, tolerance) => params.with("expected", expected).with("tolerance", tolerance),
+            FloatValidation::Sign(_, _, sign) => params.with("sign", sign),
+            FloatValidation::Type(_, _, ty) => params.with("type", ty),
+            FloatValidation::NonZero(_) => params,
+            FloatValidation::DecimalPlaces(_, _, places) => params.with("places", places),
+            FloatValidation::Step(_, _, step) => params.with("step", step),
+        }
+    }
+    fn bits(&self) -> FloatValidationBits<'_> {
+        match self {
+            FloatValidation::Size(path, float, size) => FloatValidationBits::Size(path, float.to_bits(), size.clone().map(f64::to_bits)),
+            FloatValidation::Approx(path, float, expected, tolerance) => FloatValidationBits::Approx(path, float.to_bits(), expected.to_bits(), tolerance.to_bits()),
+            FloatValidation::Sign(path, float, sign) => FloatValidationBits::Sign(path, float.to_bits(), sign),
+            FloatValidation::Type(path, float, ty) => FloatValidationBits::Type(path, float.to_bits(), ty),
+            FloatValidation::NonZero(path) => FloatValidationBits::NonZero(path),
+            FloatValidation::DecimalPlaces(path, float, places) => FloatValidationBits::DecimalPlaces(path, float.to_bits(), *places),
+            FloatValidation::Step(path, float, step) => FloatValidationBits::Step(path, float.to_bits(), step.to_bits()),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            FloatValidation::Size(path, ..)
+            | FloatValidation::Approx(path, ..)
+            | FloatValidation::Sign(path, ..)
+            | FloatValidation::Type(path, ..)
+            | FloatValidation::NonZero(path, ..)
+            | FloatValidation::DecimalPlaces(path, ..)
+            | FloatValidation::Step(path, ..) => prefix_path(prefix, path),
+        }
+    }
+}
+
+/// `FloatValidation` with its floats as their bits, which errors are compared and hashed by.
+/// This way an error for a NaN value is equal to itself.
+#[derive(PartialEq, Eq, Hash)]
+enum FloatValidationBits<'a> {
+    Size(&'a str, u64, ExpectedBounds<u64>),
+    Approx(&'a str, u64, u64, u64),
+    Sign(&'a str, u64, &'static str),
+    Type(&'a str, u64, &'static str),
+    NonZero(&'a str),
+    DecimalPlaces(&'a str, u64, u32),
+    Step(&'a str, u64, u64),
+}
+
+impl PartialEq for FloatValidation {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for FloatValidation {}
+
+impl Hash for FloatValidation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
 }
 
 impl Display for FloatValidation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            FloatValidation::Size(path, float, size) => write!(f, "Expected `{}` to be a float {}, got {}", path, size, float),
+            FloatValidation::Size(path, float, size) => write!(f, "Expected `{}` to be a float to be {}, got {}", path, size, float),
+            FloatValidation::Approx(path, float, expected, tolerance) => write!(f, "Expected `{}` to be a float to be within {} of {}, got {}", path, tolerance, expected, float),
             FloatValidation::Sign(path, float, sign) => write!(f, "Expected `{}` to be a float with sign {}, got {}", path, float, sign),
+            FloatValidation::Type(path, float, r#type) => write!(f, "Expected `{}` to be a float of type {}, got {}", path, r#type, float),
+            FloatValidation::NonZero(path) => write!(f, "Expected `{}` to be a float other than zero", path),
+            FloatValidation::DecimalPlaces(path, float, places) => write!(f, "Expected `{}` to be a float with at most {} decimal places, got {}", path, places, float),
+            FloatValidation::Step(path, float, step) => write!(f, "Expected `{}` to be a float with step {}, got {}", path, step, float),
         }
     }
 }
\ No newline at end of file
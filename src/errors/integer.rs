@@ -1,4 +1,6 @@
-use std::fmt::{Display, Formatter};
+use std::fmt::{Binary, Display, Formatter};
+
+use super::{prefix_path, ExpectedBounds, Params};
 
 macro_rules! impl_from_integer {
     ($name:ident, $integer:ty) => {
@@ -10,7 +12,7 @@ macro_rules! impl_from_integer {
     };
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Integer {
     Negative(i128),
     Positive(u128),
@@ -37,6 +39,17 @@ impl From<usize> for Integer {
     }
 }
 
+impl Integer {
+    /// The number of decimal digits of the integer, not counting the sign. Zero has one digit.
+    pub fn digits(&self) -> u32 {
+        let magnitude = match self {
+            Integer::Negative(i) => i.unsigned_abs(),
+            Integer::Positive(i) => *i,
+        };
+        magnitude.checked_ilog10().map_or(1, |log| log + 1)
+    }
+}
+
 impl Display for Integer {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -46,19 +59,153 @@ impl Display for Integer {
     }
 }
 
-#[derive(Debug, Clone)]
+impl Binary for Integer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Integer::Negative(i) => Binary::fmt(i, f),
+            Integer::Positive(i) => Binary::fmt(i, f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum IntegerValidation {
-    Size(&'static str, Integer, String),
-    Sign(&'static str, Integer, &'static str),
-    Step(&'static str, Integer, Integer),
+    // The bounds are boxed to keep `RodValidateError` small
+    Size(String, Integer, Box<ExpectedBounds<Integer>>),
+    Sign(String, Integer, &'static str),
+    Step(String, Integer, Integer),
+    Parity(String, Integer, &'static str),
+    // The step and offset are boxed to keep `RodValidateError` small
+    StepOffset(String, Integer, Box<(Integer, Integer)>),
+    OneOf(String, Integer, &'static str),
+    NotOneOf(String, Integer, &'static str),
+    // The last integer is the mask of the bits that are not set
+    BitsSet(String, Integer, Integer),
+    // The last integer is the mask of the bits that are not clear
+    BitsClear(String, Integer, Integer),
+    NonZero(String),
+    // The exact or maximum number of digits
+    Digits(String, Integer, ExpectedBounds<u32>),
+}
+
+impl IntegerValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            IntegerValidation::Size(path, ..)
+            | IntegerValidation::Sign(path, ..)
+            | IntegerValidation::Step(path, ..)
+            | IntegerValidation::Parity(path, ..)
+            | IntegerValidation::StepOffset(path, ..)
+            | IntegerValidation::OneOf(path, ..)
+            | IntegerValidation::NotOneOf(path, ..)
+            | IntegerValidation::BitsSet(path, ..)
+            | IntegerValidation::BitsClear(path, ..)
+            | IntegerValidation::NonZero(path)
+            | IntegerValidation::Digits(path, ..) => path,
+        }
+    }
+    /// The integer that failed validation. `NonZero` errors have none, as the value can only be zero.
+    pub fn value(&self) -> Option<&Integer> {
+        match self {
+            IntegerValidation::Size(_, value, _)
+            | IntegerValidation::Sign(_, value, _)
+            | IntegerValidation::Step(_, value, _)
+            | IntegerValidation::Parity(_, value, _)
+            | IntegerValidation::StepOffset(_, value, _)
+            | IntegerValidation::OneOf(_, value, _)
+            | IntegerValidation::NotOneOf(_, value, _)
+            | IntegerValidation::BitsSet(_, value, _)
+            | IntegerValidation::BitsClear(_, value, _)
+            | IntegerValidation::Digits(_, value, _) => Some(value),
+            IntegerValidation::NonZero(_) => None,
+        }
+    }
+    pub fn expected_size(&self) -> Option<&ExpectedBounds<Integer>> {
+        match self {
+            IntegerValidation::Size(_, _, size) => Some(size),
+            _ => None,
+        }
+    }
+    pub fn expected_digits(&self) -> Option<&ExpectedBounds<u32>> {
+        match self {
+            IntegerValidation::Digits(_, _, digits) => Some(digits),
+            _ => None,
+        }
+    }
+    /// The step, and the offset for `StepOffset` errors.
+    pub fn expected_step(&self) -> Option<(&Integer, Option<&Integer>)> {
+        match self {
+            IntegerValidation::Step(_, _, step) => Some((step, None)),
+            IntegerValidation::StepOffset(_, _, step_offset) => Some((&step_offset.0, Some(&step_offset.1))),
+            _ => None,
+        }
+    }
+    /// A stable identifier of the kind of error, like `integer.size`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            IntegerValidation::Size(..) => "integer.size",
+            IntegerValidation::Sign(..) => "integer.sign",
+            IntegerValidation::Step(..) => "integer.step",
+            IntegerValidation::Parity(..) => "integer.parity",
+            IntegerValidation::StepOffset(..) => "integer.step_offset",
+            IntegerValidation::OneOf(..) => "integer.one_of",
+            IntegerValidation::NotOneOf(..) => "integer.not_one_of",
+            IntegerValidation::BitsSet(..) => "integer.bits_set",
+            IntegerValidation::BitsClear(..) => "integer.bits_clear",
+            IntegerValidation::NonZero(..) => "integer.non_zero",
+            IntegerValidation::Digits(..) => "integer.digits",
+        }
+    }
+    pub fn params(&self) -> Params {
+        let params = match self.value() {
+            Some(value) => Params::new().with("value", value),
+            None => Params::new(),
+        };
+        match self {
+            IntegerValidation::Size(_, _, size) => params.with_bounds(size.as_ref()),
+            IntegerValidation::Sign(_, _, sign) => params.with("sign", sign),
+            IntegerValidation::Step(_, _, step) => params.with("step", step),
+            IntegerValidation::Parity(_, _, parity) => params.with("parity", parity),
+            IntegerValidation::StepOffset(_, _, step_offset) => params.with("step", &step_offset.0).with("offset", &step_offset.1),
+            IntegerValidation::OneOf(_, _, values) | IntegerValidation::NotOneOf(_, _, values) => params.with("values", values),
+            IntegerValidation::BitsSet(_, _, missing) => params.with("missing", missing),
+            IntegerValidation::BitsClear(_, _, unexpected) => params.with("unexpected", unexpected),
+            IntegerValidation::NonZero(_) => params,
+            IntegerValidation::Digits(_, value, digits) => params.with("digits", value.digits()).with_bounds(digits),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            IntegerValidation::Size(path, ..)
+            | IntegerValidation::Sign(path, ..)
+            | IntegerValidation::Step(path, ..)
+            | IntegerValidation::Parity(path, ..)
+            | IntegerValidation::StepOffset(path, ..)
+            | IntegerValidation::OneOf(path, ..)
+            | IntegerValidation::NotOneOf(path, ..)
+            | IntegerValidation::BitsSet(path, ..)
+            | IntegerValidation::BitsClear(path, ..)
+            | IntegerValidation::NonZero(path, ..)
+            | IntegerValidation::Digits(path, ..) => prefix_path(prefix, path),
+        }
+    }
 }
 
 impl Display for IntegerValidation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            IntegerValidation::Size(path, int, size) => write!(f, "Expected `{}` to be an integer {}, got {}", path, size, int),
+            IntegerValidation::Size(path, int, size) => write!(f, "Expected `{}` to be an integer to be {}, got {}", path, size, int),
             IntegerValidation::Sign(path, int, sign) => write!(f, "Expected `{}` to be an integer with sign {}, got {}", path, sign, int),
             IntegerValidation::Step(path, int, step) => write!(f, "Expected `{}` to be an integer with step {}, got {}", path, step, int),
+            IntegerValidation::Parity(path, int, parity) => write!(f, "Expected `{}` to be an {} integer, got {}", path, parity, int),
+            IntegerValidation::StepOffset(path, int, step_offset) => write!(f, "Expected `{}` to be an integer with step {} and offset {}, got {}", path, step_offset.0, step_offset.1, int),
+            IntegerValidation::OneOf(path, int, values) => write!(f, "Expected `{}` to be one of {}, got {}", path, values, int),
+            IntegerValidation::NotOneOf(path, int, values) => write!(f, "Expected `{}` not to be one of {}, got {}", path, values, int),
+            IntegerValidation::BitsSet(path, int, missing) => write!(f, "Expected `{}` to have the bits {:#b} set, got {:#b}", path, missing, int),
+            IntegerValidation::BitsClear(path, int, unexpected) => write!(f, "Expected `{}` to have the bits {:#b} clear, got {:#b}", path, unexpected, int),
+            IntegerValidation::NonZero(path) => write!(f, "Expected `{}` to be an integer other than zero", path),
+            IntegerValidation::Digits(path, int, ExpectedBounds::Range { end: Some(max), .. }) => write!(f, "Expected `{}` to have at most {} digits, got {}", path, max, int),
+            IntegerValidation::Digits(path, int, digits) => write!(f, "Expected `{}` to have {} digits, got {}", path, digits, int),
         }
     }
 }
\ No newline at end of file
@@ -1,15 +1,214 @@
-use std::fmt::{Display, Formatter};
+use std::{fmt::{Display, Formatter}, hash::{Hash, Hasher}};
+
+use super::{prefix_path, ExpectedBounds, Params, RodValidateError, REDACTED};
 
 #[derive(Debug, Clone)]
 pub enum IterableValidation {
-    Length(&'static str, usize, String),
+    Length(String, usize, ExpectedBounds<usize>),
+    Empty(String),
+    // The actual sum, then the expected sum with its tolerance, if any
+    Sum(String, String, String),
+    // The actual mean, then the expected mean or range
+    Mean(String, f64, ExpectedBounds<f64>),
+    // The duplicated item, then the indices of its first and second occurrence
+    Duplicate(String, String, usize, usize),
+    // The duplicated key from `unique_by`, then the indices of the two items that share it
+    DuplicateKey(String, String, usize, usize),
+    // The expected order, then the index of the first item that is out of order and the item itself
+    Sorted(String, &'static str, usize, String),
+    Contains(String, String),
+    // The index is where the forbidden item was found
+    NotContains(String, String, usize),
+    // An item failed its own validation, with the index of the item and the error it failed with
+    Item(String, usize, Box<RodValidateError>),
+}
+
+impl IterableValidation {
+    /// The path of the failing item, like `tags[3]`, or of the iterable itself for other errors.
+    pub fn path(&self) -> String {
+        match self {
+            IterableValidation::Item(path, index, _) => format!("{}[{}]", path, index),
+            IterableValidation::Length(path, ..)
+            | IterableValidation::Empty(path)
+            | IterableValidation::Sum(path, ..)
+            | IterableValidation::Mean(path, ..)
+            | IterableValidation::Duplicate(path, ..)
+            | IterableValidation::DuplicateKey(path, ..)
+            | IterableValidation::Sorted(path, ..)
+            | IterableValidation::Contains(path, ..)
+            | IterableValidation::NotContains(path, ..) => path.to_string(),
+        }
+    }
+    pub fn expected_length(&self) -> Option<&ExpectedBounds<usize>> {
+        match self {
+            IterableValidation::Length(_, _, length) => Some(length),
+            _ => None,
+        }
+    }
+    pub fn actual_length(&self) -> Option<usize> {
+        match self {
+            IterableValidation::Length(_, length, _) => Some(*length),
+            IterableValidation::Empty(_) => Some(0),
+            _ => None,
+        }
+    }
+    /// The index of the item the error is about, for errors that point at one item.
+    /// Duplicates report the index of the second occurrence.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            IterableValidation::Duplicate(_, _, _, index)
+            | IterableValidation::DuplicateKey(_, _, _, index)
+            | IterableValidation::Sorted(_, _, index, _)
+            | IterableValidation::NotContains(_, _, index)
+            | IterableValidation::Item(_, index, _) => Some(*index),
+            _ => None,
+        }
+    }
+    /// A stable identifier of the kind of error, like `iterable.length`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            IterableValidation::Length(..) => "iterable.length",
+            IterableValidation::Empty(..) => "iterable.empty",
+            IterableValidation::Sum(..) => "iterable.sum",
+            IterableValidation::Mean(..) => "iterable.mean",
+            IterableValidation::Duplicate(..) => "iterable.duplicate",
+            IterableValidation::DuplicateKey(..) => "iterable.duplicate_key",
+            IterableValidation::Sorted(..) => "iterable.sorted",
+            IterableValidation::Contains(..) => "iterable.contains",
+            IterableValidation::NotContains(..) => "iterable.not_contains",
+            IterableValidation::Item(..) => "iterable.item",
+        }
+    }
+    /// The params of an `Item` error are the index of the item and the code of the error of the item.
+    pub fn params(&self) -> Params {
+        let params = Params::new();
+        match self {
+            IterableValidation::Length(_, length, expected) => params.with("length", length).with_bounds(expected),
+            IterableValidation::Empty(_) => params,
+            IterableValidation::Sum(_, sum, expected) => params.with("sum", sum).with("expected", expected),
+            IterableValidation::Mean(_, mean, expected) => params.with("mean", mean).with_bounds(expected),
+            IterableValidation::Duplicate(_, item, first, second) => params.with("item", item).with("first", first).with("second", second),
+            IterableValidation::DuplicateKey(_, key, first, second) => params.with("key", key).with("first", first).with("second", second),
+            IterableValidation::Sorted(_, order, index, item) => params.with("order", order).with("index", index).with("item", item),
+            IterableValidation::Contains(_, item) => params.with("item", item),
+            IterableValidation::NotContains(_, item, index) => params.with("item", item).with("index", index),
+            IterableValidation::Item(_, index, error) => params.with("index", index).with("error", error.code()),
+        }
+    }
+    /// The error of the item, for `Item` errors.
+    pub fn item_error(&self) -> Option<&RodValidateError> {
+        match self {
+            IterableValidation::Item(_, _, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl IterableValidation {
+    fn bits(&self) -> IterableValidationBits<'_> {
+        match self {
+            IterableValidation::Length(path, length, expected) => IterableValidationBits::Length(path, *length, expected),
+            IterableValidation::Empty(path) => IterableValidationBits::Empty(path),
+            IterableValidation::Sum(path, sum, expected) => IterableValidationBits::Sum(path, sum, expected),
+            IterableValidation::Mean(path, mean, expected) => IterableValidationBits::Mean(path, mean.to_bits(), expected.clone().map(f64::to_bits)),
+            IterableValidation::Duplicate(path, item, first, second) => IterableValidationBits::Duplicate(path, item, *first, *second),
+            IterableValidation::DuplicateKey(path, key, first, second) => IterableValidationBits::DuplicateKey(path, key, *first, *second),
+            IterableValidation::Sorted(path, order, index, item) => IterableValidationBits::Sorted(path, order, *index, item),
+            IterableValidation::Contains(path, item) => IterableValidationBits::Contains(path, item),
+            IterableValidation::NotContains(path, item, index) => IterableValidationBits::NotContains(path, item, *index),
+            IterableValidation::Item(path, index, error) => IterableValidationBits::Item(path, *index, error),
+        }
+    }
+    /// Redacts the items, and the errors of items. Expected values, like the item of `contains`, are kept.
+    pub(crate) fn redact(&mut self) {
+        match self {
+            IterableValidation::Item(_, _, error) => error.redact(),
+            IterableValidation::Duplicate(_, item, ..)
+            | IterableValidation::DuplicateKey(_, item, ..)
+            | IterableValidation::Sorted(_, _, _, item) => *item = REDACTED.to_string(),
+            _ => {}
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            IterableValidation::Item(path, _, error) => {
+                prefix_path(prefix, path);
+                error.prefix_paths(prefix);
+            }
+            IterableValidation::Length(path, ..)
+            | IterableValidation::Empty(path, ..)
+            | IterableValidation::Sum(path, ..)
+            | IterableValidation::Mean(path, ..)
+            | IterableValidation::Duplicate(path, ..)
+            | IterableValidation::DuplicateKey(path, ..)
+            | IterableValidation::Sorted(path, ..)
+            | IterableValidation::Contains(path, ..)
+            | IterableValidation::NotContains(path, ..) => prefix_path(prefix, path),
+        }
+    }
+}
+
+/// `IterableValidation` with the floats of `Mean` as their bits, which errors are compared and hashed by.
+#[derive(PartialEq, Eq, Hash)]
+enum IterableValidationBits<'a> {
+    Length(&'a str, usize, &'a ExpectedBounds<usize>),
+    Empty(&'a str),
+    Sum(&'a str, &'a str, &'a str),
+    Mean(&'a str, u64, ExpectedBounds<u64>),
+    Duplicate(&'a str, &'a str, usize, usize),
+    DuplicateKey(&'a str, &'a str, usize, usize),
+    Sorted(&'a str, &'static str, usize, &'a str),
+    Contains(&'a str, &'a str),
+    NotContains(&'a str, &'a str, usize),
+    Item(&'a str, usize, &'a RodValidateError),
+}
+
+impl PartialEq for IterableValidation {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for IterableValidation {}
+
+impl Hash for IterableValidation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
 }
 
 impl Display for IterableValidation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             IterableValidation::Length(path, actual_length, expected_length) => {
-                write!(f, "Expected iterable at {} to have length {}, got {}", path, expected_length, actual_length)
+                write!(f, "Expected iterable at {} to have length to be {}, got {}", path, expected_length, actual_length)
+            }
+            IterableValidation::Empty(path) => {
+                write!(f, "Expected iterable at {} to have at least one item", path)
+            }
+            IterableValidation::Sum(path, actual_sum, expected_sum) => {
+                write!(f, "Expected iterable at {} to sum to {}, got {}", path, expected_sum, actual_sum)
+            }
+            IterableValidation::Mean(path, actual_mean, expected_mean) => {
+                write!(f, "Expected the mean of iterable at {} to be {}, got {}", path, expected_mean, actual_mean)
+            }
+            IterableValidation::Duplicate(path, item, first, second) => {
+                write!(f, "Expected iterable at {} to have unique items, got {} at indices {} and {}", path, item, first, second)
+            }
+            IterableValidation::DuplicateKey(path, key, first, second) => {
+                write!(f, "Expected iterable at {} to have items with unique keys, got key {} at indices {} and {}", path, key, first, second)
+            }
+            IterableValidation::Sorted(path, order, index, item) => {
+                write!(f, "Expected iterable at {} to be in {} order, got {} at index {}", path, order, item, index)
+            }
+            IterableValidation::Contains(path, item) => {
+                write!(f, "Expected iterable at {} to contain {}", path, item)
+            }
+            IterableValidation::NotContains(path, item, index) => {
+                write!(f, "Expected iterable at {} not to contain {}, found at index {}", path, item, index)
+            }
+            IterableValidation::Item(path, index, error) => {
+                write!(f, "Invalid item at {}[{}]: {}", path, index, error)
             }
         }
     }
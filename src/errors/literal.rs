@@ -1,14 +1,59 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Clone)]
+use super::{prefix_path, Params, REDACTED};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LiteralValidation {
-    Value(&'static str, String, String),
+    Value(String, String, String),
+    OneOf(String, String, &'static str),
+    // The forbidden value, formatted with `Debug` so that an empty string is visible
+    Not(String, String),
+}
+
+impl LiteralValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            LiteralValidation::Value(path, ..)
+            | LiteralValidation::OneOf(path, ..)
+            | LiteralValidation::Not(path, ..) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `literal.value`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LiteralValidation::Value(..) => "literal.value",
+            LiteralValidation::OneOf(..) => "literal.one_of",
+            LiteralValidation::Not(..) => "literal.not",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            LiteralValidation::Value(_, value, expected) => Params::new().with("value", value).with("expected", expected),
+            LiteralValidation::OneOf(_, value, values) => Params::new().with("value", value).with("values", values),
+            LiteralValidation::Not(_, value) => Params::new().with("value", value),
+        }
+    }
+    pub(crate) fn redact(&mut self) {
+        match self {
+            LiteralValidation::Value(_, value, _) | LiteralValidation::OneOf(_, value, _) => *value = REDACTED.to_string(),
+            LiteralValidation::Not(..) => {}
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            LiteralValidation::Value(path, ..)
+            | LiteralValidation::OneOf(path, ..)
+            | LiteralValidation::Not(path, ..) => prefix_path(prefix, path),
+        }
+    }
 }
 
 impl Display for LiteralValidation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             LiteralValidation::Value(path, value, expected) => write!(f, "Expected `{}` to be {}, got {}", path, expected, value),
+            LiteralValidation::OneOf(path, value, values) => write!(f, "Expected `{}` to be one of {}, got {}", path, values, value),
+            LiteralValidation::Not(path, value) => write!(f, "Expected `{}` not to be {}", path, value),
         }
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::{ops::Index, error::Error, fmt::{Display, Formatter}};
+use std::{collections::{HashMap, HashSet}, ops::Index, error::Error, fmt::{Display, Formatter}};
 
 macro_rules! rod_validation_types {
     (
@@ -15,15 +15,84 @@ macro_rules! rod_validation_types {
         /// This is a sum type of all possible validation errors.
         /// It also includes a variant for custom validation checks that fail.
         /// This is used in the `validate` method of the `RodValidate` trait.
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
         pub enum RodValidateError {
             $(
                 $tuple_name($mod_name::$type_name),
             )*
-            CheckFailed(&'static str),
+            CheckFailed(String),
             UserDefined(String),
         }
 
+        impl RodValidateError {
+            /// The path of the value that failed, like `profile.addresses[2].zip`.
+            /// Errors of struct-level rules report the first field they name, and user-defined errors have no path.
+            pub fn path(&self) -> Option<String> {
+                match self {
+                    $(
+                        RodValidateError::$tuple_name(validation) => Some(validation.path().to_string()),
+                    )*
+                    RodValidateError::CheckFailed(path) => Some(path.clone()),
+                    RodValidateError::UserDefined(_) => None,
+                }
+            }
+            /// A stable identifier of the kind of error, like `string.length`, for clients that handle errors by kind.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(
+                        RodValidateError::$tuple_name(validation) => validation.code(),
+                    )*
+                    RodValidateError::CheckFailed(_) => "check_failed",
+                    RodValidateError::UserDefined(_) => "user_defined",
+                }
+            }
+            /// The values the error reports, like the expected and actual length of a string, by name.
+            pub fn params(&self) -> Params {
+                match self {
+                    $(
+                        RodValidateError::$tuple_name(validation) => validation.params(),
+                    )*
+                    RodValidateError::CheckFailed(_) | RodValidateError::UserDefined(_) => Params::new(),
+                }
+            }
+            /// Replaces the error by a user-defined error with the message of `key`, built from the params of the error
+            /// by the translator set with [`set_translator`](crate::set_translator).
+            pub fn translated(&self, key: &str) -> RodValidateError {
+                RodValidateError::UserDefined(crate::translate(key, &self.params()))
+            }
+            /// Prepends `prefix` to the paths of the error, so `zip` becomes `address.zip` and `[2]` becomes `tags[2]`.
+            /// Errors of nested values are prefixed with the path of the field that holds them.
+            pub fn with_path_prefix(mut self, prefix: &str) -> Self {
+                self.prefix_paths(prefix);
+                self
+            }
+            /// Replaces the values the error holds, like the string that failed, with `"<redacted>"`, so that secrets are not shown or logged.
+            /// Lengths and expected values are kept. Fields marked `sensitive` redact their errors.
+            pub fn redacted(mut self) -> Self {
+                self.redact();
+                self
+            }
+            pub(crate) fn redact(&mut self) {
+                match self {
+                    RodValidateError::String(validation) => validation.redact(),
+                    RodValidateError::Literal(validation) => validation.redact(),
+                    RodValidateError::Option(validation) => validation.redact(),
+                    RodValidateError::Iterable(validation) => validation.redact(),
+                    RodValidateError::Custom(validation) => validation.redact(),
+                    _ => {}
+                }
+            }
+            pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+                match self {
+                    $(
+                        RodValidateError::$tuple_name(validation) => validation.prefix_paths(prefix),
+                    )*
+                    RodValidateError::CheckFailed(path) => prefix_path(prefix, path),
+                    RodValidateError::UserDefined(_) => {}
+                }
+            }
+        }
+
         impl Error for RodValidateError {}
 
         impl Display for RodValidateError {
@@ -43,8 +112,11 @@ macro_rules! rod_validation_types {
 
         /// A list of validation errors.
         /// This is used in the `validate_all` method of the `RodValidate` trait
-        #[derive(Debug, Clone)]
-        pub struct RodValidateErrorList(Vec<RodValidateError>);
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct RodValidateErrorList {
+            errors: Vec<RodValidateError>,
+            truncated: bool,
+        }
 
         impl Default for RodValidateErrorList {
             fn default() -> Self {
@@ -54,19 +126,105 @@ macro_rules! rod_validation_types {
 
         impl RodValidateErrorList {
             pub fn new() -> Self {
-                RodValidateErrorList(Vec::new())
+                RodValidateErrorList { errors: Vec::new(), truncated: false }
             }
             pub fn push(&mut self, error: RodValidateError) {
-                self.0.push(error);
+                self.errors.push(error);
             }
             pub fn is_empty(&self) -> bool {
-                self.0.is_empty()
+                self.errors.is_empty()
             }
             pub fn len(&self) -> usize {
-                self.0.len()
+                self.errors.len()
             }
             pub fn iter(&self) -> std::slice::Iter<'_, RodValidateError> {
-                self.0.iter()
+                self.errors.iter()
+            }
+            /// Returns `true` if validation stopped early, e.g. with `validate_all_limited`, so there are more errors than the list holds.
+            pub fn is_truncated(&self) -> bool {
+                self.truncated
+            }
+            pub fn set_truncated(&mut self, truncated: bool) {
+                self.truncated = truncated;
+            }
+            /// Keeps the first `len` errors, marking the list as truncated if any were dropped.
+            pub fn truncate(&mut self, len: usize) {
+                if self.errors.len() > len {
+                    self.errors.truncate(len);
+                    self.truncated = true;
+                }
+            }
+            /// Moves the errors of `other` to the end of this list, e.g. to combine the errors of several sub-validations.
+            /// The list is truncated if either list was.
+            pub fn merge(&mut self, other: RodValidateErrorList) {
+                self.errors.extend(other.errors);
+                self.truncated |= other.truncated;
+            }
+            /// Removes the errors equal to an earlier error, keeping the first of each in order.
+            pub fn dedup(&mut self) {
+                let mut seen = HashSet::new();
+                self.errors.retain(|error| seen.insert(error.clone()));
+            }
+            /// Keeps the errors of the field at `prefix` and of everything nested in it,
+            /// so `"address"` keeps `address`, `address.zip` and `address[0]`, but not `addresses`.
+            /// Errors without a path are only kept by an empty prefix, which keeps every error.
+            pub fn retain_paths(&mut self, prefix: &str) {
+                if prefix.is_empty() {
+                    return;
+                }
+                self.errors.retain(|error| match error.path() {
+                    Some(path) => path_is_within(&path, prefix),
+                    None => false,
+                });
+            }
+            /// Renders the errors on a single line, like `Got 2 errors while validating: [a; b]`, which suits logs better than `Display`.
+            pub fn to_compact_string(&self) -> String {
+                if self.errors.is_empty() {
+                    return String::from("No validation errors");
+                }
+                let errors = self.errors.iter().map(|error| error.to_string()).collect::<Vec<_>>().join("; ");
+                format!("{}: [{}]", self.summary(), errors)
+            }
+            /// Renders the errors as a JSON array, the same as serializing the list.
+            #[cfg(all(feature = "serde", feature = "serde_json"))]
+            pub fn to_json(&self) -> String {
+                serde_json::to_string(self).expect("errors serialize to JSON")
+            }
+            fn summary(&self) -> String {
+                if self.truncated {
+                    format!("Got at least {} errors while validating", self.errors.len())
+                } else {
+                    format!("Got {} errors while validating", self.errors.len())
+                }
+            }
+            /// Groups the errors by their path, like `address.zip`, keeping their order within each path.
+            /// Errors without a path, i.e. user-defined messages, are grouped under the empty string.
+            pub fn to_map(&self) -> HashMap<String, Vec<RodValidateError>> {
+                let mut map: HashMap<String, Vec<RodValidateError>> = HashMap::new();
+                for error in self.errors.iter() {
+                    map.entry(error.path().unwrap_or_default()).or_default().push(error.clone());
+                }
+                map
+            }
+            /// Same as [`RodValidateErrorList::to_map`], with the messages of the errors instead of the errors.
+            pub fn to_message_map(&self) -> HashMap<String, Vec<String>> {
+                let mut map: HashMap<String, Vec<String>> = HashMap::new();
+                for error in self.errors.iter() {
+                    map.entry(error.path().unwrap_or_default()).or_default().push(error.to_string());
+                }
+                map
+            }
+            /// Turns the errors into their messages grouped by field, ready to be returned as the body of a `422` response.
+            pub fn into_field_errors(self) -> RodFieldErrors {
+                let mut fields = RodFieldErrors::new();
+                for error in self.errors {
+                    fields.push(error.path().unwrap_or_default(), error.to_string());
+                }
+                fields
+            }
+            /// The HTTP status code that fits these errors, `422 Unprocessable Entity`.
+            pub fn status(&self) -> u16 {
+                RodFieldErrors::STATUS
             }
         }
 
@@ -74,29 +232,61 @@ macro_rules! rod_validation_types {
             type Output = RodValidateError;
 
             fn index(&self, index: usize) -> &Self::Output {
-                &self.0[index]
+                &self.errors[index]
             }
         }
 
-        impl Iterator for RodValidateErrorList {
+        impl IntoIterator for RodValidateErrorList {
             type Item = RodValidateError;
+            type IntoIter = std::vec::IntoIter<RodValidateError>;
 
-            fn next(&mut self) -> Option<Self::Item> {
-                self.0.pop()
+            fn into_iter(self) -> Self::IntoIter {
+                self.errors.into_iter()
             }
         }
 
+        impl<'a> IntoIterator for &'a RodValidateErrorList {
+            type Item = &'a RodValidateError;
+            type IntoIter = std::slice::Iter<'a, RodValidateError>;
 
+            fn into_iter(self) -> Self::IntoIter {
+                self.errors.iter()
+            }
+        }
+
+        impl Extend<RodValidateError> for RodValidateErrorList {
+            fn extend<I: IntoIterator<Item = RodValidateError>>(&mut self, errors: I) {
+                self.errors.extend(errors);
+            }
+        }
+
+        impl FromIterator<RodValidateError> for RodValidateErrorList {
+            fn from_iter<I: IntoIterator<Item = RodValidateError>>(errors: I) -> Self {
+                RodValidateErrorList { errors: errors.into_iter().collect(), truncated: false }
+            }
+        }
+
+        impl From<Vec<RodValidateError>> for RodValidateErrorList {
+            fn from(errors: Vec<RodValidateError>) -> Self {
+                RodValidateErrorList { errors, truncated: false }
+            }
+        }
+
+        impl From<RodValidateErrorList> for Vec<RodValidateError> {
+            fn from(errors: RodValidateErrorList) -> Self {
+                errors.errors
+            }
+        }
 
         impl Error for RodValidateErrorList {}
 
         impl Display for RodValidateErrorList {
             fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-                if self.0.is_empty() {
+                if self.errors.is_empty() {
                     return write!(f, "No validation errors");
                 }
-                write!(f, "Got {} errors while validating: [\n", self.0.len())?;
-                for (i, error) in self.0.iter().enumerate() {
+                write!(f, "{}: [\n", self.summary())?;
+                for (i, error) in self.errors.iter().enumerate() {
                     if i > 0 {
                         write!(f, ",\n")?;
                     }
@@ -108,11 +298,51 @@ macro_rules! rod_validation_types {
     }
 }
 
+mod bounds;
+mod field_errors;
+mod params;
+mod report;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use bounds::ExpectedBounds;
+pub use field_errors::RodFieldErrors;
+pub use params::{ParamValue, Params};
+pub use report::RodValidateReport;
+pub use integer::Integer;
+
+/// The value shown in place of the values of `sensitive` fields in errors.
+pub const REDACTED: &str = "<redacted>";
+
+/// Joins `prefix` and `path` with a dot, unless `path` starts with an index like `[2]`.
+pub(crate) fn prefix_path(prefix: &str, path: &mut String) {
+    *path = if path.is_empty() {
+        prefix.to_string()
+    } else if path.starts_with('[') {
+        format!("{}{}", prefix, path)
+    } else {
+        format!("{}.{}", prefix, path)
+    };
+}
+
+/// Returns `true` if `path` is `prefix` or a path nested in it, like `prefix.field` or `prefix[2]`.
+fn path_is_within(path: &str, prefix: &str) -> bool {
+    match path.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with('.') || rest.starts_with('['),
+        None => false,
+    }
+}
+
 rod_validation_types! {
     string, String, StringValidation,
     integer, Integer, IntegerValidation,
     literal, Literal, LiteralValidation,
+    boolean, Boolean, BooleanValidation,
     option, Option, OptionValidation,
     float, Float, FloatValidation,
     iterable, Iterable, IterableValidation,
+    schema, Schema, SchemaValidation,
+    enum_tag, EnumTag, EnumTagValidation,
+    structure, Struct, StructValidation,
+    custom, Custom, CustomValidation,
 }
\ No newline at end of file
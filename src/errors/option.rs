@@ -1,11 +1,57 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Clone)]
+use super::{prefix_path, Params, REDACTED};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OptionValidation {
     // Is None when the value should be Some
-    None(&'static str, &'static str),
+    None(String, &'static str),
     // Is Some when the value should be None
-    Some(&'static str, String),
+    Some(String, String),
+    // Is None when the value is required, without constraints on the inner value
+    Required(String),
+    // Is None when the `required_if` condition of the value holds
+    RequiredIf(String),
+}
+
+impl OptionValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            OptionValidation::None(path, ..)
+            | OptionValidation::Some(path, ..)
+            | OptionValidation::Required(path, ..)
+            | OptionValidation::RequiredIf(path, ..) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `option.required`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            OptionValidation::None(..) => "option.none",
+            OptionValidation::Some(..) => "option.some",
+            OptionValidation::Required(..) => "option.required",
+            OptionValidation::RequiredIf(..) => "option.required_if",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            OptionValidation::None(_, expected) => Params::new().with("expected", expected),
+            OptionValidation::Some(_, value) => Params::new().with("value", value),
+            OptionValidation::Required(_) | OptionValidation::RequiredIf(_) => Params::new(),
+        }
+    }
+    pub(crate) fn redact(&mut self) {
+        if let OptionValidation::Some(_, value) = self {
+            *value = REDACTED.to_string();
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            OptionValidation::None(path, ..)
+            | OptionValidation::Some(path, ..)
+            | OptionValidation::Required(path, ..)
+            | OptionValidation::RequiredIf(path, ..) => prefix_path(prefix, path),
+        }
+    }
 }
 
 impl Display for OptionValidation {
@@ -13,6 +59,8 @@ impl Display for OptionValidation {
         match self {
             OptionValidation::None(path, n) => write!(f, "Expected `{}` to be {}, got None", path, n),
             OptionValidation::Some(path, s) => write!(f, "Expected `{}` to be None, got {}", path, s),
+            OptionValidation::Required(path) => write!(f, "Expected `{}` to be Some, got None", path),
+            OptionValidation::RequiredIf(path) => write!(f, "Expected `{}` to be Some when its condition holds, got None", path),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::fmt::{Display, Formatter};
+
+use super::{ExpectedBounds, Integer};
+
+/// `ParamValue` is a value an error reports, like the length a string was expected to have.
+#[derive(Debug, Clone)]
+pub enum ParamValue {
+    String(String),
+    Integer(Integer),
+    Float(f64),
+    Bool(bool),
+    List(Vec<String>),
+}
+
+macro_rules! impl_from_param {
+    ($variant:ident, $($ty:ty => $convert:expr),* $(,)?) => {
+        $(
+            impl From<$ty> for ParamValue {
+                fn from(value: $ty) -> Self {
+                    ParamValue::$variant(($convert)(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_param!(String, &str => String::from, String => String::from, &String => String::clone, &&'static str => |value: &&str| value.to_string());
+impl_from_param!(Integer, Integer => Integer::from, &Integer => Integer::clone, usize => Integer::from, &usize => |value: &usize| Integer::from(*value), u32 => Integer::from, &u32 => |value: &u32| Integer::from(*value));
+impl_from_param!(Float, f64 => f64::from, &f64 => |value: &f64| *value);
+impl_from_param!(Bool, bool => bool::from, &bool => |value: &bool| *value);
+impl_from_param!(List, &Vec<String> => Vec::clone);
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::String(value) => write!(f, "{}", value),
+            ParamValue::Integer(value) => write!(f, "{}", value),
+            ParamValue::Float(value) => write!(f, "{}", value),
+            ParamValue::Bool(value) => write!(f, "{}", value),
+            ParamValue::List(values) => write!(f, "{}", values.join(", ")),
+        }
+    }
+}
+
+/// `Params` are the named values of an error, in the order the error reports them.
+/// The path is not included, as every error has one.
+#[derive(Debug, Clone, Default)]
+pub struct Params(Vec<(&'static str, ParamValue)>);
+
+impl Params {
+    pub fn new() -> Self {
+        Params(Vec::new())
+    }
+    pub fn with(mut self, name: &'static str, value: impl Into<ParamValue>) -> Self {
+        self.0.push((name, value.into()));
+        self
+    }
+    /// Adds `exact`, or `min`, `max` and `inclusive` for a range. Unbounded ends are left out.
+    pub fn with_bounds<T>(self, bounds: &ExpectedBounds<T>) -> Self
+    where
+        for<'a> &'a T: Into<ParamValue>,
+    {
+        match bounds {
+            ExpectedBounds::Exact(exact) => self.with("exact", exact),
+            ExpectedBounds::Range { start, end, inclusive } => {
+                let params = match start {
+                    Some(start) => self.with("min", start),
+                    None => self,
+                };
+                let params = match end {
+                    Some(end) => params.with("max", end),
+                    None => params,
+                };
+                params.with("inclusive", *inclusive)
+            }
+        }
+    }
+    pub fn get(&self, name: &str) -> Option<&ParamValue> {
+        self.0.iter().find(|(param, _)| *param == name).map(|(_, value)| value)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ParamValue)> {
+        self.0.iter().map(|(name, value)| (*name, value))
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
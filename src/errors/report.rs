@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+use super::RodValidateErrorList;
+
+/// The result of `validate_report`: every error, and the warnings of the rules marked with `?warn "message"`.
+/// Warnings do not fail validation, so a value with only warnings is valid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RodValidateReport {
+    pub errors: RodValidateErrorList,
+    pub warnings: RodValidateErrorList,
+}
+
+impl RodValidateReport {
+    pub fn new() -> Self {
+        RodValidateReport::default()
+    }
+    /// Returns `true` if there are no errors. Warnings are not taken into account.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+    /// Drops the warnings, returning the errors like `validate_all` does.
+    pub fn into_result(self) -> Result<(), RodValidateErrorList> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+impl Display for RodValidateReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.errors)?;
+        if self.has_warnings() {
+            writeln!(f, "\nGot {} warnings while validating: [", self.warnings.len())?;
+            for (i, warning) in self.warnings.iter().enumerate() {
+                if i > 0 {
+                    writeln!(f, ",")?;
+                }
+                write!(f, "    {}", warning)?;
+            }
+            write!(f, "\n]")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+use super::{prefix_path, Params};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SchemaValidation {
+    // The value has a different shape than the schema expects
+    Type(String, &'static str, String),
+    // A required field is missing from the value
+    Missing(String),
+}
+
+impl SchemaValidation {
+    pub fn path(&self) -> &str {
+        match self {
+            SchemaValidation::Type(path, ..)
+            | SchemaValidation::Missing(path, ..) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `schema.missing`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SchemaValidation::Type(..) => "schema.type",
+            SchemaValidation::Missing(..) => "schema.missing",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            SchemaValidation::Type(_, expected, value) => Params::new().with("expected", expected).with("value", value),
+            SchemaValidation::Missing(_) => Params::new(),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            SchemaValidation::Type(path, ..)
+            | SchemaValidation::Missing(path, ..) => prefix_path(prefix, path),
+        }
+    }
+}
+
+impl Display for SchemaValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaValidation::Type(path, expected, value) => write!(f, "Expected `{}` to be {}, got {}", path, expected, value),
+            SchemaValidation::Missing(path) => write!(f, "Expected `{}` to be present", path),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{ser::{SerializeMap, SerializeSeq, SerializeStruct}, Serialize, Serializer};
+
+use super::{Integer, ParamValue, Params, RodFieldErrors, RodValidateError, RodValidateErrorList, RodValidateReport};
+
+/// Serializes as `{ "path": ..., "code": ..., "message": ..., "params": { ... } }`.
+/// User-defined errors have a `null` path.
+impl Serialize for RodValidateError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("path", &self.path())?;
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("message", &self.to_string())?;
+        map.serialize_entry("params", &self.params())?;
+        map.end()
+    }
+}
+
+/// Serializes as an array of the errors.
+impl Serialize for RodValidateErrorList {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for error in self.iter() {
+            seq.serialize_element(error)?;
+        }
+        seq.end()
+    }
+}
+
+/// Serializes as `{ "errors": [...], "warnings": [...] }`.
+impl Serialize for RodValidateReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut report = serializer.serialize_struct("RodValidateReport", 2)?;
+        report.serialize_field("errors", &self.errors)?;
+        report.serialize_field("warnings", &self.warnings)?;
+        report.end()
+    }
+}
+
+/// Serializes as `{ "field": ["message", ...] }`.
+impl Serialize for RodFieldErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (field, messages) in self.iter() {
+            map.serialize_entry(field, messages)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Params {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (name, value) in self.iter() {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for ParamValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ParamValue::String(value) => serializer.serialize_str(value),
+            ParamValue::Integer(value) => value.serialize(serializer),
+            ParamValue::Float(value) => serializer.serialize_f64(*value),
+            ParamValue::Bool(value) => serializer.serialize_bool(*value),
+            ParamValue::List(values) => values.serialize(serializer),
+        }
+    }
+}
+
+/// Serializes as a plain number.
+impl Serialize for Integer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Integer::Negative(value) => serializer.serialize_i128(*value),
+            Integer::Positive(value) => serializer.serialize_u128(*value),
+        }
+    }
+}
@@ -1,22 +1,135 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+use super::{prefix_path, ExpectedBounds, Params, REDACTED};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum StringValidation {
-    Length(&'static str, String, String),
-    Format(&'static str, String, &'static str),
-    StartsWith(&'static str, String, String),
-    EndsWith(&'static str, String, String),
-    Includes(&'static str, String, String),
+    // The string, then its length, which is kept when the string is redacted
+    Length(String, String, usize, ExpectedBounds<usize>),
+    Format(String, String, &'static str),
+    StartsWith(String, String, String),
+    EndsWith(String, String, String),
+    Includes(String, String, String),
+    ParsesTo(String, String, &'static str),
+    Printable(String, String, &'static str),
+    Whitespace(String, String, &'static str),
+    Numeric(String, String, &'static str),
+    CharsIn(String, String, &'static str),
+}
+
+impl StringValidation {
+    pub fn path(&self) -> &str {
+        self.parts().0
+    }
+    /// The string that failed validation.
+    pub fn value(&self) -> &str {
+        self.parts().1
+    }
+    pub fn expected_length(&self) -> Option<&ExpectedBounds<usize>> {
+        match self {
+            StringValidation::Length(_, _, _, length) => Some(length),
+            _ => None,
+        }
+    }
+    pub fn actual_length(&self) -> Option<usize> {
+        match self {
+            StringValidation::Length(_, _, length, _) => Some(*length),
+            _ => None,
+        }
+    }
+    /// The name of the format the string was expected to have, e.g. `Email`.
+    pub fn format(&self) -> Option<&'static str> {
+        match self {
+            StringValidation::Format(_, _, format) => Some(format),
+            _ => None,
+        }
+    }
+    /// A stable identifier of the kind of error, like `string.length`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            StringValidation::Length(..) => "string.length",
+            StringValidation::Format(..) => "string.format",
+            StringValidation::StartsWith(..) => "string.starts_with",
+            StringValidation::EndsWith(..) => "string.ends_with",
+            StringValidation::Includes(..) => "string.includes",
+            StringValidation::ParsesTo(..) => "string.parses_to",
+            StringValidation::Printable(..) => "string.printable",
+            StringValidation::Whitespace(..) => "string.whitespace",
+            StringValidation::Numeric(..) => "string.numeric",
+            StringValidation::CharsIn(..) => "string.chars_in",
+        }
+    }
+    pub fn params(&self) -> Params {
+        let params = Params::new().with("value", self.value());
+        match self {
+            StringValidation::Length(_, _, actual_length, length) => params.with("length", actual_length).with_bounds(length),
+            StringValidation::Format(_, _, format) => params.with("format", format),
+            StringValidation::StartsWith(_, _, prefix) => params.with("prefix", prefix),
+            StringValidation::EndsWith(_, _, suffix) => params.with("suffix", suffix),
+            StringValidation::Includes(_, _, substring) => params.with("substring", substring),
+            StringValidation::ParsesTo(_, _, ty) => params.with("type", ty),
+            StringValidation::Printable(_, _, allowed) | StringValidation::CharsIn(_, _, allowed) => params.with("allowed", allowed),
+            StringValidation::Whitespace(_, _, policy) => params.with("policy", policy),
+            StringValidation::Numeric(_, _, description) => params.with("description", description),
+        }
+    }
+    fn parts(&self) -> (&str, &str) {
+        match self {
+            StringValidation::Length(path, value, ..)
+            | StringValidation::Format(path, value, _)
+            | StringValidation::StartsWith(path, value, _)
+            | StringValidation::EndsWith(path, value, _)
+            | StringValidation::Includes(path, value, _)
+            | StringValidation::ParsesTo(path, value, _)
+            | StringValidation::Printable(path, value, _)
+            | StringValidation::Whitespace(path, value, _)
+            | StringValidation::Numeric(path, value, _)
+            | StringValidation::CharsIn(path, value, _) => (path, value),
+        }
+    }
+    pub(crate) fn redact(&mut self) {
+        match self {
+            StringValidation::Length(_, value, ..)
+            | StringValidation::Format(_, value, _)
+            | StringValidation::StartsWith(_, value, _)
+            | StringValidation::EndsWith(_, value, _)
+            | StringValidation::Includes(_, value, _)
+            | StringValidation::ParsesTo(_, value, _)
+            | StringValidation::Printable(_, value, _)
+            | StringValidation::Whitespace(_, value, _)
+            | StringValidation::Numeric(_, value, _)
+            | StringValidation::CharsIn(_, value, _) => *value = REDACTED.to_string(),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            StringValidation::Length(path, ..)
+            | StringValidation::Format(path, ..)
+            | StringValidation::StartsWith(path, ..)
+            | StringValidation::EndsWith(path, ..)
+            | StringValidation::Includes(path, ..)
+            | StringValidation::ParsesTo(path, ..)
+            | StringValidation::Printable(path, ..)
+            | StringValidation::Whitespace(path, ..)
+            | StringValidation::Numeric(path, ..)
+            | StringValidation::CharsIn(path, ..) => prefix_path(prefix, path),
+        }
+    }
 }
 
 impl Display for StringValidation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            StringValidation::Length(path, s, r) => write!(f, "Expected `{}` to have length {}, got {}", path, r, s.len()),
+            StringValidation::Length(path, _, actual_length, length) => write!(f, "Expected `{}` to have length to be {}, got {}", path, length, actual_length),
             StringValidation::Format(path, s, format) => write!(f, "Expected `{}` to have format {}, got {}", path, format, s),
             StringValidation::StartsWith(path, s, prefix) => write!(f, "Expected `{}` to start with {}, got {}", path, prefix, s),
             StringValidation::EndsWith(path, s, suffix) => write!(f, "Expected `{}` to end with {}, got {}", path, suffix, s),
             StringValidation::Includes(path, s, substring) => write!(f, "Expected `{}` to include {}, got {}", path, substring, s),
+            StringValidation::ParsesTo(path, s, ty) => write!(f, "Expected `{}` to parse as {}, got {}", path, ty, s),
+            StringValidation::Printable(path, s, allowed) => write!(f, "Expected `{}` to contain only {}, got {:?}", path, allowed, s),
+            StringValidation::Whitespace(path, s, policy) => write!(f, "Expected `{}` to have {}, got {:?}", path, policy, s),
+            StringValidation::Numeric(path, s, description) => write!(f, "Expected `{}` to contain {}, got {}", path, description, s),
+            StringValidation::CharsIn(path, s, allowed) => write!(f, "Expected `{}` to contain only {}, got {:?}", path, allowed, s),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+
+use super::{prefix_path, Params};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StructValidation {
+    // The overlapping values are formatted with `Debug`
+    Disjoint(String, String, Vec<String>),
+    // The subset, the superset, and the elements of the subset missing from the superset
+    SubsetOf(String, String, Vec<String>),
+    SameLength(String, usize, String, usize),
+    // The field, how it relates to the other field, e.g. "equal", and the other field
+    Compare(String, &'static str, String),
+    // The exclusive group, then the fields of the group that are present
+    Exclusive(Vec<String>, Vec<String>),
+    AnyOf(Vec<String>),
+    // A field that was not set when building the struct with its `RodBuilder`
+    Missing(String),
+}
+
+impl StructValidation {
+    /// The first field the rule names.
+    pub fn path(&self) -> &str {
+        match self {
+            StructValidation::Disjoint(first, ..)
+            | StructValidation::SubsetOf(first, ..)
+            | StructValidation::SameLength(first, ..)
+            | StructValidation::Compare(first, ..) => first,
+            StructValidation::Exclusive(group, _) | StructValidation::AnyOf(group) => &group[0],
+            StructValidation::Missing(path) => path,
+        }
+    }
+    /// A stable identifier of the kind of error, like `struct.same_length`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            StructValidation::Disjoint(..) => "struct.disjoint",
+            StructValidation::SubsetOf(..) => "struct.subset_of",
+            StructValidation::SameLength(..) => "struct.same_length",
+            StructValidation::Compare(..) => "struct.compare",
+            StructValidation::Exclusive(..) => "struct.exclusive",
+            StructValidation::AnyOf(..) => "struct.any_of",
+            StructValidation::Missing(..) => "struct.missing",
+        }
+    }
+    pub fn params(&self) -> Params {
+        match self {
+            StructValidation::Disjoint(_, other, overlap) => Params::new().with("other", other).with("overlap", overlap),
+            StructValidation::SubsetOf(_, superset, missing) => Params::new().with("superset", superset).with("missing", missing),
+            StructValidation::SameLength(_, length, other, other_length) => Params::new().with("length", length).with("other", other).with("other_length", other_length),
+            StructValidation::Compare(_, relation, other) => Params::new().with("relation", relation).with("other", other),
+            StructValidation::Exclusive(group, present) => Params::new().with("fields", group).with("present", present),
+            StructValidation::AnyOf(group) => Params::new().with("fields", group),
+            StructValidation::Missing(_) => Params::new(),
+        }
+    }
+    pub(crate) fn prefix_paths(&mut self, prefix: &str) {
+        match self {
+            StructValidation::Disjoint(first, second, _)
+            | StructValidation::SubsetOf(first, second, _)
+            | StructValidation::SameLength(first, _, second, _)
+            | StructValidation::Compare(first, _, second) => {
+                prefix_path(prefix, first);
+                prefix_path(prefix, second);
+            }
+            StructValidation::Exclusive(group, present) => group.iter_mut().chain(present).for_each(|path| prefix_path(prefix, path)),
+            StructValidation::AnyOf(group) => group.iter_mut().for_each(|path| prefix_path(prefix, path)),
+            StructValidation::Missing(path) => prefix_path(prefix, path),
+        }
+    }
+}
+
+impl Display for StructValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StructValidation::Disjoint(first, second, overlap) => write!(f, "Expected `{}` and `{}` to have no common elements, found [{}]", first, second, overlap.join(", ")),
+            StructValidation::SubsetOf(subset, superset, missing) => write!(f, "Expected every element of `{}` to be in `{}`, missing [{}]", subset, superset, missing.join(", ")),
+            StructValidation::SameLength(first, first_len, other, other_len) => write!(f, "Expected `{}` and `{}` to have the same length, got {} and {}", first, other, first_len, other_len),
+            StructValidation::Compare(path, relation, other) => write!(f, "Expected `{}` to {} `{}`", path, relation, other),
+            StructValidation::Exclusive(group, present) => write!(f, "Expected at most one of `{}` to be present, got `{}`", group.join("`, `"), present.join("`, `")),
+            StructValidation::AnyOf(group) => write!(f, "Expected at least one of `{}` to be present", group.join("`, `")),
+            StructValidation::Missing(path) => write!(f, "Expected `{}` to be set", path),
+        }
+    }
+}
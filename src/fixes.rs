@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::ops::{Bound, RangeBounds};
+
+use crate::errors::{prefix_path, Integer};
+
+/// A proposed corrected value for a field that fails validation.
+/// Returned by [`RodValidate::suggest_fixes`][crate::RodValidate::suggest_fixes].
+/// The path is built like the paths of errors, e.g. `address.zip` for a field of a nested type.
+#[derive(Debug, Clone)]
+pub struct RodFix {
+    pub path: Cow<'static, str>,
+    pub value: RodFixValue,
+}
+
+impl RodFix {
+    /// Prefixes the path with `prefix`, e.g. the name of the field that holds the value this fix is for.
+    pub fn with_path_prefix(mut self, prefix: &str) -> Self {
+        let mut path = self.path.into_owned();
+        prefix_path(prefix, &mut path);
+        self.path = Cow::Owned(path);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RodFixValue {
+    String(String),
+    Integer(Integer),
+    Float(f64),
+}
+
+impl Display for RodFix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Change `{}` to {}", self.path, self.value)
+    }
+}
+
+impl Display for RodFixValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RodFixValue::String(s) => write!(f, "{:?}", s),
+            RodFixValue::Integer(i) => write!(f, "{}", i),
+            RodFixValue::Float(float) => write!(f, "{}", float),
+        }
+    }
+}
+
+/// `RodClamp` computes the closest value inside a `size` range.
+/// Returns `None` if the value is already in the range, or if no value in the range can be reached by clamping.
+pub trait RodClamp: Sized {
+    fn rod_clamp<R: RangeBounds<Self>>(self, range: &R) -> Option<Self>;
+}
+
+macro_rules! impl_rod_clamp_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl RodClamp for $integer {
+                fn rod_clamp<R: RangeBounds<Self>>(self, range: &R) -> Option<Self> {
+                    if range.contains(&self) {
+                        return None;
+                    }
+                    let mut value = self;
+                    match range.start_bound() {
+                        Bound::Included(&min) => value = value.max(min),
+                        Bound::Excluded(&min) => value = value.max(min.checked_add(1)?),
+                        Bound::Unbounded => {}
+                    }
+                    match range.end_bound() {
+                        Bound::Included(&max) => value = value.min(max),
+                        Bound::Excluded(&max) => value = value.min(max.checked_sub(1)?),
+                        Bound::Unbounded => {}
+                    }
+                    range.contains(&value).then_some(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_rod_clamp_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_rod_clamp_float {
+    ($($float:ty),*) => {
+        $(
+            impl RodClamp for $float {
+                fn rod_clamp<R: RangeBounds<Self>>(self, range: &R) -> Option<Self> {
+                    if range.contains(&self) || self.is_nan() {
+                        return None;
+                    }
+                    // Excluded float bounds have no closest value, so only inclusive bounds are clamped to
+                    let mut value = self;
+                    if let Bound::Included(&min) = range.start_bound() {
+                        value = value.max(min);
+                    }
+                    if let Bound::Included(&max) = range.end_bound() {
+                        value = value.min(max);
+                    }
+                    range.contains(&value).then_some(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_rod_clamp_float!(f32, f64);
+
+/// `RodTruncate` shortens a string so that its length fits a `length` range.
+/// Returns `None` if the string already fits, or if it is too short to be fixed by truncation.
+pub trait RodTruncate {
+    fn rod_truncate<R: RangeBounds<usize>>(&self, range: &R) -> Option<String>;
+}
+
+impl RodTruncate for str {
+    fn rod_truncate<R: RangeBounds<usize>>(&self, range: &R) -> Option<String> {
+        if range.contains(&self.len()) {
+            return None;
+        }
+        let mut end = match range.end_bound() {
+            Bound::Included(&max) => max,
+            Bound::Excluded(&max) => max.checked_sub(1)?,
+            Bound::Unbounded => return None,
+        }
+        .min(self.len());
+        while !self.is_char_boundary(end) {
+            end -= 1;
+        }
+        let fixed = &self[..end];
+        range.contains(&fixed.len()).then(|| fixed.to_string())
+    }
+}
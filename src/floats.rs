@@ -0,0 +1,39 @@
+/// `RodFloat` implements the float checks that need a tolerance, so that generated code works the same for `f32` and `f64`.
+pub trait RodFloat: Sized {
+    /// Returns `true` if the value has at most `places` decimal digits.
+    /// The comparison is done within a few units of least precision of the scaled value,
+    /// so values like `0.1 + 0.2` still count as having 1 decimal place. Non-finite values never pass.
+    fn rod_has_decimal_places(self, places: u32) -> bool;
+    /// Returns `true` if the value is within `tolerance` of a multiple of `step`.
+    /// Without a tolerance, a few units of least precision of the larger of the value and the step are allowed.
+    fn rod_is_multiple_of(self, step: Self, tolerance: Option<Self>) -> bool;
+    /// Returns `true` if the value is within `tolerance` of `other`.
+    /// Without a tolerance, a few units of least precision of the larger of the two values are allowed.
+    fn rod_approx_eq(self, other: Self, tolerance: Option<Self>) -> bool;
+}
+
+macro_rules! impl_rod_float {
+    ($($float:ty),*) => {
+        $(
+            impl RodFloat for $float {
+                fn rod_has_decimal_places(self, places: u32) -> bool {
+                    let scaled = self * (10 as $float).powi(places as i32);
+                    let tolerance = scaled.abs().max(1.0) * <$float>::EPSILON * 4.0;
+                    (scaled - scaled.round()).abs() <= tolerance
+                }
+                fn rod_is_multiple_of(self, step: Self, tolerance: Option<Self>) -> bool {
+                    let quotient = self / step;
+                    let distance = (quotient - quotient.round()).abs() * step.abs();
+                    let tolerance = tolerance.unwrap_or_else(|| self.abs().max(step.abs()) * <$float>::EPSILON * 4.0);
+                    distance <= tolerance
+                }
+                fn rod_approx_eq(self, other: Self, tolerance: Option<Self>) -> bool {
+                    let tolerance = tolerance.unwrap_or_else(|| self.abs().max(other.abs()) * <$float>::EPSILON * 4.0);
+                    (self - other).abs() <= tolerance
+                }
+            }
+        )*
+    };
+}
+
+impl_rod_float!(f32, f64);
@@ -0,0 +1,54 @@
+/// A named string format that can be referenced with `format: <Name>` inside a `String` rule.
+/// Implementations are usually generated with the [`formats!`][crate::formats] macro.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a string format",
+    label = "not a built-in format or one registered with `formats!`",
+    note = "Valid string formats are: Email, Url, Uuid, Ipv4, Ipv6, DateTime, a custom regex string literal, or a format registered with `formats!`"
+)]
+pub trait RodFormat {
+    /// The name of the format, used in error messages.
+    const NAME: &'static str;
+    /// The regex pattern a string must match to have this format.
+    const PATTERN: &'static str;
+}
+
+/// Registers project-wide string formats once, so they can be referenced by name in `String` rules.
+///
+/// Each entry generates a unit struct implementing [`RodFormat`][crate::RodFormat].
+/// # Usage
+/// ```
+/// use rod_validation::prelude::*;
+///
+/// rod_validation::formats! {
+///     TicketId => r"^TCK-\d{6}$",
+///     pub Sku => r"^[A-Z]{3}-\d{4}$",
+/// }
+///
+/// #[derive(RodValidate)]
+/// struct Ticket {
+///     #[rod(String { format: TicketId })]
+///     id: String,
+/// }
+///
+/// assert!(Ticket { id: "TCK-000042".to_string() }.validate().is_ok());
+/// assert!(Ticket { id: "TCK-42".to_string() }.validate().is_err());
+/// ```
+#[macro_export]
+macro_rules! formats {
+    (
+        $(
+            $(#[$meta:meta])*
+            $vis:vis $name:ident => $pattern:expr
+        ),* $(,)?
+    ) => {
+        $(
+            $(#[$meta])*
+            $vis struct $name;
+
+            impl $crate::RodFormat for $name {
+                const NAME: &'static str = stringify!($name);
+                const PATTERN: &'static str = $pattern;
+            }
+        )*
+    };
+}
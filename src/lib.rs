@@ -1,11 +1,134 @@
 #[cfg(test)]
 mod tests;
+mod checked;
+mod containers;
 mod errors;
+mod fixes;
+mod floats;
+mod formats;
 pub mod prelude;
+mod primitives;
+pub mod schema;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod translate;
+mod valid;
+
+pub use checked::Checked;
+pub use fixes::{RodClamp, RodFix, RodFixValue, RodTruncate};
+pub use floats::RodFloat;
+pub use formats::RodFormat;
+pub use translate::{set_translator, translate, RodTranslator};
+pub use valid::Valid;
 
 pub trait RodValidate {
     /// Validate the struct, returning an error if validation fails.
     fn validate(&self) -> Result<(), errors::RodValidateError>;
     /// Validate the struct, returning a list of errors if validation fails.
     fn validate_all(&self) -> Result<(), errors::RodValidateErrorList>;
-}
\ No newline at end of file
+    /// Validate the struct like `validate_all`, but stop once `max_errors` errors are collected, to bound the time and memory spent on large values.
+    /// If there were more errors, the list is marked as truncated. A `max_errors` of 0 is treated as 1.
+    /// The default implementation validates everything, then truncates the list.
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), errors::RodValidateErrorList> {
+        self.validate_all().map_err(|mut errors| {
+            errors.truncate(max_errors.max(1));
+            errors
+        })
+    }
+    /// Validate the struct, returning every error along with the warnings of the rules marked with `?warn "message"`.
+    /// Warnings do not fail `validate` or `validate_all`, which ignore them.
+    /// The default implementation has no warnings.
+    fn validate_report(&self) -> errors::RodValidateReport {
+        errors::RodValidateReport {
+            errors: self.validate_all().err().unwrap_or_default(),
+            warnings: errors::RodValidateErrorList::new(),
+        }
+    }
+    /// Validate only the field at `path`, e.g. to validate a single input of a form when it changes, returning its errors if validation fails.
+    /// The path is the name of a field, or the name given with `rename`, and can point into nested values, like `address.zip` or `tags[2]`.
+    /// The default implementation validates everything and keeps the errors at `path`.
+    /// The derived implementation only validates that field, and does not run struct-level rules, since they span several fields.
+    fn validate_field(&self, path: &str) -> Result<(), errors::RodValidateErrorList> {
+        let mut errors = self.validate_all().err().unwrap_or_default();
+        errors.retain_paths(path);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+    /// Validate only the fields tagged with at least one of `tags`, returning an error if validation fails.
+    /// Nested types are validated with the same tags.
+    /// Types that do not declare any tags have no rules in any category, so the default implementation always succeeds.
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), errors::RodValidateError> {
+        let _ = tags;
+        Ok(())
+    }
+    /// Validate only the fields tagged with at least one of `tags`, returning a list of errors if validation fails.
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), errors::RodValidateErrorList> {
+        let _ = tags;
+        Ok(())
+    }
+    /// Normalize the fields in place with their transforms, like `trim` or `lowercase`, including the fields of nested types.
+    /// The default implementation does nothing.
+    fn sanitize(&mut self) {}
+    /// Sanitize the value, then validate it like `validate_all`, so the rules check the normalized value.
+    fn validate_sanitized(&mut self) -> Result<(), errors::RodValidateErrorList> {
+        self.sanitize();
+        self.validate_all()
+    }
+    /// Propose corrected values for fields failing simple constraints:
+    /// numbers outside a `size` range are clamped, and strings longer than their `length` are truncated.
+    /// Other failures have no automatic fix and are not included.
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        Vec::new()
+    }
+    /// Describe the rules of the type as runtime data, e.g. for tooling that inspects them or renders them as documentation.
+    /// The derived implementation describes every field of a struct. The default implementation has no fields.
+    fn rod_schema() -> schema::StructSchema
+    where
+        Self: Sized,
+    {
+        schema::StructSchema { name: std::any::type_name::<Self>(), fields: Vec::new() }
+    }
+}
+
+/// Validate `value`, returning the first error if validation fails. This is the same as calling [`RodValidate::validate`].
+pub fn validate<T: RodValidate + ?Sized>(value: &T) -> Result<(), errors::RodValidateError> {
+    value.validate()
+}
+
+/// Validate `value`, returning every error if validation fails. This is the same as calling [`RodValidate::validate_all`].
+pub fn validate_all<T: RodValidate + ?Sized>(value: &T) -> Result<(), errors::RodValidateErrorList> {
+    value.validate_all()
+}
+
+/// Validate `value`, returning at most `max_errors` errors if validation fails. This is the same as calling [`RodValidate::validate_all_limited`].
+pub fn validate_all_limited<T: RodValidate + ?Sized>(value: &T, max_errors: usize) -> Result<(), errors::RodValidateErrorList> {
+    value.validate_all_limited(max_errors)
+}
+
+/// Validate `value`, returning every error and warning. This is the same as calling [`RodValidate::validate_report`].
+pub fn validate_report<T: RodValidate + ?Sized>(value: &T) -> errors::RodValidateReport {
+    value.validate_report()
+}
+
+/// Validate each of `values` in order, stopping at the first value that fails.
+/// The error is returned with the index of that value, e.g. its position in a slice.
+// The index only adds a word to `RodValidateError`, which `validate` already returns unboxed
+#[allow(clippy::result_large_err)]
+pub fn validate_ref_iter<'a, T: RodValidate + ?Sized + 'a>(values: impl IntoIterator<Item = &'a T>) -> Result<(), (usize, errors::RodValidateError)> {
+    for (index, value) in values.into_iter().enumerate() {
+        value.validate().map_err(|error| (index, error))?;
+    }
+    Ok(())
+}
+
+/// Validate each of `values` with `validate_all` as the returned iterator is advanced, e.g. for the rows of a large CSV file,
+/// without collecting them first. Valid values are yielded as `Ok(value)`, and invalid values as `Err((value, errors))`, so they can still be reported.
+pub fn validate_iter<T: RodValidate>(values: impl IntoIterator<Item = T>) -> impl Iterator<Item = Result<T, (T, errors::RodValidateErrorList)>> {
+    values.into_iter().map(|value| match value.validate_all() {
+        Ok(()) => Ok(value),
+        Err(errors) => Err((value, errors)),
+    })
+}
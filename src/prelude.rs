@@ -4,6 +4,20 @@ pub use crate::errors::*;
 
 pub use crate::RodValidate;
 
+pub use crate::RodFormat;
+
+pub use crate::{RodClamp, RodFix, RodFixValue, RodTruncate};
+
+pub use crate::RodFloat;
+
+pub use crate::{Checked, Valid};
+
+pub use crate::schema;
+
+/// The schema types under a name that a `mod schema` of the deriving crate cannot shadow, for the code generated by `#[derive(RodValidate)]`.
+#[doc(hidden)]
+pub use crate::schema as __rod_schema;
+
 /// Doctests
 /// 
 /// Substruct does not implement `RodValidate`
@@ -157,4 +171,4 @@ pub use crate::RodValidate;
 ///     field: i32,
 /// }
 /// ```
-pub use rod_derive::RodValidate;
\ No newline at end of file
+pub use rod_derive::{RodBuilder, RodValidate};
\ No newline at end of file
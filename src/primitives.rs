@@ -0,0 +1,29 @@
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::errors::{RodValidateError, RodValidateErrorList};
+use crate::RodValidate;
+
+/// Primitives and strings have no rules of their own, so they are always valid.
+/// Fields of these types without a `#[rod(...)]` attribute are not constrained, and containers of them, like `Vec<String>`, implement `RodValidate`.
+macro_rules! impl_rod_validate_no_op {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl RodValidate for $ty {
+                fn validate(&self) -> Result<(), RodValidateError> {
+                    Ok(())
+                }
+                fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_rod_validate_no_op!(
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64, bool, char,
+    String, str, OsString, OsStr, PathBuf, Path,
+);
@@ -0,0 +1,646 @@
+use std::fmt::{Display, Formatter};
+
+use crate::errors::ExpectedBounds;
+
+/// `StructSchema` is a runtime description of the validation rules attached to a struct.
+/// It mirrors what is written in the `#[rod(...)]` attributes, so tooling can inspect the rules
+/// or apply them to data that has not been deserialized into the Rust type yet.
+/// # Usage
+/// ```
+/// use rod_validation::schema::*;
+///
+/// let schema = StructSchema {
+///     name: "User",
+///     fields: vec![
+///         FieldSchema::new("username", TypeSchema::String(vec![
+///             Rule::new(RuleKind::Length(Bounds::range(Some(3.0), Some(12.0), true))),
+///         ])),
+///         FieldSchema::new("age", TypeSchema::Integer(vec![
+///             Rule::new(RuleKind::Size(Bounds::range(Some(18.0), None, false))),
+///         ])),
+///     ],
+/// };
+/// assert_eq!(schema.field("age").map(|f| f.name), Some("age"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructSchema {
+    pub name: &'static str,
+    pub fields: Vec<FieldSchema>,
+}
+
+impl StructSchema {
+    /// Returns the schema of the field with the given name, if any.
+    pub fn field(&self, name: &str) -> Option<&FieldSchema> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// `FieldSchema` describes a single field of a [`StructSchema`].
+/// `message` is the field-wide `message: "..."` override, if one was given.
+#[derive(Debug, Clone)]
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub ty: TypeSchema,
+    pub message: Option<&'static str>,
+}
+
+impl FieldSchema {
+    pub fn new(name: &'static str, ty: TypeSchema) -> Self {
+        FieldSchema { name, ty, message: None }
+    }
+    pub fn with_message(mut self, message: &'static str) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+/// `TypeSchema` describes the shape of a value together with the rules applied to it.
+/// It has one variant per Rod type.
+#[derive(Debug, Clone)]
+pub enum TypeSchema {
+    String(Vec<Rule>),
+    Integer(Vec<Rule>),
+    Float(Vec<Rule>),
+    Boolean,
+    /// The source text of the expected literal, e.g. `"yes"` or `42`.
+    Literal(&'static str),
+    /// `None` means the value is expected to be `None`.
+    Option(Option<Box<TypeSchema>>),
+    /// An `Option` that may be `None`. Values that are present are validated with the inner type.
+    Optional(Box<TypeSchema>),
+    Iterable {
+        item: Box<TypeSchema>,
+        rules: Vec<Rule>,
+    },
+    Tuple(Vec<TypeSchema>),
+    /// A map, like `HashMap<String, Address>`, whose values are validated with the inner type. Keys are not validated.
+    Map(Box<TypeSchema>),
+    /// A type implementing `RodValidate`. The schema is produced lazily so recursive types are supported.
+    Nested(fn() -> StructSchema),
+    /// A type whose rules have no runtime counterpart, like an enum checked with `one_of`. Its rules can only be described.
+    Other {
+        name: &'static str,
+        rules: Vec<Rule>,
+    },
+    Skip,
+}
+
+impl TypeSchema {
+    /// The rules of the type itself, not of the types it holds. Types without rules of their own, like `Boolean`, have none.
+    pub fn rules(&self) -> &[Rule] {
+        match self {
+            TypeSchema::String(rules) | TypeSchema::Integer(rules) | TypeSchema::Float(rules) => rules,
+            TypeSchema::Iterable { rules, .. } | TypeSchema::Other { rules, .. } => rules,
+            _ => &[],
+        }
+    }
+    /// Adds `rules` to the rules of the type, e.g. the `check` closures of a field.
+    /// Types without rules of their own are left unchanged.
+    pub fn with_rules(mut self, extra: impl IntoIterator<Item = Rule>) -> Self {
+        match &mut self {
+            TypeSchema::String(rules) | TypeSchema::Integer(rules) | TypeSchema::Float(rules) => rules.extend(extra),
+            TypeSchema::Iterable { rules, .. } | TypeSchema::Other { rules, .. } => rules.extend(extra),
+            _ => {}
+        }
+        self
+    }
+    /// A short human-readable name of the type.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            TypeSchema::String(_) => "string",
+            TypeSchema::Integer(_) => "integer",
+            TypeSchema::Float(_) => "float",
+            TypeSchema::Boolean => "boolean",
+            TypeSchema::Literal(_) => "literal",
+            TypeSchema::Option(_) | TypeSchema::Optional(_) => "option",
+            TypeSchema::Iterable { .. } => "iterable",
+            TypeSchema::Tuple(_) => "tuple",
+            TypeSchema::Map(_) => "map",
+            TypeSchema::Nested(_) => "object",
+            TypeSchema::Other { name, .. } => name,
+            TypeSchema::Skip => "any",
+        }
+    }
+}
+
+/// A single rule, with the per-rule `?"message"` override if one was given.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub kind: RuleKind,
+    pub message: Option<&'static str>,
+}
+
+impl Rule {
+    pub fn new(kind: RuleKind) -> Self {
+        Rule { kind, message: None }
+    }
+    pub fn with_message(mut self, message: &'static str) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+/// `RuleKind` is the constraint a [`Rule`] checks.
+/// `IntegerType` is the Rust type of an integer field, e.g. `u8`, whose range the value must fit in.
+/// `Check` stands for a `check` closure, which can only be described, not evaluated, at runtime.
+/// `Other` is any other rule without a runtime counterpart, like `unique` or `parity: Even`, described in words.
+#[derive(Debug, Clone)]
+pub enum RuleKind {
+    Length(Bounds),
+    Size(Bounds),
+    Sign(&'static str),
+    Step(f64),
+    IntegerType(&'static str),
+    FloatType(&'static str),
+    Format {
+        name: &'static str,
+        pattern: &'static str,
+    },
+    StartsWith(&'static str),
+    EndsWith(&'static str),
+    Includes(&'static str),
+    Check,
+    Other(&'static str),
+}
+
+impl Display for RuleKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleKind::Length(bounds) => write!(f, "length {}", bounds),
+            RuleKind::Size(bounds) => write!(f, "size {}", bounds),
+            RuleKind::Sign(sign) => write!(f, "sign {}", sign),
+            RuleKind::Step(step) => write!(f, "step {}", step),
+            RuleKind::IntegerType(itype) => write!(f, "type {}", itype),
+            RuleKind::FloatType(ftype) => write!(f, "type {}", ftype),
+            RuleKind::Format { name, .. } => write!(f, "format {}", name),
+            RuleKind::StartsWith(prefix) => write!(f, "starts with {:?}", prefix),
+            RuleKind::EndsWith(suffix) => write!(f, "ends with {:?}", suffix),
+            RuleKind::Includes(substring) => write!(f, "includes {:?}", substring),
+            RuleKind::Check => write!(f, "custom check"),
+            RuleKind::Other(description) => write!(f, "{}", description),
+        }
+    }
+}
+
+/// `Bounds` is the runtime counterpart of an exact value or a range in a `length`/`size` attribute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bounds {
+    Exact(f64),
+    Range {
+        start: Option<f64>,
+        end: Option<f64>,
+        inclusive: bool,
+    },
+}
+
+impl Bounds {
+    pub fn range(start: Option<f64>, end: Option<f64>, inclusive: bool) -> Self {
+        Bounds::Range { start, end, inclusive }
+    }
+    pub fn contains(&self, value: f64) -> bool {
+        match *self {
+            Bounds::Exact(exact) => value == exact,
+            Bounds::Range { start, end, inclusive } => {
+                start.is_none_or(|start| value >= start)
+                    && end.is_none_or(|end| if inclusive { value <= end } else { value < end })
+            }
+        }
+    }
+    /// Describes the bounds the same way the derived validations do, e.g. `to be in the range 1..=5`.
+    pub fn describe(&self) -> String {
+        match self {
+            Bounds::Exact(exact) => format!("to be exactly {}", exact),
+            Bounds::Range { .. } => format!("to be in the range {}", self),
+        }
+    }
+    /// The bounds as they are reported by errors.
+    pub fn expected(&self) -> ExpectedBounds<f64> {
+        match *self {
+            Bounds::Exact(exact) => ExpectedBounds::Exact(exact),
+            Bounds::Range { start, end, inclusive } => ExpectedBounds::Range { start, end, inclusive },
+        }
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Bounds::Exact(exact) => write!(f, "{}", exact),
+            Bounds::Range { start, end, inclusive } => {
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                write!(f, "{}", if *inclusive { "..=" } else { ".." })?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl StructSchema {
+    /// Renders the rules of this schema as a Markdown table with one row per field.
+    /// # Usage
+    /// ```
+    /// use rod_validation::schema::*;
+    ///
+    /// let schema = StructSchema {
+    ///     name: "User",
+    ///     fields: vec![
+    ///         FieldSchema::new("username", TypeSchema::String(vec![
+    ///             Rule::new(RuleKind::Length(Bounds::range(Some(3.0), Some(12.0), true))),
+    ///         ])).with_message("Invalid username"),
+    ///     ],
+    /// };
+    /// let markdown = schema.to_markdown();
+    /// assert!(markdown.starts_with("### User\n"));
+    /// assert!(markdown.contains("| `username` | string | length 3..=12 | Invalid username |"));
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("### {}\n\n| Field | Type | Constraints | Message |\n| --- | --- | --- | --- |\n", self.name);
+        for field in &self.fields {
+            let mut messages = Vec::new();
+            messages.extend(field.message);
+            field.ty.collect_messages(&mut messages);
+            out.push_str(&format!(
+                "| `{}` | {} | {} | {} |\n",
+                field.name,
+                escape_markdown(&field.ty.describe_type()),
+                escape_markdown(&field.ty.describe_constraints()),
+                escape_markdown(&messages.join("; ")),
+            ));
+        }
+        out
+    }
+}
+
+/// Renders several schemas as consecutive Markdown sections, e.g. to embed every validated type of an API in a handbook.
+pub fn to_markdown(schemas: &[StructSchema]) -> String {
+    schemas.iter().map(StructSchema::to_markdown).collect::<Vec<_>>().join("\n")
+}
+
+fn escape_markdown(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', " ")
+}
+
+impl TypeSchema {
+    /// Describes the full type, including nested types, e.g. `option<string>`.
+    pub fn describe_type(&self) -> String {
+        match self {
+            TypeSchema::Option(Some(inner)) | TypeSchema::Optional(inner) => format!("option<{}>", inner.describe_type()),
+            TypeSchema::Iterable { item, .. } => format!("iterable<{}>", item.describe_type()),
+            TypeSchema::Map(value) => format!("map<{}>", value.describe_type()),
+            TypeSchema::Tuple(elements) => {
+                format!("({})", elements.iter().map(TypeSchema::describe_type).collect::<Vec<_>>().join(", "))
+            }
+            TypeSchema::Nested(schema) => schema().name.to_string(),
+            _ => self.type_name().to_string(),
+        }
+    }
+    /// Describes the rules of this type, including the rules of nested types.
+    pub fn describe_constraints(&self) -> String {
+        let rules = |rules: &[Rule]| rules.iter().map(|rule| rule.kind.to_string()).collect::<Vec<_>>();
+        let constraints = match self {
+            TypeSchema::String(r) | TypeSchema::Integer(r) | TypeSchema::Float(r) | TypeSchema::Other { rules: r, .. } => rules(r),
+            TypeSchema::Boolean => Vec::new(),
+            TypeSchema::Literal(literal) => vec![format!("equals {}", literal)],
+            TypeSchema::Option(None) => vec![String::from("must be None")],
+            TypeSchema::Option(Some(inner)) => {
+                let mut constraints = vec![String::from("must be Some")];
+                constraints.extend(non_empty(inner.describe_constraints()));
+                constraints
+            }
+            TypeSchema::Optional(inner) => {
+                let mut constraints = vec![String::from("may be None")];
+                constraints.extend(non_empty(inner.describe_constraints()));
+                constraints
+            }
+            TypeSchema::Iterable { item, rules: r } => {
+                let mut constraints = rules(r);
+                constraints.extend(non_empty(item.describe_constraints()).map(|item| format!("items: {}", item)));
+                constraints
+            }
+            TypeSchema::Tuple(elements) => elements
+                .iter()
+                .enumerate()
+                .filter_map(|(i, element)| non_empty(element.describe_constraints()).map(|c| format!("{}: {}", i, c)))
+                .collect(),
+            TypeSchema::Map(value) => non_empty(value.describe_constraints()).map(|value| format!("values: {}", value)).into_iter().collect(),
+            TypeSchema::Nested(schema) => vec![format!("validated as `{}`", schema().name)],
+            TypeSchema::Skip => vec![String::from("not validated")],
+        };
+        constraints.join(", ")
+    }
+    fn collect_messages(&self, messages: &mut Vec<&'static str>) {
+        match self {
+            TypeSchema::String(rules) | TypeSchema::Integer(rules) | TypeSchema::Float(rules) | TypeSchema::Other { rules, .. } => {
+                messages.extend(rules.iter().filter_map(|rule| rule.message));
+            }
+            TypeSchema::Option(Some(inner)) | TypeSchema::Optional(inner) | TypeSchema::Map(inner) => inner.collect_messages(messages),
+            TypeSchema::Iterable { item, rules } => {
+                messages.extend(rules.iter().filter_map(|rule| rule.message));
+                item.collect_messages(messages);
+            }
+            TypeSchema::Tuple(elements) => elements.iter().for_each(|element| element.collect_messages(messages)),
+            _ => {}
+        }
+    }
+}
+
+fn non_empty(s: String) -> Option<String> {
+    if s.is_empty() { None } else { Some(s) }
+}
+
+#[cfg(feature = "serde_json")]
+mod json {
+    use serde_json::Value;
+    #[cfg(feature = "regex")]
+    use regex::Regex;
+    #[cfg(all(feature = "regex-lite", not(feature = "regex")))]
+    use regex_lite::Regex;
+
+    use std::cmp::Ordering;
+
+    use super::*;
+    use crate::errors::*;
+
+    impl StructSchema {
+        /// Validates a JSON object against the rules of this schema, without deserializing it first.
+        /// All errors are collected, as with `validate_all`.
+        /// `check` closures and other rules without a runtime counterpart cannot be evaluated on JSON values and are ignored.
+        pub fn validate_json(&self, value: &Value) -> Result<(), RodValidateErrorList> {
+            let mut errors = RodValidateErrorList::new();
+            validate_object(self, "", value, &mut errors);
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+    }
+
+    /// The path of the object is empty at the top level, and the paths of its fields are joined to it, like `address.zip`.
+    fn validate_object(schema: &StructSchema, path: &str, value: &Value, errors: &mut RodValidateErrorList) {
+        let Some(object) = value.as_object() else {
+            let path = if path.is_empty() { schema.name } else { path };
+            errors.push(RodValidateError::Schema(SchemaValidation::Type(path.to_string(), "an object", value.to_string())));
+            return;
+        };
+        for field in &schema.fields {
+            let field_path = if path.is_empty() { field.name.to_string() } else { format!("{}.{}", path, field.name) };
+            match object.get(field.name) {
+                Some(value) => validate_value(&field.ty, &field_path, value, field.message, errors),
+                None if matches!(field.ty, TypeSchema::Option(_) | TypeSchema::Optional(_) | TypeSchema::Skip) => {
+                    validate_value(&field.ty, &field_path, &Value::Null, field.message, errors)
+                }
+                None => errors.push(RodValidateError::Schema(SchemaValidation::Missing(field_path))),
+            }
+        }
+    }
+
+    fn validate_value(ty: &TypeSchema, path: &str, value: &Value, message: Option<&'static str>, errors: &mut RodValidateErrorList) {
+        let mismatch = |expected: &'static str| RodValidateError::Schema(SchemaValidation::Type(path.to_string(), expected, value.to_string()));
+        match ty {
+            TypeSchema::String(rules) => match value.as_str() {
+                Some(s) => rules.iter().for_each(|rule| validate_string(rule, path, s, message, errors)),
+                None => errors.push(mismatch("a string")),
+            },
+            TypeSchema::Integer(rules) => {
+                // JSON integers fit in an `i128`, so they are compared exactly, not as `f64`
+                let exact = value.as_u64().map(i128::from).or_else(|| value.as_i64().map(i128::from));
+                let integer: Option<Integer> = value.as_u64().map(Into::into).or_else(|| value.as_i64().map(Into::into));
+                let Some((integer, exact)) = integer.zip(exact) else {
+                    errors.push(mismatch("an integer"));
+                    return;
+                };
+                for rule in rules {
+                    if let RuleKind::IntegerType(itype) = rule.kind
+                        && let Some((min, max, expected)) = integer_range(itype)
+                        && !(min..=max).contains(&exact)
+                    {
+                        // A value that does not fit in the type cannot be deserialized, so its other rules are not checked
+                        errors.push(mismatch(expected));
+                        return;
+                    }
+                }
+                rules.iter().for_each(|rule| validate_integer(rule, path, &integer, exact, message, errors));
+            }
+            TypeSchema::Float(rules) => match value.as_f64() {
+                Some(float) => rules.iter().for_each(|rule| validate_float(rule, path, float, message, errors)),
+                None => errors.push(mismatch("a float")),
+            },
+            TypeSchema::Boolean => {
+                if !value.is_boolean() {
+                    errors.push(mismatch("a boolean"));
+                }
+            }
+            TypeSchema::Literal(literal) => {
+                let expected: Option<Value> = serde_json::from_str(literal).ok();
+                if expected.as_ref() != Some(value) {
+                    errors.push(user_defined_or(message, || {
+                        RodValidateError::Literal(LiteralValidation::Value(path.to_string(), value.to_string(), literal.to_string()))
+                    }));
+                }
+            }
+            TypeSchema::Option(None) => {
+                if !value.is_null() {
+                    errors.push(user_defined_or(message, || RodValidateError::Option(OptionValidation::Some(path.to_string(), value.to_string()))));
+                }
+            }
+            TypeSchema::Option(Some(inner)) => {
+                if value.is_null() {
+                    errors.push(user_defined_or(message, || RodValidateError::Option(OptionValidation::None(path.to_string(), inner.type_name()))));
+                } else {
+                    validate_value(inner, path, value, message, errors);
+                }
+            }
+            TypeSchema::Optional(inner) => {
+                if !value.is_null() {
+                    validate_value(inner, path, value, message, errors);
+                }
+            }
+            TypeSchema::Iterable { item, rules } => match value.as_array() {
+                Some(items) => {
+                    for rule in rules {
+                        if let RuleKind::Length(bounds) = &rule.kind
+                            && !bounds.contains(items.len() as f64)
+                        {
+                            errors.push(rule_error(rule, message, || {
+                                RodValidateError::Iterable(IterableValidation::Length(path.to_string(), items.len(), bounds.expected().map(|bound| bound as usize)))
+                            }));
+                        }
+                    }
+                    for (index, value) in items.iter().enumerate() {
+                        let mut item_errors = RodValidateErrorList::new();
+                        validate_value(item, &format!("{}[{}]", path, index), value, message, &mut item_errors);
+                        for error in item_errors.iter() {
+                            errors.push(RodValidateError::Iterable(IterableValidation::Item(path.to_string(), index, Box::new(error.clone()))));
+                        }
+                    }
+                }
+                None => errors.push(mismatch("an array")),
+            },
+            TypeSchema::Tuple(elements) => match value.as_array() {
+                Some(items) if items.len() == elements.len() => {
+                    elements.iter().zip(items).enumerate().for_each(|(index, (ty, value))| {
+                        validate_value(ty, &format!("{}.{}", path, index), value, message, errors)
+                    });
+                }
+                _ => errors.push(mismatch("a tuple")),
+            },
+            // The values are joined to the path by their key, like `by_label[home].zip`
+            TypeSchema::Map(inner) => match value.as_object() {
+                Some(object) => object.iter().for_each(|(key, value)| {
+                    validate_value(inner, &format!("{}[{}]", path, key), value, message, errors)
+                }),
+                None => errors.push(mismatch("an object")),
+            },
+            TypeSchema::Nested(schema) => validate_object(&schema(), path, value, errors),
+            TypeSchema::Other { .. } | TypeSchema::Skip => {}
+        }
+    }
+
+    /// Per-rule messages take precedence over the field-wide message, as in the derived validations.
+    fn rule_error(rule: &Rule, message: Option<&'static str>, default: impl FnOnce() -> RodValidateError) -> RodValidateError {
+        user_defined_or(rule.message.or(message), default)
+    }
+
+    fn user_defined_or(message: Option<&'static str>, default: impl FnOnce() -> RodValidateError) -> RodValidateError {
+        match message {
+            Some(message) => RodValidateError::UserDefined(message.to_string()),
+            None => default(),
+        }
+    }
+
+    fn validate_string(rule: &Rule, path: &str, s: &str, message: Option<&'static str>, errors: &mut RodValidateErrorList) {
+        let error = match &rule.kind {
+            RuleKind::Length(bounds) if !bounds.contains(s.len() as f64) => {
+                Some(StringValidation::Length(path.to_string(), s.to_string(), s.len(), bounds.expected().map(|bound| bound as usize)))
+            }
+            #[cfg(any(feature = "regex", feature = "regex-lite"))]
+            RuleKind::Format { name, pattern } if !Regex::new(pattern).is_ok_and(|regex| regex.is_match(s)) => {
+                Some(StringValidation::Format(path.to_string(), s.to_string(), name))
+            }
+            RuleKind::StartsWith(prefix) if !s.starts_with(prefix) => {
+                Some(StringValidation::StartsWith(path.to_string(), s.to_string(), prefix.to_string()))
+            }
+            RuleKind::EndsWith(suffix) if !s.ends_with(suffix) => {
+                Some(StringValidation::EndsWith(path.to_string(), s.to_string(), suffix.to_string()))
+            }
+            RuleKind::Includes(substring) if !s.contains(substring) => {
+                Some(StringValidation::Includes(path.to_string(), s.to_string(), substring.to_string()))
+            }
+            _ => None,
+        };
+        if let Some(error) = error {
+            errors.push(rule_error(rule, message, || RodValidateError::String(error)));
+        }
+    }
+
+    fn integer_bound(bound: f64) -> Integer {
+        if bound < 0.0 {
+            Integer::Negative(bound as i128)
+        } else {
+            Integer::Positive(bound as u128)
+        }
+    }
+
+    /// Returns the range of the integer type named `itype`, and how a value of it is described in errors.
+    /// `u128` values above `i128::MAX` are not JSON integers, so its range ends there.
+    fn integer_range(itype: &str) -> Option<(i128, i128, &'static str)> {
+        let range = match itype {
+            "i8" => (i8::MIN.into(), i8::MAX.into(), "an i8"),
+            "i16" => (i16::MIN.into(), i16::MAX.into(), "an i16"),
+            "i32" => (i32::MIN.into(), i32::MAX.into(), "an i32"),
+            "i64" => (i64::MIN.into(), i64::MAX.into(), "an i64"),
+            "i128" => (i128::MIN, i128::MAX, "an i128"),
+            "isize" => (isize::MIN as i128, isize::MAX as i128, "an isize"),
+            "u8" => (0, u8::MAX.into(), "a u8"),
+            "u16" => (0, u16::MAX.into(), "a u16"),
+            "u32" => (0, u32::MAX.into(), "a u32"),
+            "u64" => (0, u64::MAX.into(), "a u64"),
+            "u128" => (0, i128::MAX, "a u128"),
+            "usize" => (0, usize::MAX as i128, "a usize"),
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    /// Compares an integer to a bound exactly, even where the integer cannot be represented as an `f64`.
+    fn compare_to_bound(value: i128, bound: f64) -> Ordering {
+        if bound.is_nan() {
+            return Ordering::Equal;
+        }
+        if bound >= i128::MAX as f64 {
+            return Ordering::Less;
+        }
+        if bound < i128::MIN as f64 {
+            return Ordering::Greater;
+        }
+        // Integral `f64` values in the range of `i128` convert exactly
+        let floor = bound.floor();
+        match value.cmp(&(floor as i128)) {
+            Ordering::Equal if floor < bound => Ordering::Less,
+            ordering => ordering,
+        }
+    }
+
+    fn bounds_contain(bounds: &Bounds, value: i128) -> bool {
+        match *bounds {
+            Bounds::Exact(exact) => compare_to_bound(value, exact) == Ordering::Equal,
+            Bounds::Range { start, end, inclusive } => {
+                start.is_none_or(|start| compare_to_bound(value, start) != Ordering::Less)
+                    && end.is_none_or(|end| match compare_to_bound(value, end) {
+                        Ordering::Less => true,
+                        Ordering::Equal => inclusive,
+                        Ordering::Greater => false,
+                    })
+            }
+        }
+    }
+
+    fn validate_integer(rule: &Rule, path: &str, integer: &Integer, value: i128, message: Option<&'static str>, errors: &mut RodValidateErrorList) {
+        let error = match &rule.kind {
+            RuleKind::Size(bounds) if !bounds_contain(bounds, value) => {
+                Some(IntegerValidation::Size(path.to_string(), integer.clone(), Box::new(bounds.expected().map(integer_bound))))
+            }
+            RuleKind::Sign(sign) if !sign_matches(sign, value.signum() as f64) => {
+                Some(IntegerValidation::Sign(path.to_string(), integer.clone(), sign))
+            }
+            RuleKind::Step(step) if *step as i128 != 0 && value % (*step as i128) != 0 => {
+                Some(IntegerValidation::Step(path.to_string(), integer.clone(), (*step as i128).into()))
+            }
+            _ => None,
+        };
+        if let Some(error) = error {
+            errors.push(rule_error(rule, message, || RodValidateError::Integer(error)));
+        }
+    }
+
+    fn validate_float(rule: &Rule, path: &str, value: f64, message: Option<&'static str>, errors: &mut RodValidateErrorList) {
+        let error = match &rule.kind {
+            RuleKind::Size(bounds) if !bounds.contains(value) => {
+                Some(FloatValidation::Size(path.to_string(), value, bounds.expected()))
+            }
+            RuleKind::Sign(sign) if !sign_matches(sign, value) => {
+                Some(FloatValidation::Sign(path.to_string(), value, sign))
+            }
+            _ => None,
+        };
+        if let Some(error) = error {
+            errors.push(rule_error(rule, message, || RodValidateError::Float(error)));
+        }
+    }
+
+    fn sign_matches(sign: &str, value: f64) -> bool {
+        match sign {
+            "Positive" => value > 0.0,
+            "Negative" => value < 0.0,
+            "Nonpositive" => value <= 0.0,
+            "Nonnegative" => value >= 0.0,
+            _ => true,
+        }
+    }
+}
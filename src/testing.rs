@@ -0,0 +1,40 @@
+//! Helpers for asserting what `#[derive(RodValidate)]` accepts and rejects at compile time.
+//!
+//! Requires the `testing` feature, which pulls in [`trybuild`](https://docs.rs/trybuild).
+
+#[doc(hidden)]
+pub use trybuild::TestCases;
+
+/// Compiles fixture files and checks the result of `#[derive(RodValidate)]` on them.
+///
+/// `pass` fixtures must compile and run successfully.
+/// `fail` fixtures must fail to compile, with the compiler output matching the `.stderr` file next to each fixture.
+/// Paths are relative to the crate root and may contain globs.
+/// Run with `TRYBUILD=overwrite` to (re)generate the `.stderr` files.
+/// # Usage
+/// ```no_run
+/// #[test]
+/// fn derive_diagnostics() {
+///     rod_validation::testing::expand_check! {
+///         pass: "tests/ui/pass/*.rs",
+///         fail: "tests/ui/fail/*.rs",
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! expand_check {
+    (@case $cases:ident, pass, $path:expr) => {
+        $cases.pass($path);
+    };
+    (@case $cases:ident, fail, $path:expr) => {
+        $cases.compile_fail($path);
+    };
+    ($($kind:ident : $path:expr),* $(,)?) => {{
+        let cases = $crate::testing::TestCases::new();
+        $(
+            $crate::expand_check!(@case cases, $kind, $path);
+        )*
+    }};
+}
+
+pub use crate::expand_check;
@@ -43,6 +43,112 @@ fn test_string_literals() {
     assert!(test.validate().is_err());
 }
 
+#[test]
+fn test_literal_one_of() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Literal { one_of: ["draft", "published"] })]
+        status: String,
+        #[rod(Literal { ?"unsupported version" one_of: [1, 2, 3] })]
+        version: i32,
+    }
+    let test = Test {
+        status: "published".to_string(),
+        version: 3,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        status: "archived".to_string(),
+        version: 4,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Literal(LiteralValidation::OneOf(path, value, _)) if path == "status" && value == "archived"));
+    assert_eq!(errors[0].to_string(), "Expected `status` to be one of [\"draft\", \"published\"], got archived");
+    assert_eq!(errors[1].to_string(), "unsupported version");
+}
+
+#[test]
+fn test_literal_not() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Literal { not: "" })]
+        name: String,
+        #[rod(Literal { ?"id must be set" not: 0 })]
+        id: u64,
+    }
+    let test = Test {
+        name: "rod".to_string(),
+        id: 1,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        name: String::new(),
+        id: 0,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Literal(LiteralValidation::Not(path, value)) if path == "name" && value == "\"\""));
+    assert_eq!(errors[0].to_string(), "Expected `name` not to be \"\"");
+    assert_eq!(errors[1].to_string(), "id must be set");
+}
+
+#[test]
+fn test_literal_case_insensitive() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Literal { value: "yes", case_insensitive })]
+        confirm: String,
+        #[rod(Literal { case_insensitive, one_of: ["debug", "info"] })]
+        level: &'static str,
+        #[rod(Literal { not: "root", case_insensitive })]
+        user: String,
+    }
+    let test = Test {
+        confirm: "YES".to_string(),
+        level: "Info",
+        user: "admin".to_string(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        confirm: "no".to_string(),
+        level: "trace",
+        user: "Root".to_string(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].to_string(), "Expected `confirm` to be yes, got no");
+}
+
+#[test]
+fn test_boolean_is() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(bool { is: true })]
+        accepted_terms: bool,
+        #[rod(bool { ?"account must not be locked" is: false })]
+        locked: bool,
+        #[rod(bool)]
+        newsletter: bool,
+    }
+    let test = Test {
+        accepted_terms: true,
+        locked: false,
+        newsletter: true,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        accepted_terms: false,
+        locked: true,
+        newsletter: false,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Boolean(BooleanValidation::Is(path, true)) if path == "accepted_terms"));
+    assert_eq!(errors[0].to_string(), "Expected `accepted_terms` to be true, got false");
+    assert_eq!(errors[1].to_string(), "account must not be locked");
+}
+
 #[test]
 fn test_string_length_enum() {
     #[derive(RodValidate)]
@@ -88,6 +194,44 @@ fn test_enum_tuple() {
     assert!(test.validate().is_err());
 }
 
+#[test]
+fn test_enum_variant_rules() {
+    #[derive(RodValidate)]
+    struct Inner {
+        #[rod(i32 { sign: Positive })]
+        value: i32,
+    }
+    #[derive(RodValidate)]
+    enum Status {
+        Pending {
+            #[rod(i32 { size: 1..=30 })]
+            days: i32,
+        },
+        Overdue {
+            #[rod(i32 { size: 31.. })]
+            days: i32,
+            inner: Inner,
+        },
+        #[rod(skip)]
+        Legacy { days: i32, inner: Inner },
+        #[rod(Skip)]
+        Archived(Inner),
+    }
+    let test = Status::Pending { days: 10 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Status::Pending { days: 40 };
+    assert!(test.validate().is_err());
+    let test = Status::Overdue { days: 40, inner: Inner { value: 1 } };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Status::Overdue { days: 10, inner: Inner { value: -1 } };
+    assert_eq!(test.validate_all().unwrap_err().len(), 2);
+    let test = Status::Legacy { days: -5, inner: Inner { value: -1 } };
+    assert!(test.validate_all().is_ok());
+    let test = Status::Archived(Inner { value: -1 });
+    assert!(test.validate_all().is_ok());
+    assert!(test.suggest_fixes().is_empty());
+}
+
 #[test]
 fn test_enum_embedded_struct() {
     #[derive(RodValidate)]
@@ -168,6 +312,99 @@ fn test_option_none() {
     assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
 }
 
+#[test]
+fn test_option_optional() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(
+            Option {
+                optional,
+                String {
+                    length: 5,
+                }
+            }
+        )]
+        field: Option<String>,
+    }
+
+    let test = Test {
+        field: Some("12345".to_string()),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        field: None,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        field: Some("1234".to_string()),
+    };
+    assert!(matches!(test.validate(), Err(RodValidateError::String(StringValidation::Length(..)))));
+}
+
+#[test]
+fn test_option_required() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Option { required: true })]
+        must_be_some: Option<String>,
+        #[rod(Option { required: false, inner: i32 { size: 1..=10 } })]
+        may_be_either: Option<i32>,
+        #[rod(Option { required: true, inner: i32 { size: 1..=10 } })]
+        must_be_valid: Option<i32>,
+    }
+
+    let test = Test {
+        must_be_some: Some(String::new()),
+        may_be_either: None,
+        must_be_valid: Some(5),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        must_be_some: None,
+        may_be_either: Some(11),
+        must_be_valid: None,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], RodValidateError::Option(OptionValidation::Required(path)) if path == "must_be_some"));
+    assert_eq!(errors[0].to_string(), "Expected `must_be_some` to be Some, got None");
+    assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::Size(..))));
+    assert!(matches!(&errors[2], RodValidateError::Option(OptionValidation::None(path, _)) if path == "must_be_valid"));
+}
+
+#[test]
+fn test_option_required_if() {
+    #[derive(PartialEq)]
+    enum Kind {
+        Email,
+        Sms,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Skip)]
+        kind: Kind,
+        #[rod(Option { required_if: |s: &Self| s.kind == Kind::Email, inner: String { length: 3..=254 } })]
+        email: Option<String>,
+        #[rod(Option { ?"a phone number is needed for SMS" required_if: |s: &Self| s.kind == Kind::Sms })]
+        phone: Option<String>,
+    }
+    let test = Test { kind: Kind::Email, email: Some("a@b.c".to_string()), phone: None };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { kind: Kind::Sms, email: None, phone: Some("555".to_string()) };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { kind: Kind::Sms, email: Some("x".to_string()), phone: None };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Length(..))));
+    assert_eq!(errors[1].to_string(), "a phone number is needed for SMS");
+    let test = Test { kind: Kind::Email, email: None, phone: None };
+    assert!(matches!(test.validate(), Err(RodValidateError::Option(OptionValidation::RequiredIf(path))) if path == "email"));
+    assert_eq!(test.validate().unwrap_err().to_string(), "Expected `email` to be Some when its condition holds, got None");
+}
+
 #[test]
 fn test_option_nested() {
     #[derive(RodValidate)]
@@ -331,6 +568,35 @@ fn test_tuple_struct() {
     assert!(test.validate().is_err(), "{}", test.validate().unwrap_err());
 }
 
+#[test]
+fn test_tuple_skip() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(
+            Tuple (
+                i32 {
+                    sign: Positive,
+                },
+                Skip,
+                String {
+                    length: 1..=5,
+                },
+                skip
+            )
+        )]
+        field: (i32, Vec<u8>, String, (f64, bool)),
+    }
+    let test = Test {
+        field: (1, vec![], "hello".to_string(), (0.5, true)),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        field: (-1, vec![1, 2], "too long".to_string(), (0.5, false)),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+}
+
 #[test]
 fn test_struct_with_reference() {
     #[derive(RodValidate)]
@@ -424,6 +690,59 @@ fn test_iterable() {
     assert!(test.validate().is_err(), "{}", test.validate().unwrap_err());
 }
 
+#[test]
+fn test_iterable_allow_empty() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(
+            Iterable {
+                item: i32 {
+                    sign: Positive,
+                },
+            }
+        )]
+        allowed: Vec<i32>,
+        #[rod(
+            Iterable {
+                item: i32 {
+                    sign: Positive,
+                },
+                allow_empty: false,
+            }
+        )]
+        required: Vec<i32>,
+        #[rod(
+            Iterable {
+                item: String,
+                ?"need at least one tag"
+                allow_empty: false,
+            }
+        )]
+        tags: std::collections::HashSet<String>,
+    }
+    let test = Test {
+        allowed: vec![],
+        required: vec![1],
+        tags: std::collections::HashSet::from(["a".to_string()]),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        allowed: vec![],
+        required: vec![],
+        tags: std::collections::HashSet::new(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Empty(path)) if path == "required"));
+    assert!(matches!(&errors[1], RodValidateError::UserDefined(msg) if msg == "need at least one tag"));
+    let test = Test {
+        allowed: vec![],
+        required: vec![-1],
+        tags: std::collections::HashSet::from(["a".to_string()]),
+    };
+    assert!(test.validate().is_err());
+}
+
 #[test]
 fn test_validate_all() {
     #[derive(RodValidate)]
@@ -638,8 +957,3113 @@ fn test_per_validation_custom_errors() {
         "literal",
         "option",
         "iter length",
-        "iter item length",
     ] {
         assert!(errors.iter().any(|e| matches!(e, RodValidateError::UserDefined(msg) if msg == expected)), "Missing expected message `{}` in errors: {}", expected, errors);
     }
-}
\ No newline at end of file
+    // Item errors keep their message, wrapped with the index of the item
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        RodValidateError::Iterable(IterableValidation::Item(path, 0, inner)) if path == "iterable_field" && matches!(inner.as_ref(), RodValidateError::UserDefined(msg) if msg == "iter item length")
+    )), "Missing expected message `iter item length` in errors: {}", errors);
+}
+crate::formats! {
+    TicketId => r"^TCK-\d{6}$",
+}
+
+mod registered_formats {
+    crate::formats! {
+        pub Sku => r"^[A-Z]{3}-\d{4}$",
+    }
+}
+
+#[test]
+fn test_named_format() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String {
+            format: TicketId,
+        })]
+        ticket: String,
+        #[rod(String {
+            format: registered_formats::Sku,
+        })]
+        sku: String,
+    }
+
+    let test = Test {
+        ticket: "TCK-123456".to_string(),
+        sku: "ABC-1234".to_string(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        ticket: "TCK-12".to_string(),
+        sku: "abc-1234".to_string(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Format(_, _, "TicketId"))));
+    assert!(matches!(&errors[1], RodValidateError::String(StringValidation::Format(_, _, "Sku"))));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_schema_validate_json() {
+    use crate::schema::*;
+
+    fn address_schema() -> StructSchema {
+        StructSchema {
+            name: "Address",
+            fields: vec![
+                FieldSchema::new("zip", TypeSchema::String(vec![
+                    Rule::new(RuleKind::Length(Bounds::Exact(5.0))),
+                ])),
+            ],
+        }
+    }
+
+    let schema = StructSchema {
+        name: "User",
+        fields: vec![
+            FieldSchema::new("username", TypeSchema::String(vec![
+                Rule::new(RuleKind::Length(Bounds::range(Some(3.0), Some(12.0), true))),
+                Rule::new(RuleKind::StartsWith("u_")).with_message("must start with u_"),
+            ])),
+            FieldSchema::new("age", TypeSchema::Integer(vec![
+                Rule::new(RuleKind::Size(Bounds::range(Some(18.0), Some(100.0), false))),
+            ])),
+            FieldSchema::new("nickname", TypeSchema::Option(Some(Box::new(TypeSchema::String(vec![
+                Rule::new(RuleKind::Length(Bounds::range(Some(1.0), None, false))),
+            ]))))),
+            FieldSchema::new("tags", TypeSchema::Iterable {
+                item: Box::new(TypeSchema::String(vec![])),
+                rules: vec![Rule::new(RuleKind::Length(Bounds::range(None, Some(2.0), true)))],
+            }),
+            FieldSchema::new("address", TypeSchema::Nested(address_schema)),
+        ],
+    };
+
+    let valid = serde_json::json!({
+        "username": "u_rod",
+        "age": 30,
+        "nickname": "r",
+        "tags": ["a", "b"],
+        "address": { "zip": "12345" },
+    });
+    assert!(schema.validate_json(&valid).is_ok(), "{}", schema.validate_json(&valid).unwrap_err());
+
+    let invalid = serde_json::json!({
+        "username": "rod",
+        "age": 12.5,
+        "nickname": null,
+        "tags": ["a", "b", "c"],
+        "address": { "zip": "123" },
+    });
+    let errors = schema.validate_json(&invalid).unwrap_err();
+    assert_eq!(errors.len(), 5, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::UserDefined(msg) if msg == "must start with u_"));
+    assert!(matches!(&errors[1], RodValidateError::Schema(SchemaValidation::Type(path, "an integer", _)) if path == "age"));
+    assert!(matches!(&errors[2], RodValidateError::Option(OptionValidation::None(path, _)) if path == "nickname"));
+    assert!(matches!(&errors[3], RodValidateError::Iterable(IterableValidation::Length(path, 3, _)) if path == "tags"));
+    assert!(matches!(&errors[4], RodValidateError::String(StringValidation::Length(path, ..)) if path == "address.zip"));
+
+    let missing = serde_json::json!({ "username": "u_rod" });
+    let errors = schema.validate_json(&missing).unwrap_err();
+    assert!(errors.iter().any(|e| matches!(e, RodValidateError::Schema(SchemaValidation::Missing(path)) if path == "age")), "{}", errors);
+}
+
+#[test]
+fn test_schema_markdown() {
+    use crate::schema::*;
+
+    fn address_schema() -> StructSchema {
+        StructSchema {
+            name: "Address",
+            fields: vec![FieldSchema::new("zip", TypeSchema::String(vec![
+                Rule::new(RuleKind::Length(Bounds::Exact(5.0))).with_message("zip | code"),
+            ]))],
+        }
+    }
+    let schema = StructSchema {
+        name: "User",
+        fields: vec![
+            FieldSchema::new("tags", TypeSchema::Iterable {
+                item: Box::new(TypeSchema::String(vec![Rule::new(RuleKind::StartsWith("#"))])),
+                rules: vec![Rule::new(RuleKind::Length(Bounds::range(None, Some(3.0), true)))],
+            }),
+            FieldSchema::new("bio", TypeSchema::Option(None)),
+            FieldSchema::new("address", TypeSchema::Nested(address_schema)),
+        ],
+    };
+    let markdown = to_markdown(&[schema, address_schema()]);
+    assert_eq!(markdown, "\
+### User
+
+| Field | Type | Constraints | Message |
+| --- | --- | --- | --- |
+| `tags` | iterable<string> | length ..=3, items: starts with \"#\" |  |
+| `bio` | option | must be None |  |
+| `address` | Address | validated as `Address` |  |
+
+### Address
+
+| Field | Type | Constraints | Message |
+| --- | --- | --- | --- |
+| `zip` | string | length 5 | zip \\| code |
+");
+}
+
+#[test]
+fn test_rod_schema() {
+    use crate::schema::*;
+
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(String { length: 5 })]
+        zip: String,
+    }
+
+    #[derive(RodValidate)]
+    struct User {
+        #[rod(rename = "userName", String { length: 3..=12, starts_with: "u_" }, message: "invalid username")]
+        username: String,
+        #[rod(i32 { size: 18..=120 }, check = |age| age % 2 == 0)]
+        age: i32,
+        #[rod(String { format: Email })]
+        email: String,
+        #[rod(Option { required: false, inner: String { length: 1.. } })]
+        nickname: Option<String>,
+        address: Address,
+    }
+
+    let schema = User::rod_schema();
+    assert_eq!(schema.name, "User");
+    assert_eq!(schema.fields.iter().map(|field| field.name).collect::<Vec<_>>(), ["userName", "age", "email", "nickname", "address"]);
+
+    let username = schema.field("userName").unwrap();
+    assert_eq!(username.message, Some("invalid username"));
+    assert_eq!(username.ty.describe_constraints(), "length 3..=12, starts with \"u_\"");
+    assert!(matches!(username.ty.rules()[0].kind, RuleKind::Length(bounds) if bounds == Bounds::range(Some(3.0), Some(12.0), true)));
+
+    let age = schema.field("age").unwrap();
+    assert_eq!(age.ty.describe_type(), "integer");
+    assert_eq!(age.ty.describe_constraints(), "type i32, size 18..=120, custom check");
+
+    assert_eq!(schema.field("email").unwrap().ty.describe_constraints(), "format Email");
+    assert_eq!(schema.field("nickname").unwrap().ty.describe_constraints(), "may be None, length 1..");
+    assert_eq!(schema.field("address").unwrap().ty.describe_type(), "Address");
+    assert_eq!(Address::rod_schema().field("zip").unwrap().ty.describe_constraints(), "length 5");
+
+    #[cfg(feature = "serde_json")]
+    {
+        let payload = serde_json::json!({
+            "userName": "rod",
+            "age": 30,
+            "email": "rod@example.com",
+            "address": { "zip": "123" },
+        });
+        let errors = schema.validate_json(&payload).unwrap_err();
+        assert_eq!(errors.len(), 2, "{}", errors);
+        assert!(matches!(&errors[0], RodValidateError::UserDefined(msg) if msg == "invalid username"));
+        assert!(matches!(&errors[1], RodValidateError::String(StringValidation::Length(path, ..)) if path == "address.zip"));
+
+        // Integers are checked against the range of their type, and compared exactly above 2^53
+        #[derive(RodValidate)]
+        struct Counter {
+            #[rod(u8 { size: 1.. })]
+            level: u8,
+            #[rod(u64 { size: ..=9007199254740994, step: 2 })]
+            ticks: u64,
+        }
+        let schema = Counter::rod_schema();
+        let errors = schema.validate_json(&serde_json::json!({ "level": 300, "ticks": 9007199254740993u64 })).unwrap_err();
+        assert_eq!(errors.len(), 2, "{}", errors);
+        assert!(matches!(&errors[0], RodValidateError::Schema(SchemaValidation::Type(path, "a u8", _)) if path == "level"));
+        assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::Step(path, ..)) if path == "ticks"));
+        assert!(schema.validate_json(&serde_json::json!({ "level": 255, "ticks": 9007199254740994u64 })).is_ok());
+        let errors = schema.validate_json(&serde_json::json!({ "level": 1, "ticks": 9007199254740995u64 })).unwrap_err();
+        assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::Size(path, ..)) if path == "ticks"));
+    }
+
+    // Maps are JSON objects, and each value is validated with the schema of the map's values
+    #[derive(RodValidate)]
+    struct Contacts {
+        by_label: std::collections::HashMap<String, Address>,
+    }
+    let schema = Contacts::rod_schema();
+    let by_label = schema.field("by_label").unwrap();
+    assert_eq!(by_label.ty.describe_type(), "map<Address>");
+    assert_eq!(by_label.ty.describe_constraints(), "values: validated as `Address`");
+    #[cfg(feature = "serde_json")]
+    {
+        assert!(schema.validate_json(&serde_json::json!({ "by_label": { "home": { "zip": "12345" } } })).is_ok());
+        let errors = schema.validate_json(&serde_json::json!({ "by_label": { "home": { "zip": "123" } } })).unwrap_err();
+        assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Length(path, ..)) if path == "by_label[home].zip"));
+        let errors = schema.validate_json(&serde_json::json!({ "by_label": [] })).unwrap_err();
+        assert!(matches!(&errors[0], RodValidateError::Schema(SchemaValidation::Type(path, "an object", _)) if path == "by_label"));
+    }
+}
+
+#[test]
+fn test_rod_schema_local_schema_module() {
+    // A `mod schema` of the deriving crate, like the one generated by Diesel, shadows `schema` from the prelude
+    mod models {
+        use crate::prelude::*;
+
+        #[allow(dead_code)]
+        mod schema {
+            pub struct Posts;
+        }
+
+        #[derive(RodValidate)]
+        pub struct Tag {
+            #[rod(String { length: 1..=16 })]
+            pub label: String,
+        }
+
+        #[derive(RodValidate)]
+        pub struct Post {
+            #[rod(String { length: 1..=80 }, check = |title| !title.is_empty())]
+            pub title: String,
+            #[rod(u8 { size: 1..=5 })]
+            pub rating: u8,
+            #[rod(Option { f64 { size: 0.0..=1.0 } })]
+            pub score: Option<f64>,
+            pub tags: Vec<Tag>,
+        }
+    }
+    let schema = models::Post::rod_schema();
+    assert_eq!(schema.fields.iter().map(|field| field.name).collect::<Vec<_>>(), ["title", "rating", "score", "tags"]);
+    assert_eq!(schema.field("title").unwrap().ty.describe_constraints(), "length 1..=80, custom check");
+    assert_eq!(schema.field("rating").unwrap().ty.describe_constraints(), "type u8, size 1..=5");
+}
+
+#[test]
+fn test_tags() {
+    #[derive(RodValidate)]
+    struct Billing {
+        #[rod(String { length: 16 }, tags("billing", "pii"))]
+        card: String,
+        #[rod(i32 { sign: Positive })]
+        amount: i32,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 1..=5 }, tags("pii"))]
+        name: String,
+        #[rod(String { includes: "@" }, tags("contact"))]
+        email: String,
+        billing: Billing,
+    }
+    let test = Test {
+        name: "too long name".to_string(),
+        email: "nope".to_string(),
+        billing: Billing {
+            card: "123".to_string(),
+            amount: -1,
+        },
+    };
+    assert_eq!(test.validate_all().unwrap_err().len(), 4);
+    assert_eq!(test.validate_all_tagged(&["pii"]).unwrap_err().len(), 2);
+    assert_eq!(test.validate_all_tagged(&["contact", "billing"]).unwrap_err().len(), 2);
+    assert!(test.validate_all_tagged(&["other"]).is_ok());
+    assert!(matches!(
+        test.validate_tagged(&["contact"]),
+        Err(RodValidateError::String(StringValidation::Includes(path, _, _))) if path == "email"
+    ));
+}
+
+#[test]
+fn test_suggest_fixes() {
+    #[derive(RodValidate)]
+    struct Inner {
+        #[rod(f32 { size: 0.0..=1.0 })]
+        ratio: f32,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 1..=5 })]
+        name: String,
+        #[rod(str { length: 3 })]
+        code: &'static str,
+        #[rod(u8 { size: 10..20 })]
+        count: u8,
+        #[rod(i64 { size: 3 })]
+        exact: i64,
+        #[rod(i32 { sign: Positive })]
+        unfixable: i32,
+        inner: Inner,
+    }
+    let test = Test {
+        name: "héllo world".to_string(),
+        code: "ab",
+        count: 42,
+        exact: 7,
+        unfixable: -1,
+        inner: Inner { ratio: 1.5 },
+    };
+    let fixes = test.suggest_fixes();
+    assert_eq!(fixes.len(), 4, "{:?}", fixes);
+    assert!(matches!(&fixes[0], RodFix { path, value: RodFixValue::String(s) } if path == "name" && s == "héll"));
+    assert!(matches!(&fixes[1], RodFix { path, value: RodFixValue::Integer(Integer::Positive(19)) } if path == "count"));
+    assert!(matches!(&fixes[2], RodFix { path, value: RodFixValue::Integer(Integer::Negative(3)) } if path == "exact"));
+    assert!(matches!(&fixes[3], RodFix { path, value: RodFixValue::Float(1.0) } if path == "inner.ratio"));
+
+    #[derive(RodValidate)]
+    enum TestEnum {
+        Named {
+            #[rod(i32 { size: 0..=3 })]
+            value: i32,
+            #[rod(skip)]
+            other: i32,
+        },
+        Unnamed(
+            #[rod(skip)]
+            i32,
+            #[rod(String { length: 0..2 })]
+            String,
+        ),
+        Unit,
+    }
+    let fixes = TestEnum::Named { value: 5, other: 5 }.suggest_fixes();
+    assert!(matches!(&fixes[..], [RodFix { path, value: RodFixValue::Integer(Integer::Negative(3)) }] if path == "value"));
+    let fixes = TestEnum::Unnamed(0, "abc".to_string()).suggest_fixes();
+    assert!(matches!(&fixes[..], [RodFix { path, value: RodFixValue::String(s) }] if path == "field_1" && s == "a"));
+    assert!(TestEnum::Unit.suggest_fixes().is_empty());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_expand_check() {
+    crate::testing::expand_check! {
+        pass: "tests/ui/pass/*.rs",
+        fail: "tests/ui/fail/*.rs",
+    }
+}
+
+#[test]
+fn test_parses_to() {
+    #[derive(RodValidate)]
+    struct Config {
+        #[rod(String {
+            parses_to: u16 {
+                size: 1024..=65535,
+            },
+        })]
+        port: String,
+        #[rod(String {
+            parses_to: f64 {
+                size: 0.0..=1.0,
+            },
+        })]
+        ratio: String,
+        #[rod(String {
+            ?"Must be an IP address"
+            parses_to: std::net::IpAddr,
+        })]
+        host: String,
+    }
+
+    let config = Config {
+        port: "8080".to_string(),
+        ratio: "0.5".to_string(),
+        host: "127.0.0.1".to_string(),
+    };
+    assert!(config.validate().is_ok(), "{}", config.validate().unwrap_err());
+
+    let config = Config {
+        port: "80".to_string(),
+        ratio: "half".to_string(),
+        host: "localhost".to_string(),
+    };
+    let errors = config.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::Size(path, Integer::Positive(80), _)) if path == "port"));
+    assert!(matches!(&errors[1], RodValidateError::String(StringValidation::ParsesTo(path, _, "f64")) if path == "ratio"));
+    assert!(matches!(&errors[2], RodValidateError::UserDefined(msg) if msg == "Must be an IP address"));
+
+    let config = Config {
+        port: "99999".to_string(),
+        ratio: "0.5".to_string(),
+        host: "::1".to_string(),
+    };
+    let error = config.validate().unwrap_err();
+    assert!(matches!(&error, RodValidateError::String(StringValidation::ParsesTo(path, _, "u16")) if path == "port"), "{}", error);
+}
+
+#[test]
+fn test_wrap_error() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static WRAPPED: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_error(error: RodValidateError) -> RodValidateError {
+        WRAPPED.fetch_add(1, Ordering::SeqCst);
+        error
+    }
+
+    fn hide_value(error: RodValidateError) -> RodValidateError {
+        RodValidateError::UserDefined(format!("hidden: {}", error.to_string().len()))
+    }
+
+    #[derive(RodValidate)]
+    #[rod(wrap_error = hide_value)]
+    struct Inner {
+        #[rod(i32 { size: 0..=10 })]
+        value: i32,
+    }
+
+    #[derive(RodValidate)]
+    #[rod(wrap_error = count_error)]
+    struct Test {
+        #[rod(String { length: 1..=3 })]
+        name: String,
+        inner: Inner,
+    }
+
+    let test = Test {
+        name: "abcd".to_string(),
+        inner: Inner { value: 42 },
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Length(path, ..)) if path == "name"));
+    assert!(matches!(&errors[1], RodValidateError::UserDefined(msg) if msg.starts_with("hidden: ")));
+    assert_eq!(WRAPPED.load(Ordering::SeqCst), 2);
+
+    assert!(test.validate().is_err());
+    assert_eq!(WRAPPED.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_custom_equals() {
+    #[derive(Debug, PartialEq)]
+    enum Plan {
+        Active,
+        Cancelled { reason: String },
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Plan { equals: Plan::Active })]
+        plan: Plan,
+        #[rod(Plan { ?"backup plan must be active" equals: Plan::Active })]
+        backup: Plan,
+    }
+    let test = Test {
+        plan: Plan::Active,
+        backup: Plan::Active,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        plan: Plan::Cancelled { reason: "moved".to_string() },
+        backup: Plan::Cancelled { reason: "moved".to_string() },
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Custom(CustomValidation::Equals(path, _, expected)) if path == "plan" && expected == "Active"));
+    assert_eq!(errors[0].to_string(), "Expected `plan` to equal Active, got Cancelled { reason: \"moved\" }");
+    assert_eq!(errors[1].to_string(), "backup plan must be active");
+}
+
+#[test]
+fn test_enum_tag() {
+    #[allow(dead_code)]
+    enum Status {
+        Active,
+        Paused,
+        Deleted,
+    }
+
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(EnumTag {
+            one_of: [Status::Active, Status::Paused],
+        })]
+        status: Status,
+        #[rod(Option {
+            EnumTag {
+                ?"Previous status must be active"
+                one_of: [Status::Active],
+            }
+        })]
+        previous: Option<Status>,
+    }
+
+    let test = Test {
+        status: Status::Paused,
+        previous: Some(Status::Active),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        status: Status::Deleted,
+        previous: Some(Status::Paused),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::EnumTag(EnumTagValidation::OneOf(path, "Status::Active, Status::Paused")) if path == "status"));
+    assert_eq!(errors[0].to_string(), "Expected `status` to be one of Status::Active, Status::Paused");
+    assert!(matches!(&errors[1], RodValidateError::UserDefined(msg) if msg == "Previous status must be active"));
+}
+
+#[test]
+fn test_printable() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String {
+            printable: true,
+        })]
+        display_name: String,
+        #[rod(String {
+            printable: Ascii,
+        })]
+        username: String,
+    }
+
+    let test = Test {
+        display_name: "Zoë Smith".to_string(),
+        username: "zoe smith".to_string(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        display_name: "Zoë\u{1b}[31m".to_string(),
+        username: "zoë".to_string(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Printable(path, _, "printable characters")) if path == "display_name"));
+    assert_eq!(errors[0].to_string(), "Expected `display_name` to contain only printable characters, got \"Zoë\\u{1b}[31m\"");
+    assert!(matches!(&errors[1], RodValidateError::String(StringValidation::Printable(path, _, "printable ASCII characters")) if path == "username"));
+
+    let test = Test {
+        display_name: "tab\there".to_string(),
+        username: "tab\there".to_string(),
+    };
+    assert_eq!(test.validate_all().unwrap_err().len(), 2);
+}
+
+#[test]
+fn test_parity() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(i32 {
+            parity: Even,
+        })]
+        even: i32,
+        #[rod(i64 {
+            parity: Odd,
+        })]
+        odd: i64,
+    }
+
+    for (even, odd) in [(0, 1), (-4, -3), (2, -1)] {
+        let test = Test { even, odd };
+        assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    }
+
+    let test = Test { even: -3, odd: -4 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::Parity(path, Integer::Negative(-3), "even")) if path == "even"));
+    assert_eq!(errors[0].to_string(), "Expected `even` to be an even integer, got -3");
+    assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::Parity(path, Integer::Negative(-4), "odd")) if path == "odd"));
+}
+
+#[test]
+fn test_whitespace() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String {
+            whitespace: None,
+        })]
+        handle: String,
+        #[rod(String {
+            whitespace: NoLeadingTrailing,
+        })]
+        title: String,
+        #[rod(String {
+            whitespace: SingleSpacesOnly,
+        })]
+        full_name: String,
+    }
+
+    let test = Test {
+        handle: "jdoe".to_string(),
+        title: "Dr.  John".to_string(),
+        full_name: "John Ronald Doe".to_string(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        handle: "j doe".to_string(),
+        title: "Dr. John\n".to_string(),
+        full_name: "John  Doe".to_string(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Whitespace(path, _, "no whitespace")) if path == "handle"));
+    assert!(matches!(&errors[1], RodValidateError::String(StringValidation::Whitespace(path, _, "no leading or trailing whitespace")) if path == "title"));
+    assert!(matches!(&errors[2], RodValidateError::String(StringValidation::Whitespace(path, _, "single spaces between words only")) if path == "full_name"));
+
+    for full_name in [" John Doe", "John Doe ", "John\tDoe"] {
+        let test = Test {
+            handle: String::new(),
+            title: String::new(),
+            full_name: full_name.to_string(),
+        };
+        assert!(test.validate().is_err(), "{:?} should be rejected", full_name);
+    }
+}
+
+#[test]
+fn test_numeric_string() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String {
+            numeric: true,
+        })]
+        account: String,
+        #[rod(String {
+            numeric: true,
+            leading_zeros: false,
+            max_digits: 4,
+        })]
+        pin: String,
+    }
+
+    let test = Test {
+        account: "000123".to_string(),
+        pin: "0".to_string(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        account: "12a3".to_string(),
+        pin: "0123".to_string(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::String(StringValidation::Numeric(path, _, "only digits")) if path == "account"));
+    assert_eq!(errors[1].to_string(), "Expected `pin` to contain only digits without leading zeros, at most 4 of them, got 0123");
+
+    for (account, pin) in [("", "1"), ("1", "12345"), ("١٢", "1"), ("1", "-1")] {
+        let test = Test {
+            account: account.to_string(),
+            pin: pin.to_string(),
+        };
+        assert!(test.validate().is_err(), "{:?} {:?} should be rejected", account, pin);
+    }
+}
+
+#[test]
+fn test_step_offset() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(i32 {
+            step: (5, 1),
+        })]
+        offset: i32,
+        #[rod(u32 {
+            step: (4, -1),
+        })]
+        negative_offset: u32,
+        #[rod(i32 {
+            step: (3, 3),
+        })]
+        full_offset: i32,
+    }
+
+    for (offset, negative_offset, full_offset) in [(1, 3, 0), (11, 7, -3), (-4, 11, 9)] {
+        let test = Test { offset, negative_offset, full_offset };
+        assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    }
+
+    let test = Test { offset: -1, negative_offset: 4, full_offset: 1 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::StepOffset(path, Integer::Negative(-1), step_offset)) if path == "offset" && matches!(**step_offset, (Integer::Negative(5), Integer::Negative(1)))));
+    assert_eq!(errors[0].to_string(), "Expected `offset` to be an integer with step 5 and offset 1, got -1");
+    assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::StepOffset(path, _, step_offset)) if path == "negative_offset" && matches!(step_offset.1, Integer::Negative(3))));
+    assert!(matches!(&errors[2], RodValidateError::Integer(IntegerValidation::Step(path, _, _)) if path == "full_offset"));
+}
+
+#[test]
+fn test_integer_one_of() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(u16 {
+            one_of: [80, 443, 8080],
+        })]
+        port: u16,
+        #[rod(i32 {
+            not_one_of: [-1, 0],
+        })]
+        id: i32,
+    }
+
+    let test = Test { port: 443, id: 7 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test { port: 22, id: -1 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::OneOf(path, Integer::Positive(22), "[80, 443, 8080]")) if path == "port"));
+    assert_eq!(errors[0].to_string(), "Expected `port` to be one of [80, 443, 8080], got 22");
+    assert_eq!(errors[1].to_string(), "Expected `id` not to be one of [-1, 0], got -1");
+}
+
+#[test]
+fn test_bitmask() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(u8 {
+            bits_set: 0b0000_0101,
+            bits_clear: 0x80,
+        })]
+        flags: u8,
+    }
+
+    let test = Test { flags: 0b0111_0101 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test { flags: 0b1000_0100 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::BitsSet(path, Integer::Positive(0b1000_0100), Integer::Positive(0b1))) if path == "flags"));
+    assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::BitsClear(path, _, Integer::Positive(0x80))) if path == "flags"));
+    assert_eq!(errors[1].to_string(), "Expected `flags` to have the bits 0b10000000 clear, got 0b10000100");
+}
+
+#[test]
+fn test_non_zero() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(i32 {
+            non_zero: true,
+        })]
+        divisor: i32,
+        #[rod(f64 {
+            non_zero: true,
+        })]
+        scale: f64,
+        #[rod(Option {
+            u8 {
+                non_zero: true,
+            }
+        })]
+        retries: Option<u8>,
+        #[rod(Tuple (
+            f32 {
+                non_zero: true,
+            },
+            i64
+        ))]
+        point: (f32, i64),
+        #[rod(Iterable {
+            item: u32 {
+                ?"Weights must not be zero"
+                non_zero: true,
+            }
+        })]
+        weights: Vec<u32>,
+    }
+
+    let test = Test {
+        divisor: -2,
+        scale: 0.5,
+        retries: Some(3),
+        point: (1.0, 0),
+        weights: vec![1, 2],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+
+    let test = Test {
+        divisor: 0,
+        scale: -0.0,
+        retries: Some(0),
+        point: (0.0, 0),
+        weights: vec![1, 0],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 5, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::NonZero(path)) if path == "divisor"));
+    assert_eq!(errors[0].to_string(), "Expected `divisor` to be an integer other than zero");
+    assert!(matches!(&errors[1], RodValidateError::Float(FloatValidation::NonZero(path)) if path == "scale"));
+    assert!(matches!(&errors[2], RodValidateError::Integer(IntegerValidation::NonZero(_))));
+    assert!(matches!(&errors[3], RodValidateError::Float(FloatValidation::NonZero(_))));
+    assert!(matches!(&errors[4], RodValidateError::Iterable(IterableValidation::Item(path, 1, inner)) if path == "weights" && matches!(inner.as_ref(), RodValidateError::UserDefined(msg) if msg == "Weights must not be zero")));
+}
+
+#[test]
+fn test_decimal_places() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(f64 { decimal_places: 2 })]
+        price: f64,
+        #[rod(f32 { ?"too precise" decimal_places: 1 })]
+        ratio: f32,
+    }
+    let test = Test { price: 19.99, ratio: 0.5 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { price: 0.1 + 0.2, ratio: 12.3 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { price: 10.0, ratio: -3.0 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { price: 19.999, ratio: 0.25 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Float(FloatValidation::DecimalPlaces(path, _, 2)) if path == "price"));
+    assert!(matches!(&errors[1], RodValidateError::UserDefined(msg) if msg == "too precise"));
+    let test = Test { price: f64::NAN, ratio: 0.5 };
+    assert!(test.validate().is_err());
+}
+
+#[test]
+fn test_fail_if() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(
+            String {
+                length: 3..=16,
+            },
+            fail_if = |s| ["admin", "root"].contains(&s.as_str())
+        )]
+        username: String,
+        #[rod(
+            i32 {
+                sign: Positive,
+            },
+            check = |x| *x < 1000,
+            fail_if = |x| *x == 13,
+            message: "unlucky or too large"
+        )]
+        number: i32,
+    }
+    let test = Test { username: "alice".to_string(), number: 7 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { username: "root".to_string(), number: 7 };
+    assert!(matches!(test.validate(), Err(RodValidateError::CheckFailed(path)) if path == "username"));
+    let test = Test { username: "alice".to_string(), number: 13 };
+    assert!(matches!(test.validate(), Err(RodValidateError::UserDefined(msg)) if msg == "unlucky or too large"));
+    let test = Test { username: "alice".to_string(), number: 1000 };
+    assert!(test.validate().is_err());
+}
+
+#[test]
+fn test_float_step() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(f64 { step: 0.25 })]
+        quarter: f64,
+        #[rod(f32 { step: 0.1 })]
+        tenth: f32,
+        #[rod(f64 { step: 0.5, tolerance: 0.01 })]
+        loose: f64,
+    }
+    let test = Test { quarter: 1.75, tenth: 0.3, loose: 2.505 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { quarter: -0.5, tenth: 0.1 + 0.2, loose: 0.0 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { quarter: 1.3, tenth: 0.15, loose: 2.52 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], RodValidateError::Float(FloatValidation::Step(path, _, step)) if path == "quarter" && *step == 0.25));
+    let test = Test { quarter: f64::INFINITY, tenth: 0.0, loose: 0.0 };
+    assert!(test.validate().is_err());
+}
+
+#[test]
+fn test_callback_fields_are_skipped() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 1..=10 })]
+        name: String,
+        callback: fn(u32) -> bool,
+        boxed: Box<dyn Fn(&str) -> bool + Send>,
+        shared: std::sync::Arc<dyn FnMut()>,
+        borrowed: &'static dyn Fn(),
+        marker: std::marker::PhantomData<u8>,
+    }
+    #[derive(RodValidate)]
+    enum Handler {
+        Callback(fn()),
+        Named {
+            #[rod(String { length: 1..=10 })]
+            name: String,
+            callback: Box<dyn FnOnce()>,
+        },
+    }
+    let test = Test {
+        name: "job".to_string(),
+        callback: |x| x > 1,
+        boxed: Box::new(|s| s.is_empty()),
+        shared: std::sync::Arc::new(|| {}),
+        borrowed: &|| {},
+        marker: std::marker::PhantomData,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    assert!(test.suggest_fixes().is_empty());
+    assert!(Handler::Callback(|| {}).validate().is_ok());
+    let handler = Handler::Named { name: String::new(), callback: Box::new(|| {}) };
+    assert!(handler.validate().is_err());
+}
+
+#[test]
+fn test_exact_float_size() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(f64 { size: 0.3 })]
+        sum: f64,
+        #[rod(f64 { size: (1.23456, tolerance: 1e-5) })]
+        ratio: f64,
+        #[rod(f32 { size: 2, tolerance: 0.5 })]
+        two: f32,
+    }
+    let test = Test { sum: 0.1 + 0.2, ratio: 1.234561, two: 2.4 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { sum: 0.31, ratio: 1.23, two: 2.6 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], RodValidateError::Float(FloatValidation::Size(path, _, _)) if path == "sum"));
+    assert_eq!(errors[1].to_string(), "Expected `ratio` to be a float to be within 0.00001 of 1.23456, got 1.23");
+    let fixes = test.suggest_fixes();
+    assert_eq!(fixes.len(), 3);
+    assert!(matches!(fixes[0].value, RodFixValue::Float(value) if value == 0.3));
+}
+
+#[test]
+fn test_chars_in() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { chars_in: ['a'..='z', '0'..='9', '_'] })]
+        username: String,
+        #[rod(String { chars_in: 'A'..='F' })]
+        hex: String,
+        #[rod(String { ?"only dashes and dots" chars_in: ['-', '.'] })]
+        morse: String,
+    }
+    let test = Test { username: "user_42".to_string(), hex: "CAFE".to_string(), morse: ".-..".to_string() };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { username: String::new(), hex: String::new(), morse: String::new() };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { username: "User-42".to_string(), hex: "CAFG".to_string(), morse: "._".to_string() };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        errors[0].to_string(),
+        "Expected `username` to contain only characters in ['a'..='z', '0'..='9', '_'], got \"User-42\""
+    );
+    assert!(matches!(&errors[1], RodValidateError::String(StringValidation::CharsIn(path, _, _)) if path == "hex"));
+    assert!(matches!(&errors[2], RodValidateError::UserDefined(msg) if msg == "only dashes and dots"));
+}
+
+#[test]
+fn test_float_type() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(f64 { ftype: Finite })]
+        finite: f64,
+        #[rod(f64 { ftype: !NaN })]
+        not_nan: f64,
+        #[rod(f32 { ftype: not(Subnormal) })]
+        not_subnormal: f32,
+    }
+    let test = Test { finite: 1.0, not_nan: f64::INFINITY, not_subnormal: 0.5 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { finite: f64::NEG_INFINITY, not_nan: f64::NAN, not_subnormal: f32::MIN_POSITIVE / 2.0 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], RodValidateError::Float(FloatValidation::Type(path, _, "Finite")) if path == "finite"));
+    assert!(matches!(&errors[1], RodValidateError::Float(FloatValidation::Type(path, _, "not NaN")) if path == "not_nan"));
+    assert_eq!(errors[1].to_string(), "Expected `not_nan` to be a float of type not NaN, got NaN");
+}
+
+#[test]
+fn test_integer_digits() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(u16 { digits: 4 })]
+        year: u16,
+        #[rod(i64 { max_digits: 3 })]
+        offset: i64,
+        #[rod(u32 { ?"PIN must have 6 digits" digits: 6 })]
+        pin: u32,
+    }
+    let test = Test { year: 2024, offset: -999, pin: 123456 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { year: 1000, offset: 0, pin: 999999 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { year: 999, offset: -1000, pin: 12345 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].to_string(), "Expected `year` to have exactly 4 digits, got 999");
+    assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::Digits(path, _, _)) if path == "offset"));
+    assert!(matches!(&errors[2], RodValidateError::UserDefined(msg) if msg == "PIN must have 6 digits"));
+    assert_eq!(Integer::from(0u8).digits(), 1);
+    assert_eq!(Integer::from(i128::MIN).digits(), 39);
+    assert_eq!(Integer::from(u128::MAX).digits(), 39);
+}
+
+#[test]
+fn test_disjoint() {
+    #[derive(RodValidate)]
+    #[rod(disjoint(admins, members, banned))]
+    struct Test {
+        #[rod(Iterable { item: String })]
+        admins: Vec<String>,
+        #[rod(Iterable { item: String })]
+        members: Vec<String>,
+        #[rod(Iterable { item: String })]
+        banned: std::collections::BTreeSet<String>,
+    }
+    let test = Test {
+        admins: vec!["alice".to_string()],
+        members: vec!["bob".to_string(), "carol".to_string()],
+        banned: ["mallory".to_string()].into(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        admins: vec!["alice".to_string(), "bob".to_string()],
+        members: vec!["bob".to_string(), "mallory".to_string()],
+        banned: ["mallory".to_string()].into(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Struct(StructValidation::Disjoint(path1, path2, overlap)) if path1 == "admins" && path2 == "members" && overlap == &["\"bob\""]));
+    assert_eq!(errors[1].to_string(), "Expected `members` and `banned` to have no common elements, found [\"mallory\"]");
+    assert!(test.validate_tagged(&["any"]).is_ok());
+}
+
+#[test]
+fn test_const_expressions() {
+    const MIN_AGE: u8 = 18;
+    const MAX_AGE: u8 = 120;
+    mod limits {
+        pub const NAME_LEN: usize = 5;
+        pub const TARGET: f64 = 0.5;
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(u8 { size: MIN_AGE..=MAX_AGE })]
+        age: u8,
+        #[rod(String { length: limits::NAME_LEN })]
+        name: String,
+        #[rod(String { length: 1..=limits::NAME_LEN * 2 })]
+        nickname: String,
+        #[rod(Iterable { item: u8, length: ..limits::NAME_LEN })]
+        scores: Vec<u8>,
+        #[rod(f64 { size: limits::TARGET })]
+        ratio: f64,
+    }
+    let test = Test {
+        age: 30,
+        name: "Alice".to_string(),
+        nickname: "Al".to_string(),
+        scores: vec![1, 2],
+        ratio: 0.5,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        age: 17,
+        name: "Bob".to_string(),
+        nickname: "Bobby Tables".to_string(),
+        scores: vec![1, 2, 3, 4, 5],
+        ratio: 0.25,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 5);
+    assert_eq!(errors[0].to_string(), "Expected `age` to be an integer to be in the range 18..=120, got 17");
+    let fixes = test.suggest_fixes();
+    assert!(fixes.iter().any(|fix| fix.path == "age" && matches!(fix.value, RodFixValue::Integer(_))));
+    assert!(fixes.iter().any(|fix| fix.path == "ratio" && matches!(fix.value, RodFixValue::Float(value) if value == 0.5)));
+}
+
+#[test]
+fn test_subset_of() {
+    #[derive(RodValidate)]
+    #[rod(subset_of(selected, available), superset_of(available, defaults))]
+    struct Test {
+        #[rod(Iterable { item: u32 })]
+        selected: Vec<u32>,
+        #[rod(Iterable { item: u32 })]
+        available: std::collections::HashSet<u32>,
+        #[rod(Iterable { item: u32 })]
+        defaults: Vec<u32>,
+    }
+    let test = Test {
+        selected: vec![1, 2],
+        available: [1, 2, 3].into(),
+        defaults: vec![],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        selected: vec![1, 4, 5],
+        available: [1, 2, 3].into(),
+        defaults: vec![3, 6],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].to_string(), "Expected every element of `selected` to be in `available`, missing [4, 5]");
+    assert!(matches!(&errors[1], RodValidateError::Struct(StructValidation::SubsetOf(path1, path2, missing)) if path1 == "defaults" && path2 == "available" && missing == &["6"]));
+}
+
+#[test]
+fn test_same_length() {
+    #[derive(RodValidate)]
+    #[rod(same_length(labels, values, colors))]
+    struct Test {
+        #[rod(Iterable { item: String })]
+        labels: Vec<String>,
+        #[rod(Iterable { item: f64 })]
+        values: Vec<f64>,
+        #[rod(Iterable { item: u32 })]
+        colors: std::collections::VecDeque<u32>,
+    }
+    let test = Test {
+        labels: vec!["a".to_string(), "b".to_string()],
+        values: vec![1.0, 2.0],
+        colors: [1, 2].into(),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        labels: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        values: vec![1.0, 2.0],
+        colors: [1, 2, 3].into(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "Expected `labels` and `values` to have the same length, got 3 and 2");
+    assert!(matches!(test.validate(), Err(RodValidateError::Struct(StructValidation::SameLength(path1, 3, path2, 2))) if path1 == "labels" && path2 == "values"));
+}
+
+#[test]
+fn test_exclusive() {
+    #[derive(RodValidate)]
+    #[rod(exclusive(email, phone, username))]
+    struct Test {
+        #[rod(Option { optional, String })]
+        email: Option<String>,
+        #[rod(Option { optional, String })]
+        phone: Option<String>,
+        #[rod(Option { optional, String })]
+        username: Option<String>,
+    }
+    let test = Test { email: Some("a@b.c".to_string()), phone: None, username: None };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { email: None, phone: None, username: None };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { email: Some("a@b.c".to_string()), phone: None, username: Some("rod".to_string()) };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], RodValidateError::Struct(StructValidation::Exclusive(_, present)) if present == &["email", "username"]));
+    assert_eq!(errors[0].to_string(), "Expected at most one of `email`, `phone`, `username` to be present, got `email`, `username`");
+}
+
+#[test]
+fn test_any_of() {
+    #[derive(RodValidate)]
+    #[rod(any_of(email, phone, username))]
+    struct Test {
+        #[rod(Option { optional, String })]
+        email: Option<String>,
+        #[rod(Option { optional, String })]
+        phone: Option<String>,
+        #[rod(Option { optional, String })]
+        username: Option<String>,
+    }
+    let test = Test { email: None, phone: Some("555".to_string()), username: None };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { email: Some("a@b.c".to_string()), phone: Some("555".to_string()), username: None };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { email: None, phone: None, username: None };
+    let err = test.validate().unwrap_err();
+    assert!(matches!(&err, RodValidateError::Struct(StructValidation::AnyOf(group)) if group == &["email", "phone", "username"]));
+    assert_eq!(err.to_string(), "Expected at least one of `email`, `phone`, `username` to be present");
+}
+
+#[test]
+fn test_multiple_checks() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(
+            String { length: 8..=64 },
+            ?"password needs a digit" check = |s| s.chars().any(|c| c.is_ascii_digit()),
+            ?"password needs an uppercase letter" check = |s| s.chars().any(|c| c.is_ascii_uppercase()),
+            fail_if = |s| s.contains(' '),
+            fail_if = |s| s.to_lowercase().contains("password")
+        )]
+        password: String,
+    }
+    let test = Test { password: "Hunter2hunter".to_string() };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { password: "hunter hunter".to_string() };
+    let errors: Vec<String> = test.validate_all().unwrap_err().into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors.len(), 3);
+    assert!(errors.contains(&"password needs a digit".to_string()));
+    assert!(errors.contains(&"password needs an uppercase letter".to_string()));
+    assert_eq!(test.validate().unwrap_err().to_string(), "password needs a digit");
+    let test = Test { password: "MyPassword1".to_string() };
+    assert!(matches!(test.validate().unwrap_err(), RodValidateError::CheckFailed(path) if path == "password"));
+}
+
+#[test]
+fn test_check_self() {
+    fn within_limit(this: &Test, value: &u64) -> bool {
+        *value <= this.limit
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(u64)]
+        limit: u64,
+        #[rod(u64 { size: 1.. }, ?"amount exceeds the balance" check_self = |this: &Self, amount| *amount <= this.balance)]
+        amount: u64,
+        #[rod(u64, check_self = within_limit)]
+        balance: u64,
+    }
+    let test = Test { limit: 100, amount: 10, balance: 50 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { limit: 40, amount: 60, balance: 50 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].to_string(), "amount exceeds the balance");
+    assert!(matches!(&errors[1], RodValidateError::CheckFailed(path) if path == "balance"));
+}
+
+mod validators {
+    use crate::prelude::*;
+
+    pub fn check_vat_number(vat: &String) -> Result<(), RodValidateError> {
+        if vat.len() > 2 && vat[..2].chars().all(|c| c.is_ascii_uppercase()) {
+            Ok(())
+        } else {
+            Err(RodValidateError::UserDefined(format!("`{}` is not a VAT number", vat)))
+        }
+    }
+
+    pub fn is_even(value: &u32) -> Result<(), RodValidateError> {
+        if value.is_multiple_of(2) { Ok(()) } else { Err(RodValidateError::CheckFailed("value".to_string())) }
+    }
+}
+
+mod predicates {
+    pub fn is_valid_slug(slug: &str) -> bool {
+        !slug.is_empty() && slug.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+
+    pub fn is_reserved(slug: &str) -> bool {
+        slug == "admin"
+    }
+}
+
+#[test]
+fn test_check_fn_path() {
+    fn has_matching_slug(test: &Test) -> bool {
+        test.slug.starts_with(&test.prefix)
+    }
+    #[derive(RodValidate)]
+    #[rod(check = has_matching_slug)]
+    struct Test {
+        #[rod(String { length: 1..=10 })]
+        prefix: String,
+        #[rod(String { length: 1..=20 }, check = predicates::is_valid_slug, fail_if = predicates::is_reserved)]
+        slug: String,
+    }
+    let test = Test { prefix: "my".to_string(), slug: "my-post-1".to_string() };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { prefix: "my".to_string(), slug: "My Post".to_string() };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::CheckFailed(path) if path == "slug"));
+    assert!(matches!(&errors[1], RodValidateError::CheckFailed(path) if path == "Test"));
+    let test = Test { prefix: "ad".to_string(), slug: "admin".to_string() };
+    assert!(matches!(test.validate().unwrap_err(), RodValidateError::CheckFailed(path) if path == "slug"));
+}
+
+#[test]
+fn test_with_validator() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(with = validators::check_vat_number)]
+        vat_number: String,
+        #[rod(String { length: 3..=10 }, with = validators::check_vat_number)]
+        other_vat_number: String,
+        #[rod(u32 { size: 1..=10 }, with = validators::is_even)]
+        value: u32,
+    }
+    let test = Test { vat_number: "DE123".to_string(), other_vat_number: "FR123".to_string(), value: 4 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { vat_number: "123".to_string(), other_vat_number: "fr".to_string(), value: 3 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 4);
+    let err = test.validate().unwrap_err();
+    assert_eq!(err.to_string(), "`123` is not a VAT number");
+}
+
+#[test]
+fn test_skip_if() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Skip)]
+        is_draft: bool,
+        #[rod(String { length: 1..=10 }, check = |s| !s.contains(' '), skip_if = |s: &Self| s.is_draft)]
+        title: String,
+        #[rod(u32 { size: 1..=5 }, skip_if = |s| s.is_draft)]
+        priority: u32,
+    }
+    let test = Test { is_draft: true, title: String::new(), priority: 0 };
+    assert!(test.validate_all().is_ok());
+    assert!(test.suggest_fixes().is_empty());
+    let test = Test { is_draft: false, title: String::from("a title"), priority: 0 };
+    assert_eq!(test.validate_all().unwrap_err().len(), 2);
+    let test = Test { is_draft: false, title: String::from("title"), priority: 3 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+}
+
+#[test]
+fn test_struct_check() {
+    #[derive(RodValidate)]
+    #[rod(check = |s: &Self| s.start < s.end, message: "start must precede end")]
+    #[rod(fail_if = |s| s.end - s.start > 100)]
+    struct Test {
+        #[rod(u32)]
+        start: u32,
+        #[rod(u32 { size: ..1000 })]
+        end: u32,
+    }
+    let test = Test { start: 1, end: 10 };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test { start: 10, end: 1 };
+    assert!(matches!(test.validate(), Err(RodValidateError::UserDefined(msg)) if msg == "start must precede end"));
+    // Struct-level rules run after the field validations
+    let test = Test { start: 0, end: 1000 };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::Size(path, ..)) if path == "end"));
+    assert!(matches!(&errors[1], RodValidateError::CheckFailed(path) if path == "Test"));
+
+    #[derive(RodValidate)]
+    #[rod(check = |s: &Self| !matches!(s, Shape::Circle(r) if *r == 0))]
+    enum Shape {
+        Circle(#[rod(u32)] u32),
+        Point,
+    }
+    assert!(Shape::Circle(1).validate().is_ok());
+    assert!(Shape::Point.validate().is_ok());
+    assert_eq!(Shape::Circle(0).validate().unwrap_err().to_string(), "Custom validation check failed for `Shape`");
+}
+
+#[test]
+fn test_eq_ne_field() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=16 })]
+        username: String,
+        #[rod(String { min_length: 8, ne_field: username })]
+        password: String,
+        #[rod(String { ?"passwords do not match" eq_field: password })]
+        password_confirm: String,
+        #[rod(u32 { eq_field: expected_version })]
+        version: u32,
+        #[rod(u32)]
+        expected_version: u32,
+    }
+    let test = Test {
+        username: "rod".to_string(),
+        password: "correct horse".to_string(),
+        password_confirm: "correct horse".to_string(),
+        version: 2,
+        expected_version: 2,
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        username: "password".to_string(),
+        password: "password".to_string(),
+        password_confirm: "passw0rd".to_string(),
+        version: 1,
+        expected_version: 2,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], RodValidateError::Struct(StructValidation::Compare(path1, "differ from", path2)) if path1 == "password" && path2 == "username"));
+    assert_eq!(errors[0].to_string(), "Expected `password` to differ from `username`");
+    assert_eq!(errors[1].to_string(), "passwords do not match");
+    assert_eq!(errors[2].to_string(), "Expected `version` to equal `expected_version`");
+}
+
+#[test]
+fn test_ordering_fields() {
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Date(u16, u8, u8);
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(u32 { le_field: max_capacity })]
+        capacity: u32,
+        #[rod(u32 { gt_field: min_capacity })]
+        max_capacity: u32,
+        #[rod(u32)]
+        min_capacity: u32,
+        #[rod(f64 { ?"discount cannot exceed the price" lt_field: price })]
+        discount: f64,
+        #[rod(f64 { ge_field: discount })]
+        price: f64,
+        #[rod(Date { lt_field: ends_at })]
+        starts_at: Date,
+        #[rod(Skip)]
+        ends_at: Date,
+    }
+    let test = Test {
+        capacity: 10,
+        max_capacity: 10,
+        min_capacity: 1,
+        discount: 5.0,
+        price: 20.0,
+        starts_at: Date(2024, 1, 1),
+        ends_at: Date(2024, 12, 31),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        capacity: 11,
+        max_capacity: 10,
+        min_capacity: 10,
+        discount: 20.0,
+        price: 20.0,
+        starts_at: Date(2025, 1, 1),
+        ends_at: Date(2024, 12, 31),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(&errors[0], RodValidateError::Struct(StructValidation::Compare(path1, "be at most", path2)) if path1 == "capacity" && path2 == "max_capacity"));
+    assert_eq!(errors[1].to_string(), "Expected `max_capacity` to be greater than `min_capacity`");
+    assert_eq!(errors[2].to_string(), "discount cannot exceed the price");
+    assert_eq!(errors[3].to_string(), "Expected `starts_at` to be less than `ends_at`");
+}
+
+#[test]
+fn test_iterable_sum_to() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: f64, sum_to: 1.0 ~ 1e-6 })]
+        weights: Vec<f64>,
+        #[rod(Iterable { item: f32, sum_to: 1 })]
+        probabilities: Vec<f32>,
+        #[rod(Iterable { item: u8, sum_to: 100 })]
+        percentages: Vec<u8>,
+    }
+    let test = Test {
+        weights: vec![0.1, 0.2, 0.3, 0.4],
+        probabilities: vec![0.1, 0.2, 0.7],
+        percentages: vec![50, 25, 25],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        weights: vec![0.5, 0.4999],
+        probabilities: vec![0.5],
+        percentages: vec![200, 100],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].to_string(), "Expected iterable at weights to sum to 1 ~ 0.000001, got 0.9999");
+    assert!(matches!(&errors[1], RodValidateError::Iterable(IterableValidation::Sum(path, actual, expected)) if path == "probabilities" && actual == "0.5" && expected == "1"));
+    assert_eq!(errors[2].to_string(), "Expected iterable at percentages to sum to 100, got an overflow");
+}
+
+#[test]
+fn test_iterable_sum_and_mean() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: u8, sum: 0..=100 })]
+        allocation: Vec<u8>,
+        #[rod(Iterable { item: f64, sum: 1 })]
+        weights: Vec<f64>,
+        #[rod(Iterable { item: u32, mean: 1..=5 })]
+        ratings: Vec<u32>,
+        #[rod(Iterable { item: f32, ?"scores must average 0.5" mean: 0.5 })]
+        scores: Vec<f32>,
+    }
+    let test = Test {
+        allocation: vec![40, 30, 30],
+        weights: vec![0.25, 0.75],
+        ratings: vec![],
+        scores: vec![0.25, 0.75],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        allocation: vec![60, 50],
+        weights: vec![0.5],
+        ratings: vec![6, 7],
+        scores: vec![1.0],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 4);
+    assert_eq!(errors[0].to_string(), "Expected iterable at allocation to sum to a value in the range 0..=100, got 110");
+    assert_eq!(errors[1].to_string(), "Expected iterable at weights to sum to 1, got 0.5");
+    assert!(matches!(&errors[2], RodValidateError::Iterable(IterableValidation::Mean(path, actual, expected)) if path == "ratings" && *actual == 6.5 && expected.min() == Some(&1.0) && expected.max() == Some(&5.0)));
+    assert!(matches!(&errors[3], RodValidateError::UserDefined(msg) if msg == "scores must average 0.5"));
+}
+
+#[test]
+fn test_option_iterable() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Option { Iterable { item: String { length: 1..=3 } }, allow_none: true })]
+        tags: Option<Vec<String>>,
+        #[rod(Option { Iterable { item: Option { i32 { size: 0..10 } }, length: 2 } })]
+        scores: Option<Vec<Option<i32>>>,
+    }
+    let test = Test {
+        tags: None,
+        scores: Some(vec![Some(1), Some(9)]),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        tags: Some(vec!["rust".to_string()]),
+        scores: None,
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Item(_, 0, _))));
+    assert!(matches!(&errors[1], RodValidateError::Option(OptionValidation::None(path, _)) if path == "scores"));
+}
+
+#[test]
+fn test_iterable_unique() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { unique, item: String })]
+        tags: Vec<String>,
+        #[rod(Iterable { item: u32, ?"ids must be unique" unique })]
+        ids: Vec<u32>,
+    }
+    let test = Test {
+        tags: vec!["a".to_string(), "b".to_string()],
+        ids: vec![],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        tags: vec!["a".to_string(), "b".to_string(), "c".to_string(), "b".to_string(), "a".to_string()],
+        ids: vec![1, 2, 1],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Duplicate(path, item, 1, 3)) if path == "tags" && item == "\"b\""));
+    assert_eq!(errors[0].to_string(), "Expected iterable at tags to have unique items, got \"b\" at indices 1 and 3");
+    assert_eq!(errors[1].to_string(), "ids must be unique");
+}
+
+#[test]
+fn test_iterable_unique_by() {
+    #[derive(RodValidate)]
+    struct User {
+        #[rod(String { format: Email })]
+        email: String,
+        #[rod(u32)]
+        id: u32,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { unique_by: |user| &user.email, item: User })]
+        users: Vec<User>,
+        #[rod(Iterable { item: User, ?"ids must be unique" unique_by: |user: &User| user.id % 10 })]
+        admins: Vec<User>,
+    }
+    let user = |email: &str, id| User { email: email.to_string(), id };
+    let test = Test {
+        users: vec![user("a@example.com", 1), user("b@example.com", 1)],
+        admins: vec![user("a@example.com", 1), user("a@example.com", 2)],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        users: vec![user("a@example.com", 1), user("b@example.com", 2), user("a@example.com", 3)],
+        admins: vec![user("a@example.com", 1), user("b@example.com", 11)],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::DuplicateKey(path, key, 0, 2)) if path == "users" && key == "\"a@example.com\""));
+    assert_eq!(errors[0].to_string(), "Expected iterable at users to have items with unique keys, got key \"a@example.com\" at indices 0 and 2");
+    assert_eq!(errors[1].to_string(), "ids must be unique");
+}
+
+#[test]
+fn test_iterable_sorted() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: u64, sorted: StrictAscending })]
+        timestamps: Vec<u64>,
+        #[rod(Iterable { item: f64, sorted: Descending })]
+        scores: Vec<f64>,
+        #[rod(Iterable { item: String, sorted: Ascending })]
+        names: Vec<String>,
+    }
+    let test = Test {
+        timestamps: vec![1, 2, 3],
+        scores: vec![9.5, 9.5, 7.0],
+        names: vec![],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        timestamps: vec![1, 2, 2],
+        scores: vec![9.5, f64::NAN],
+        names: vec!["b".to_string(), "a".to_string()],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].to_string(), "Expected iterable at timestamps to be in strictly ascending order, got 2 at index 2");
+    assert!(matches!(&errors[1], RodValidateError::Iterable(IterableValidation::Sorted(path, "descending", 1, item)) if path == "scores" && item == "NaN"));
+    assert!(matches!(&errors[2], RodValidateError::Iterable(IterableValidation::Sorted(path, "ascending", 1, _)) if path == "names"));
+}
+
+#[test]
+fn test_free_functions() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(i32 { size: 0..10 })]
+        field: i32,
+    }
+    let valid = Test { field: 5 };
+    let invalid = Test { field: 10 };
+    assert!(crate::validate(&valid).is_ok());
+    assert!(crate::validate(&invalid).is_err());
+    assert_eq!(crate::validate_all(&invalid).unwrap_err().len(), 1);
+    let values = [Test { field: 1 }, Test { field: 2 }, Test { field: 20 }, Test { field: 30 }];
+    assert!(crate::validate_ref_iter(&values[..2]).is_ok());
+    assert!(matches!(crate::validate_ref_iter(&values), Err((2, RodValidateError::Integer(_)))));
+    let mut validated = crate::validate_iter(values);
+    assert!(matches!(validated.next(), Some(Ok(Test { field: 1 }))));
+    assert!(matches!(validated.nth(1), Some(Err((Test { field: 20 }, errors))) if errors.len() == 1));
+    // Values are validated one at a time, so an endless stream works too
+    let invalid = crate::validate_iter((0..).map(|field| Test { field })).filter(Result::is_err).take(2).count();
+    assert_eq!(invalid, 2);
+}
+
+#[test]
+fn test_iterable_contains() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: String, contains: "admin", not_contains: "root" })]
+        roles: Vec<String>,
+        #[rod(Iterable { item: u16, ?"port 22 must stay closed" not_contains: 22 })]
+        ports: Vec<u16>,
+    }
+    let test = Test {
+        roles: vec!["user".to_string(), "admin".to_string()],
+        ports: vec![80, 443],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        roles: vec!["user".to_string(), "root".to_string()],
+        ports: vec![22],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].to_string(), "Expected iterable at roles to contain \"admin\"");
+    assert!(matches!(&errors[1], RodValidateError::Iterable(IterableValidation::NotContains(path, item, 1)) if path == "roles" && item == "\"root\""));
+    assert_eq!(errors[2].to_string(), "port 22 must stay closed");
+}
+
+#[test]
+fn test_min_max_length() {
+    const MAX_TAGS: usize = 3;
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { ?"username is too short" min_length: 3, ?"username is too long" max_length: 8 })]
+        username: String,
+        #[rod(Iterable { item: String, min_length: 1, max_length: MAX_TAGS })]
+        tags: Vec<String>,
+    }
+    let test = Test {
+        username: "rustacean".to_string(),
+        tags: vec![],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].to_string(), "username is too long");
+    assert!(matches!(&errors[1], RodValidateError::Iterable(IterableValidation::Length(path, 0, _)) if path == "tags"));
+    let test = Test {
+        username: "ab".to_string(),
+        tags: vec!["a".to_string(); 4],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].to_string(), "username is too short");
+    assert_eq!(errors[1].to_string(), "Expected iterable at tags to have length to be in the range ..=3, got 4");
+    let fixes = test.suggest_fixes();
+    assert!(fixes.is_empty());
+    let test = Test {
+        username: "rustacean".to_string(),
+        tags: vec!["a".to_string()],
+    };
+    assert!(matches!(&test.suggest_fixes()[..], [RodFix { path, value: RodFixValue::String(fixed) }] if path == "username" && fixed == "rustacea"));
+}
+
+#[test]
+fn test_iterable_item_index() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: String { length: 2..=5 } })]
+        tags: Vec<String>,
+        #[rod(Iterable { item: Iterable { item: i32 { sign: Positive } } })]
+        matrix: Vec<Vec<i32>>,
+    }
+    let test = Test {
+        tags: vec!["ok".to_string(), "fine".to_string(), "much too long".to_string()],
+        matrix: vec![vec![1, 2], vec![3, -4]],
+    };
+    assert!(matches!(test.validate(), Err(RodValidateError::Iterable(IterableValidation::Item(path, 2, _))) if path == "tags"));
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    let RodValidateError::Iterable(error) = &errors[0] else { panic!("{}", errors[0]) };
+    assert_eq!(error.path(), "tags[2]");
+    let RodValidateError::Iterable(IterableValidation::Item(path, 1, inner)) = &errors[1] else { panic!("{}", errors[1]) };
+    assert_eq!(path, "matrix");
+    assert!(matches!(inner.as_ref(), RodValidateError::Iterable(IterableValidation::Item(path, 1, _)) if path == "matrix[1]"));
+    assert_eq!(
+        errors[1].to_string(),
+        "Invalid item at matrix[1]: Invalid item at matrix[1][1]: Expected `matrix[1][1]` to be an integer with sign Positive, got -4"
+    );
+}
+
+#[test]
+fn test_iterable_positional_items() {
+    #[derive(RodValidate)]
+    struct Test {
+        // A CSV-like row of name, country code and city
+        #[rod(Iterable {
+            length: 3,
+            items: [
+                String { length: 1..=20 },
+                String { length: 2 },
+                String,
+            ],
+        })]
+        row: Vec<String>,
+        #[rod(Iterable { item: i32 { sign: Nonnegative }, items: [i32 { size: 0..10 }] })]
+        scores: Vec<i32>,
+    }
+    let test = Test {
+        row: vec!["Ada".to_string(), "UK".to_string(), "London".to_string()],
+        scores: vec![5, 100],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        row: vec!["Ada".to_string(), "GBR".to_string(), "London".to_string()],
+        scores: vec![10, -1],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Item(path, 1, inner)) if path == "row" && matches!(inner.as_ref(), RodValidateError::String(StringValidation::Length(..)))));
+    assert!(matches!(&errors[1], RodValidateError::Iterable(IterableValidation::Item(path, 0, _)) if path == "scores"));
+    assert!(matches!(&errors[2], RodValidateError::Iterable(IterableValidation::Item(path, 1, _)) if path == "scores"));
+}
+
+#[test]
+fn test_iterable_of_custom_types() {
+    #[derive(RodValidate)]
+    struct Member {
+        #[rod(String { length: 1..=10 })]
+        name: String,
+        #[rod(u8 { size: 18..=100 }, tags("strict"))]
+        age: u8,
+    }
+    #[derive(RodValidate)]
+    struct Team {
+        #[rod(Iterable { item: Member }, tags("strict"))]
+        members: Vec<Member>,
+    }
+    let team = Team {
+        members: vec![
+            Member { name: "Ada".to_string(), age: 36 },
+            Member { name: String::new(), age: 12 },
+        ],
+    };
+    let errors = team.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|error| matches!(error, RodValidateError::Iterable(IterableValidation::Item(path, 1, inner)) if path == "members" && matches!(inner.as_ref(), RodValidateError::String(StringValidation::Length(path, ..)) if path == "members[1].name"))));
+    assert!(errors.iter().any(|error| error.to_string() == "Invalid item at members[1]: Expected `members[1].age` to be an integer to be in the range 18..=100, got 12"));
+    let errors = team.validate_all_tagged(&["strict"]).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Item(path, 1, inner)) if path == "members" && matches!(inner.as_ref(), RodValidateError::Integer(_))));
+}
+
+#[test]
+fn test_iterable_length_by_count() {
+    // An iterable without `len()`
+    struct Chain<T>(Vec<T>, Vec<T>);
+    impl<'a, T> IntoIterator for &'a Chain<T> {
+        type Item = &'a T;
+        type IntoIter = std::iter::Chain<std::slice::Iter<'a, T>, std::slice::Iter<'a, T>>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.iter().chain(self.1.iter())
+        }
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: String { length: 1.. }, length_by: Count, length: 2..=3 })]
+        names: Chain<String>,
+    }
+    let test = Test {
+        names: Chain(vec!["a".to_string()], vec!["b".to_string()]),
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        names: Chain(vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), String::new()]),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Length(path, 4, _)) if path == "names"));
+    assert!(matches!(&errors[1], RodValidateError::Iterable(IterableValidation::Item(path, 3, _)) if path == "names"));
+}
+
+#[test]
+fn test_iterable_first_last() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable {
+            item: String { length: 1..=12 },
+            first: Literal { value: "origin" },
+            ?"Routes must end at the destination"
+            last: Literal { value: "destination" },
+        })]
+        waypoints: Vec<String>,
+        #[rod(Iterable { first: u32 { size: 0..=0 } })]
+        offsets: Vec<u32>,
+    }
+    let test = Test {
+        waypoints: vec!["origin".to_string(), "bridge".to_string(), "destination".to_string()],
+        offsets: vec![],
+    };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        waypoints: vec!["start".to_string(), "a very long waypoint".to_string(), "end".to_string()],
+        offsets: vec![5, 0],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 4, "{}", errors);
+    assert!(matches!(&errors[0], RodValidateError::Iterable(IterableValidation::Item(path, 0, inner)) if path == "waypoints" && matches!(inner.as_ref(), RodValidateError::Literal(_))));
+    assert_eq!(errors[1].to_string(), "Invalid item at waypoints[2]: Routes must end at the destination");
+    assert!(matches!(&errors[2], RodValidateError::Iterable(IterableValidation::Item(path, 1, _)) if path == "waypoints"));
+    assert!(matches!(&errors[3], RodValidateError::Iterable(IterableValidation::Item(path, 0, _)) if path == "offsets"));
+}
+
+#[test]
+fn test_nested_error_paths() {
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(String { length: 5 })]
+        zip: String,
+    }
+    #[derive(RodValidate)]
+    struct Profile {
+        #[rod(Iterable { item: Address })]
+        addresses: Vec<Address>,
+        #[rod(Option { inner: Tuple (i32 { sign: Positive }, String { length: 1..=3 }) })]
+        location: Option<(i32, String)>,
+    }
+    #[derive(RodValidate)]
+    struct User {
+        profile: Profile,
+    }
+    let address = |zip: &str| Address { zip: zip.to_string() };
+    let user = User {
+        profile: Profile {
+            addresses: vec![address("12345"), address("54321"), address("123")],
+            location: Some((-1, "London".to_string())),
+        },
+    };
+    let errors = user.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3, "{}", errors);
+    assert!(errors.iter().any(|error| matches!(error, RodValidateError::Iterable(IterableValidation::Item(path, 2, inner)) if path == "profile.addresses" && matches!(inner.as_ref(), RodValidateError::String(StringValidation::Length(path, ..)) if path == "profile.addresses[2].zip"))));
+    assert!(errors.iter().any(|error| matches!(error, RodValidateError::Integer(IntegerValidation::Sign(path, _, _)) if path == "profile.location.0")));
+    assert!(errors.iter().any(|error| error.to_string() == "Expected `profile.location.1` to have length to be in the range 1..=3, got 6"));
+    let error = address("1").validate().unwrap_err().with_path_prefix("billing");
+    assert!(matches!(error, RodValidateError::String(StringValidation::Length(ref path, ..)) if path == "billing.zip"));
+}
+
+#[test]
+fn test_container_impls() {
+    use std::collections::HashMap;
+
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(String { trim, length: 5 })]
+        zip: String,
+    }
+    #[derive(RodValidate)]
+    struct User {
+        addresses: Vec<Address>,
+        billing: Option<Address>,
+        home: Box<Address>,
+        pair: [Address; 2],
+        by_name: HashMap<String, Address>,
+    }
+    let address = |zip: &str| Address { zip: zip.to_string() };
+    let mut user = User {
+        addresses: vec![address("12345"), address("123")],
+        billing: None,
+        home: Box::new(address("1234")),
+        pair: [address("123456"), address("54321")],
+        by_name: HashMap::from([("work".to_string(), address("12"))]),
+    };
+    let errors = user.validate_all().unwrap_err();
+    let paths = errors.iter().map(|error| error.path().unwrap()).collect::<Vec<_>>();
+    assert_eq!(paths, ["addresses[1].zip", "home.zip", "pair[0].zip", "by_name[work].zip"], "{}", errors);
+    assert_eq!(user.validate_all_limited(2).unwrap_err().len(), 2);
+
+    user.billing = Some(address("1"));
+    assert!(matches!(user.validate_field("billing.zip"), Err(errors) if errors.len() == 1));
+
+    user.addresses[1] = address(" 12345 ");
+    user.addresses.sanitize();
+    assert!(user.addresses.validate().is_ok());
+
+    let schema = User::rod_schema();
+    assert_eq!(schema.field("addresses").unwrap().ty.describe_type(), "iterable<Address>");
+    assert_eq!(schema.field("billing").unwrap().ty.describe_type(), "option<Address>");
+    assert_eq!(schema.field("home").unwrap().ty.describe_type(), "Address");
+}
+
+#[test]
+fn test_primitive_impls() {
+    use std::path::PathBuf;
+
+    #[derive(RodValidate)]
+    struct Event {
+        #[rod(String { length: 1..=10 })]
+        name: String,
+        id: u64,
+        score: f64,
+        public: bool,
+        note: String,
+        source: &'static str,
+        path: PathBuf,
+        labels: Vec<String>,
+        retries: Option<u8>,
+    }
+    let event = Event {
+        name: "launch".to_string(),
+        id: 7,
+        score: f64::NAN,
+        public: false,
+        note: String::new(),
+        source: "",
+        path: PathBuf::new(),
+        labels: vec![String::new()],
+        retries: None,
+    };
+    assert!(event.validate_all().is_ok());
+    assert!(Event { name: String::new(), ..event }.validate().is_err());
+    assert!(42i32.validate().is_ok());
+    assert!("".validate_all().is_ok());
+    let schema = Event::rod_schema();
+    assert!(matches!(schema.field("note").unwrap().ty, crate::schema::TypeSchema::Skip));
+    assert_eq!(schema.field("labels").unwrap().ty.describe_type(), "iterable<any>");
+}
+
+#[test]
+fn test_reference_impls() {
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(String { length: 5 })]
+        zip: String,
+    }
+    #[derive(RodValidate)]
+    struct Shipment<'a> {
+        #[rod(String { length: 1.. })]
+        id: String,
+        from: &'a Address,
+        to: &'a mut Address,
+        stops: &'a [Address],
+    }
+    let from = Address { zip: "12345".to_string() };
+    let mut to = Address { zip: "123".to_string() };
+    let stops = [Address { zip: "54321".to_string() }, Address { zip: "1".to_string() }];
+    let shipment = Shipment { id: "s1".to_string(), from: &from, to: &mut to, stops: &stops };
+    let errors = shipment.validate_all().unwrap_err();
+    let paths = errors.iter().map(|error| error.path().unwrap()).collect::<Vec<_>>();
+    assert_eq!(paths, ["to.zip", "stops[1].zip"], "{}", errors);
+    assert!((&&from).validate().is_ok());
+}
+
+#[test]
+fn test_trait_objects() {
+    use std::sync::Arc;
+
+    trait Shape: RodValidate {
+        fn area(&self) -> f64;
+    }
+    #[derive(RodValidate)]
+    struct Circle {
+        #[rod(f64 { size: 0.0.. })]
+        radius: f64,
+    }
+    impl Shape for Circle {
+        fn area(&self) -> f64 {
+            std::f64::consts::PI * self.radius * self.radius
+        }
+    }
+    #[derive(RodValidate)]
+    struct Square {
+        #[rod(f64 { size: 0.0.. })]
+        side: f64,
+    }
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.side * self.side
+        }
+    }
+    #[derive(RodValidate)]
+    struct Drawing {
+        shapes: Vec<Box<dyn Shape>>,
+        background: Option<Box<dyn Shape>>,
+        shared: Arc<dyn Shape>,
+    }
+    let drawing = Drawing {
+        shapes: vec![Box::new(Circle { radius: 1.0 }), Box::new(Square { side: -2.0 })],
+        background: Some(Box::new(Circle { radius: -1.0 })),
+        shared: Arc::new(Square { side: 3.0 }),
+    };
+    let errors = drawing.validate_all().unwrap_err();
+    let paths = errors.iter().map(|error| error.path().unwrap()).collect::<Vec<_>>();
+    assert_eq!(paths, ["shapes[1].side", "background.radius"], "{}", errors);
+    assert_eq!(drawing.shapes.iter().map(|shape| shape.area()).filter(|area| *area > 0.0).count(), 2);
+    let shapes: Vec<&dyn Shape> = vec![&Circle { radius: 2.0 }, drawing.shared.as_ref()];
+    assert!(shapes.validate().is_ok());
+    assert_eq!(Drawing::rod_schema().field("shapes").unwrap().ty.describe_type(), "iterable<dyn Shape>");
+}
+
+#[test]
+fn test_structured_error_data() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=12 })]
+        username: String,
+        #[rod(u64 { size: 18..=120 })]
+        age: u64,
+        #[rod(i32 { digits: 4 })]
+        year: i32,
+        #[rod(f64 { size: (1.5, tolerance: 0.1) })]
+        ratio: f64,
+        #[rod(Iterable { length: ..3, item: String { length: 5 } })]
+        tags: Vec<String>,
+        #[rod(String, check = |s| !s.is_empty())]
+        note: String,
+    }
+    let test = Test {
+        username: "ab".to_string(),
+        age: 17,
+        year: 999,
+        ratio: 2.0,
+        tags: vec!["rust".to_string(), "rodeo".to_string(), "rusty".to_string()],
+        note: String::new(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 7, "{}", errors);
+
+    let RodValidateError::String(error) = &errors[0] else { panic!("{}", errors[0]) };
+    assert_eq!(error.path(), "username");
+    assert_eq!(error.value(), "ab");
+    let length = error.expected_length().unwrap();
+    assert_eq!((length.min(), length.max()), (Some(&3), Some(&12)));
+    assert!(matches!(length, ExpectedBounds::Range { inclusive: true, .. }));
+
+    let RodValidateError::Integer(error) = &errors[1] else { panic!("{}", errors[1]) };
+    assert!(matches!(error.value(), Some(Integer::Positive(17))));
+    assert!(matches!(error.expected_size().unwrap().min(), Some(Integer::Positive(18))));
+    assert_eq!(errors[1].to_string(), "Expected `age` to be an integer to be in the range 18..=120, got 17");
+
+    let RodValidateError::Integer(error) = &errors[2] else { panic!("{}", errors[2]) };
+    assert!(matches!(error.expected_digits(), Some(ExpectedBounds::Exact(4))));
+
+    let RodValidateError::Float(error) = &errors[3] else { panic!("{}", errors[3]) };
+    assert!(matches!(error, FloatValidation::Approx(_, actual, expected, tolerance) if *actual == 2.0 && *expected == 1.5 && *tolerance == 0.1));
+    assert_eq!(error.value(), Some(2.0));
+
+    let RodValidateError::Iterable(error) = &errors[4] else { panic!("{}", errors[4]) };
+    assert_eq!(error.actual_length(), Some(3));
+    assert!(matches!(error.expected_length(), Some(ExpectedBounds::Range { start: None, end: Some(3), inclusive: false })));
+    let RodValidateError::Iterable(error) = &errors[5] else { panic!("{}", errors[5]) };
+    assert_eq!(error.index(), Some(0));
+    assert!(matches!(error.item_error(), Some(RodValidateError::String(error)) if error.path() == "tags[0]"));
+
+    assert_eq!(errors[5].path().as_deref(), Some("tags[0]"));
+    assert_eq!(errors[6].path().as_deref(), Some("note"));
+    assert_eq!(RodValidateError::UserDefined("Invalid".to_string()).path(), None);
+}
+
+#[test]
+fn test_error_codes_and_params() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=12 })]
+        username: String,
+        #[rod(i32 { sign: Positive })]
+        balance: i32,
+        #[rod(String, check = |s| !s.is_empty())]
+        note: String,
+    }
+    let test = Test { username: "ab".to_string(), balance: -5, note: String::new() };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].code(), "string.length");
+    let params = errors[0].params();
+    assert!(matches!(params.get("value"), Some(ParamValue::String(value)) if value == "ab"));
+    assert!(matches!(params.get("length"), Some(ParamValue::Integer(Integer::Positive(2)))));
+    assert!(matches!(params.get("min"), Some(ParamValue::Integer(Integer::Positive(3)))));
+    assert!(matches!(params.get("max"), Some(ParamValue::Integer(Integer::Positive(12)))));
+    assert!(matches!(params.get("inclusive"), Some(ParamValue::Bool(true))));
+    assert_eq!(errors[1].code(), "integer.sign");
+    assert_eq!(errors[1].params().iter().map(|(name, value)| format!("{}={}", name, value)).collect::<Vec<_>>(), ["value=-5", "sign=Positive"]);
+    assert_eq!(errors[2].code(), "check_failed");
+    assert!(errors[2].params().is_empty());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn test_serialize_errors() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 5 })]
+        zip: String,
+        #[rod(Option { ?"a country is required" required: true })]
+        country: Option<String>,
+    }
+    let test = Test { zip: "123".to_string(), country: None };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        serde_json::json!([
+            {
+                "path": "zip",
+                "code": "string.length",
+                "message": "Expected `zip` to have length to be exactly 5, got 3",
+                "params": { "value": "123", "length": 3, "exact": 5 },
+            },
+            {
+                "path": null,
+                "code": "user_defined",
+                "message": "a country is required",
+                "params": {},
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_error_list_to_map() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=12, starts_with: "u_" })]
+        username: String,
+        #[rod(Iterable { item: i32 { sign: Positive } })]
+        scores: Vec<i32>,
+        #[rod(Option { ?"a nickname is required" required: true })]
+        nickname: Option<String>,
+    }
+    let test = Test { username: "ab".to_string(), scores: vec![1, -2], nickname: None };
+    let errors = test.validate_all().unwrap_err();
+    let map = errors.to_map();
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["username"].len(), 2);
+    assert!(matches!(&map["username"][1], RodValidateError::String(StringValidation::StartsWith(..))));
+    assert_eq!(map["scores[1]"].len(), 1);
+    let messages = errors.to_message_map();
+    assert_eq!(messages[""], ["a nickname is required"]);
+    assert_eq!(messages["username"][0], "Expected `username` to have length to be in the range 3..=12, got 2");
+}
+
+#[test]
+fn test_error_list_iteration() {
+    #[derive(RodValidate)]
+    struct Inner {
+        #[rod(i32 { sign: Positive })]
+        first: i32,
+        #[rod(i32 { sign: Positive })]
+        second: i32,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        inner: Inner,
+    }
+    let test = Test { inner: Inner { first: -1, second: -2 } };
+    let errors = test.validate_all().unwrap_err();
+    let paths = (&errors).into_iter().map(|error| error.path().unwrap()).collect::<Vec<_>>();
+    assert_eq!(paths, ["inner.first", "inner.second"]);
+    assert_eq!(errors.len(), 2, "iterating by reference does not drain the list");
+
+    let mut merged: RodValidateErrorList = errors.clone().into_iter().filter(|error| error.path().as_deref() == Some("inner.second")).collect();
+    merged.extend(errors.clone());
+    assert_eq!(merged.len(), 3);
+    assert_eq!(merged[0].path().as_deref(), Some("inner.second"));
+    assert_eq!(merged[1].path().as_deref(), Some("inner.first"));
+
+    let list = RodValidateErrorList::from(vec![RodValidateError::UserDefined("Invalid".to_string())]);
+    let errors: Vec<RodValidateError> = list.into();
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_message_fn() {
+    mod messages {
+        pub fn describe_quantity(value: &u32) -> String {
+            format!("{} items cannot be ordered", value)
+        }
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 10, starts_with: "ord_" }, message_fn = |value| format!("bad id: {}", value))]
+        id: String,
+        #[rod(u32 { size: 1..=99 }, message_fn = messages::describe_quantity)]
+        quantity: u32,
+        #[rod(Iterable { ?"too many tags" length: ..=2, item: String { length: 1..=5 } }, message_fn = |tags| format!("bad tags: {}", tags.join(", ")))]
+        tags: Vec<String>,
+    }
+    let test = Test { id: "ord_123456".to_string(), quantity: 3, tags: vec!["new".to_string()] };
+    assert!(test.validate().is_ok(), "{}", test.validate().unwrap_err());
+    let test = Test {
+        id: "123".to_string(),
+        quantity: 100,
+        tags: vec!["a".to_string(), "b".to_string(), "toolong".to_string()],
+    };
+    let errors = test.validate_all().unwrap_err();
+    let messages = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+    assert_eq!(messages, [
+        "bad id: 123",
+        "bad id: 123",
+        "100 items cannot be ordered",
+        "too many tags",
+        "bad tags: a, b, toolong",
+    ]);
+    assert_eq!(test.validate().unwrap_err().to_string(), "bad id: 123");
+}
+
+#[test]
+fn test_message_key() {
+    fn translator(key: &str, params: &Params) -> String {
+        match key {
+            "errors.user.name.too_short" => format!("Le nom doit contenir au moins {} caractères", params.get("min").unwrap()),
+            _ => format!("missing translation: {}", key),
+        }
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=20 }, message_key: "errors.user.name.too_short")]
+        name: String,
+        #[rod(u8 { ?"too old" size: ..=120, sign: Positive }, message_key: "errors.user.age")]
+        age: u8,
+    }
+    crate::set_translator(translator);
+    let test = Test { name: "Jo".to_string(), age: 0 };
+    let errors = test.validate_all().unwrap_err();
+    let messages = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+    assert_eq!(messages, [
+        "Le nom doit contenir au moins 3 caractères",
+        "missing translation: errors.user.age",
+    ]);
+    let test = Test { name: "John".to_string(), age: 130 };
+    assert_eq!(test.validate().unwrap_err().to_string(), "too old");
+}
+
+#[test]
+fn test_warnings() {
+    #[derive(RodValidate)]
+    struct Password {
+        #[rod(String { max_length: 64, ?warn "short passwords are easy to guess" min_length: 12 })]
+        value: String,
+    }
+    #[derive(RodValidate)]
+    struct Signup {
+        #[rod(String { length: 3..=20 }, ?warn "names with digits are hard to read" fail_if = |name| name.chars().any(|c| c.is_ascii_digit()))]
+        name: String,
+        password: Password,
+        #[rod(Iterable { item: String { ?warn "long tags are cut off in listings" length: ..=8 } })]
+        tags: Vec<String>,
+    }
+    let test = Signup {
+        name: "user1".to_string(),
+        password: Password { value: "hunter22".to_string() },
+        tags: vec!["rust".to_string(), "programming".to_string()],
+    };
+    assert!(test.validate().is_ok());
+    assert!(test.validate_all().is_ok());
+    let report = test.validate_report();
+    assert!(report.is_valid());
+    let warnings = report.warnings.iter().map(|warning| warning.to_string()).collect::<Vec<_>>();
+    assert_eq!(warnings, [
+        "names with digits are hard to read",
+        "short passwords are easy to guess",
+        "long tags are cut off in listings",
+    ]);
+    let test = Signup {
+        name: "u".to_string(),
+        password: Password { value: "hunter22".to_string() },
+        tags: vec![],
+    };
+    let report = crate::validate_report(&test);
+    assert!(!report.is_valid());
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.warnings.len(), 1);
+    assert!(report.into_result().is_err());
+}
+
+#[test]
+fn test_validate_all_limited() {
+    #[derive(RodValidate)]
+    struct Item {
+        #[rod(String { length: 1..=5 })]
+        name: String,
+        #[rod(u32 { size: 1..=10 })]
+        quantity: u32,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Iterable { item: u32 { size: ..=100 } })]
+        values: Vec<u32>,
+        item: Item,
+    }
+    let test = Test {
+        values: vec![101, 102, 103],
+        item: Item { name: "toolong".to_string(), quantity: 0 },
+    };
+    assert_eq!(test.validate_all().unwrap_err().len(), 5);
+    let errors = test.validate_all_limited(2).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.is_truncated());
+    assert!(errors.to_string().starts_with("Got at least 2 errors"));
+    let errors = crate::validate_all_limited(&test, 5).unwrap_err();
+    assert_eq!(errors.len(), 5);
+    assert!(!errors.is_truncated());
+    // The errors of the nested value fill the limit exactly, but it has more
+    let test = Test { values: vec![], item: Item { name: "toolong".to_string(), quantity: 0 } };
+    let errors = test.validate_all_limited(1).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.is_truncated());
+    assert!(test.item.validate_all_limited(0).unwrap_err().is_truncated());
+}
+
+#[test]
+fn test_sensitive() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 12..=64, includes: "@" }, sensitive)]
+        password: String,
+        #[rod(Option { String { starts_with: "tok_" } }, sensitive)]
+        token: Option<String>,
+        #[rod(Iterable { item: Literal { value: "1234" } }, sensitive)]
+        pins: Vec<&'static str>,
+        #[rod(String { length: 3..=20 })]
+        name: String,
+    }
+    let test = Test {
+        password: "hunter2".to_string(),
+        token: Some("secret".to_string()),
+        pins: vec!["9999"],
+        name: "ab".to_string(),
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 5);
+    for error in errors.iter().take(4) {
+        assert!(!error.to_string().contains("hunter2") && !error.to_string().contains("secret") && !error.to_string().contains("9999"), "{}", error);
+    }
+    assert!(matches!(&errors[0], RodValidateError::String(error) if error.value() == REDACTED && error.actual_length() == Some(7)));
+    assert_eq!(errors[0].to_string(), "Expected `password` to have length to be in the range 12..=64, got 7");
+    assert_eq!(errors[1].to_string(), "Expected `password` to include @, got <redacted>");
+    assert!(matches!(&errors[4], RodValidateError::String(error) if error.value() == "ab"));
+
+    #[derive(RodValidate)]
+    struct Login {
+        #[rod(String { length: 4..=8 }, sensitive)]
+        password: String,
+        #[rod(String { length: 4..=8 })]
+        username: String,
+    }
+    let login = Login { password: "hunter2-hunter2".to_string(), username: "ferris-the-crab".to_string() };
+    let fixes = login.suggest_fixes();
+    assert_eq!(fixes.len(), 1);
+    assert_eq!(fixes[0].path, "username");
+    assert!(fixes.iter().all(|fix| !fix.to_string().contains("hunter2")));
+}
+
+#[test]
+fn test_error_equality() {
+    use std::collections::HashSet;
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=5 })]
+        name: String,
+        #[rod(f64 { size: 0.0..=1.0 })]
+        ratio: f64,
+        #[rod(Iterable { item: u8 { size: ..=9 } })]
+        digits: Vec<u8>,
+    }
+    let test = Test { name: "ab".to_string(), ratio: f64::NAN, digits: vec![1, 10] };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(errors[0], RodValidateError::String(StringValidation::Length("name".to_string(), "ab".to_string(), 2, ExpectedBounds::from_range(&(3..=5)))));
+    assert_eq!(errors[1], RodValidateError::Float(FloatValidation::Size("ratio".to_string(), f64::NAN, ExpectedBounds::from_range(&(0.0..=1.0)))));
+    assert_eq!(errors[2], RodValidateError::Iterable(IterableValidation::Item(
+        "digits".to_string(),
+        1,
+        Box::new(RodValidateError::Integer(IntegerValidation::Size("digits[1]".to_string(), Integer::from(10u8), Box::new(ExpectedBounds::from_range(&(..=Integer::from(9u8))))))),
+    )));
+    assert_ne!(errors[0], RodValidateError::String(StringValidation::Length("name".to_string(), "abc".to_string(), 3, ExpectedBounds::from_range(&(3..=5)))));
+    assert_eq!(errors, test.validate_all().unwrap_err());
+    let unique = errors.clone().into_iter().chain(test.validate_all().unwrap_err()).collect::<HashSet<_>>();
+    assert_eq!(unique.len(), 3);
+}
+
+#[test]
+fn test_error_list_rendering() {
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { length: 3..=5 })]
+        name: String,
+        #[rod(u8 { size: 1..=9 })]
+        digit: u8,
+    }
+    let errors = Test { name: "ab".to_string(), digit: 10 }.validate_all().unwrap_err();
+    assert_eq!(errors.to_string().lines().count(), 4);
+    assert_eq!(
+        errors.to_compact_string(),
+        "Got 2 errors while validating: [Expected `name` to have length to be in the range 3..=5, got 2; Expected `digit` to be an integer to be in the range 1..=9, got 10]"
+    );
+    assert_eq!(RodValidateErrorList::new().to_compact_string(), "No validation errors");
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn test_error_list_to_json() {
+    let errors = RodValidateErrorList::from(vec![RodValidateError::UserDefined("Invalid".to_string())]);
+    assert_eq!(errors.to_json(), r#"[{"path":null,"code":"user_defined","message":"Invalid","params":{}}]"#);
+}
+
+#[test]
+fn test_into_field_errors() {
+    #[derive(RodValidate)]
+    #[rod(check = |s: &Self| s.name != "admin", message: "Reserved name")]
+    struct Test {
+        #[rod(String { length: 3..=5, includes: "a" })]
+        name: String,
+        #[rod(u8 { size: 1..=9 })]
+        digit: u8,
+    }
+    let errors = Test { name: "bb".to_string(), digit: 10 }.validate_all().unwrap_err();
+    assert_eq!(errors.status(), 422);
+    let fields = errors.into_field_errors();
+    assert_eq!(fields.status(), 422);
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields.get("name").map(<[String]>::len), Some(2));
+    assert_eq!(fields.get("digit"), Some(&["Expected `digit` to be an integer to be in the range 1..=9, got 10".to_string()][..]));
+    assert_eq!(fields.iter().map(|(field, _)| field.as_str()).collect::<Vec<_>>(), ["digit", "name"]);
+
+    let errors = Test { name: "admin".to_string(), digit: 1 }.validate_all().unwrap_err();
+    assert_eq!(errors.into_field_errors().get(""), Some(&["Reserved name".to_string()][..]));
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn test_field_errors_to_json() {
+    let mut fields = RodFieldErrors::new();
+    fields.push("name".to_string(), "Too short".to_string());
+    fields.push("name".to_string(), "Missing an `a`".to_string());
+    assert_eq!(serde_json::to_string(&fields).unwrap(), r#"{"name":["Too short","Missing an `a`"]}"#);
+}
+
+#[test]
+fn test_error_list_merge_dedup_retain() {
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(String { length: 5 })]
+        zip: String,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(Address)]
+        address: Address,
+        #[rod(Iterable { length: ..=1, item: String { length: 5 } })]
+        addresses: Vec<String>,
+    }
+    let test = Test { address: Address { zip: "123".to_string() }, addresses: vec!["1".to_string(), "2".to_string()] };
+    let mut errors = test.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 4);
+    errors.merge(test.validate_all().unwrap_err());
+    errors.merge(RodValidateErrorList::from(vec![RodValidateError::UserDefined("Invalid".to_string())]));
+    assert_eq!(errors.len(), 9);
+    errors.dedup();
+    assert_eq!(errors.len(), 5);
+    assert_eq!(errors.iter().last().and_then(RodValidateError::path), None);
+
+    let mut address = errors.clone();
+    address.retain_paths("address");
+    assert_eq!(address.iter().map(|error| error.path().unwrap()).collect::<Vec<_>>(), ["address.zip"]);
+    let mut addresses = errors.clone();
+    addresses.retain_paths("addresses");
+    assert_eq!(addresses.iter().map(|error| error.path().unwrap()).collect::<Vec<_>>(), ["addresses", "addresses[0]", "addresses[1]"]);
+    errors.retain_paths("");
+    assert_eq!(errors.len(), 5);
+
+    let mut truncated = RodValidateErrorList::new();
+    truncated.set_truncated(true);
+    let mut errors = RodValidateErrorList::new();
+    errors.merge(truncated);
+    assert!(errors.is_truncated());
+}
+
+#[test]
+fn test_rename() {
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(rename = "zipCode", String { length: 5 })]
+        zip_code: String,
+    }
+    #[derive(RodValidate)]
+    #[rod(any_of(nick_name, email))]
+    struct Test {
+        #[rod(rename = "userName", String { length: 3..=10 }, check = |s| !s.contains(' '))]
+        user_name: String,
+        #[rod(Option { required: false, inner: String { length: 1.. } })]
+        nick_name: Option<String>,
+        #[rod(rename = "emailAddress", Option { required: false, inner: String { length: 1.. } })]
+        email: Option<String>,
+        #[rod(rename = "homeAddress", Address)]
+        home_address: Address,
+        #[rod(rename = "tags", Iterable { item: String { length: 1.. } })]
+        labels: Vec<String>,
+    }
+    let test = Test {
+        user_name: "a b".to_string(),
+        nick_name: None,
+        email: None,
+        home_address: Address { zip_code: "123".to_string() },
+        labels: vec!["".to_string()],
+    };
+    let errors = test.validate_all().unwrap_err();
+    assert_eq!(
+        errors.iter().map(|error| error.path().unwrap_or_default()).collect::<Vec<_>>(),
+        ["userName", "homeAddress.zipCode", "tags[0]", "nick_name"]
+    );
+    assert_eq!(errors[0].to_string(), "Custom validation check failed for `userName`");
+    assert!(matches!(&errors[3], RodValidateError::Struct(StructValidation::AnyOf(fields)) if fields == &["nick_name", "emailAddress"]));
+}
+
+#[test]
+fn test_rename_cross_field() {
+    #[derive(RodValidate)]
+    #[rod(same_length(item_ids, item_quantities))]
+    struct Order {
+        #[rod(rename = "itemIds", Iterable { item: u32 })]
+        item_ids: Vec<u32>,
+        #[rod(rename = "quantities", Iterable { item: u32 })]
+        item_quantities: Vec<u32>,
+        #[rod(rename = "maxTotal", u32)]
+        max_total: u32,
+        #[rod(rename = "total", u32 { le_field: max_total })]
+        order_total: u32,
+    }
+    let order = Order { item_ids: vec![1, 2], item_quantities: vec![3], max_total: 10, order_total: 12 };
+    let errors = order.validate_all().unwrap_err();
+    assert!(matches!(&errors[0], RodValidateError::Struct(StructValidation::Compare(path, _, other)) if path == "total" && other == "maxTotal"));
+    assert!(matches!(&errors[1], RodValidateError::Struct(StructValidation::SameLength(first, 2, other, 1)) if first == "itemIds" && other == "quantities"));
+    assert_eq!(order.validate_field("total").unwrap_err().len(), 1);
+    assert!(errors.to_map().contains_key("itemIds"));
+}
+
+#[test]
+fn test_validate_field() {
+    #[derive(RodValidate)]
+    struct Address {
+        #[rod(String { length: 5 })]
+        zip: String,
+        #[rod(String { length: 1.. })]
+        city: String,
+    }
+    #[derive(RodValidate)]
+    #[rod(check = |s: &Self| s.name != s.address.city, message: "Name cannot be the city")]
+    struct Test {
+        #[rod(rename = "fullName", String { length: 3..=10 })]
+        name: String,
+        #[rod(u8 { size: 18.. })]
+        age: u8,
+        address: Address,
+        #[rod(Iterable { item: String { length: 1.. } })]
+        tags: Vec<String>,
+    }
+    let test = Test {
+        name: "".to_string(),
+        age: 10,
+        address: Address { zip: "123".to_string(), city: "".to_string() },
+        tags: vec!["a".to_string(), "".to_string()],
+    };
+    let paths = |result: Result<(), RodValidateErrorList>| {
+        result.err().unwrap_or_default().iter().map(|error| error.path().unwrap_or_default()).collect::<Vec<_>>()
+    };
+    assert_eq!(paths(test.validate_field("fullName")), ["fullName"]);
+    assert!(test.validate_field("name").is_ok());
+    assert_eq!(paths(test.validate_field("address")), ["address.zip", "address.city"]);
+    assert_eq!(paths(test.validate_field("address.zip")), ["address.zip"]);
+    assert_eq!(paths(test.validate_field("tags")), ["tags[1]"]);
+    assert!(test.validate_field("tags[0]").is_ok());
+    assert!(test.validate_field("unknown").is_ok());
+
+    #[derive(RodValidate)]
+    enum TestEnum {
+        Named {
+            #[rod(i32 { size: 1..=5 })]
+            value: i32,
+        },
+        Unit,
+    }
+    assert_eq!(paths(TestEnum::Named { value: 0 }.validate_field("value")), ["value"]);
+    assert!(TestEnum::Unit.validate_field("value").is_ok());
+}
+
+#[test]
+fn test_field_validators() {
+    #[derive(RodValidate)]
+    #[rod(field_validators)]
+    struct Test {
+        #[rod(rename = "userName", String { length: 3..=10 }, check = |s| !s.contains(' '))]
+        user_name: String,
+        #[rod(u8 { size: 18.. }, message: "Too young")]
+        age: u8,
+        #[rod(u8 { size: ..=60, le_field: age })]
+        years_employed: u8,
+        #[rod(str { length: 1.. })]
+        code: &'static str,
+        #[rod(Skip)]
+        unchecked: u8,
+    }
+    assert!(Test::validate_user_name(&"rustacean".to_string()).is_ok());
+    assert!(matches!(Test::validate_user_name(&"ab".to_string()), Err(RodValidateError::String(StringValidation::Length(path, ..))) if path == "userName"));
+    assert!(matches!(Test::validate_user_name(&"a b".to_string()), Err(RodValidateError::CheckFailed(path)) if path == "userName"));
+    assert!(matches!(Test::validate_age(&17), Err(RodValidateError::UserDefined(msg)) if msg == "Too young"));
+    assert!(Test::validate_code("").is_err());
+    // Only the rules that compare the field to other fields are left out
+    assert!(Test::validate_years_employed(&30).is_ok());
+    assert!(Test::validate_years_employed(&70).is_err());
+    let test = Test { user_name: "rustacean".to_string(), age: 20, years_employed: 2, code: "a", unchecked: 0 };
+    assert!(test.validate().is_ok());
+
+    #[derive(RodValidate)]
+    #[rod(field_validators)]
+    struct Pair<'a, T: RodValidate> {
+        #[rod(str { length: 1..=8 })]
+        key: &'a str,
+        value: T,
+    }
+    assert!(Pair::<u8>::validate_key("size").is_ok());
+    assert!(Pair::<u8>::validate_key("").is_err());
+}
+
+#[test]
+fn test_valid() {
+    #[derive(RodValidate, Debug)]
+    struct User {
+        #[rod(String { length: 3..=10 })]
+        name: String,
+    }
+    #[derive(RodValidate)]
+    struct Team {
+        lead: Valid<User>,
+    }
+    fn greet(user: &Valid<User>) -> String {
+        format!("Hello, {}", user.name)
+    }
+    let user = Valid::new(User { name: "ferris".to_string() }).unwrap();
+    assert_eq!(greet(&user), "Hello, ferris");
+    let errors = Valid::new(User { name: "ab".to_string() }).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    let team = Team { lead: user };
+    assert!(team.validate().is_ok());
+    assert_eq!(team.lead.into_inner().name, "ferris");
+
+    // The fixes of the value are passed through, here from a type that always suggests one
+    struct Padded(u8);
+    impl RodValidate for Padded {
+        fn validate(&self) -> Result<(), RodValidateError> {
+            Ok(())
+        }
+        fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+            Ok(())
+        }
+        fn suggest_fixes(&self) -> Vec<RodFix> {
+            vec![RodFix { path: "width".into(), value: RodFixValue::Integer(Integer::Positive(self.0 as u128 + 1)) }]
+        }
+    }
+    let fixes = Valid::new(Padded(7)).unwrap().suggest_fixes();
+    assert!(matches!(&fixes[..], [RodFix { path, value: RodFixValue::Integer(Integer::Positive(8)) }] if path == "width"));
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn test_valid_deserialize() {
+    #[derive(RodValidate)]
+    struct Port {
+        #[rod(u16 { size: 1024.. })]
+        number: u16,
+    }
+    impl<'de> serde::Deserialize<'de> for Port {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u16::deserialize(deserializer).map(|number| Port { number })
+        }
+    }
+    assert_eq!(serde_json::from_str::<Valid<Port>>("8080").unwrap().number, 8080);
+    assert!(serde_json::from_str::<Valid<Port>>("80").is_err());
+}
+
+#[test]
+fn test_checked() {
+    #[derive(RodValidate, Debug, Clone, PartialEq)]
+    #[rod(check = |s: &Self| s.min_workers <= s.max_workers, message: "min_workers must not exceed max_workers")]
+    struct Settings {
+        #[rod(u32 { size: 1..=64 })]
+        min_workers: u32,
+        #[rod(u32 { size: 1..=64 })]
+        max_workers: u32,
+    }
+    assert!(Checked::new(Settings { min_workers: 0, max_workers: 4 }).is_err());
+    let mut settings = Checked::new(Settings { min_workers: 1, max_workers: 4 }).unwrap();
+
+    let errors = settings.update(|settings| settings.min_workers = 8).unwrap_err();
+    assert!(matches!(&errors[0], RodValidateError::UserDefined(msg) if msg == "min_workers must not exceed max_workers"));
+    assert_eq!(settings.min_workers, 1);
+    settings.update(|settings| settings.max_workers = 16).unwrap();
+    assert_eq!(settings.max_workers, 16);
+
+    assert!(settings.set(Settings { min_workers: 1, max_workers: 100 }).is_err());
+    settings.set(Settings { min_workers: 2, max_workers: 2 }).unwrap();
+    assert!(settings.validate_all().is_ok());
+    assert_eq!(settings.into_inner(), Settings { min_workers: 2, max_workers: 2 });
+
+    let valid = Valid::new(Settings { min_workers: 1, max_workers: 1 }).unwrap();
+    assert_eq!(Checked::from(valid).max_workers, 1);
+
+    // The fixes of the value are passed through, here from a type that always suggests one
+    struct Padded(u8);
+    impl RodValidate for Padded {
+        fn validate(&self) -> Result<(), RodValidateError> {
+            Ok(())
+        }
+        fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+            Ok(())
+        }
+        fn suggest_fixes(&self) -> Vec<RodFix> {
+            vec![RodFix { path: "width".into(), value: RodFixValue::Integer(Integer::Positive(self.0 as u128 + 1)) }]
+        }
+    }
+    #[derive(RodValidate)]
+    struct Layout {
+        column: Checked<Padded>,
+    }
+    let layout = Layout { column: Checked::new(Padded(7)).unwrap() };
+    let fixes = layout.suggest_fixes();
+    assert!(matches!(&fixes[..], [RodFix { path, value: RodFixValue::Integer(Integer::Positive(8)) }] if path == "column.width"));
+}
+
+#[test]
+fn test_constructor() {
+    #[derive(RodValidate, Debug)]
+    #[rod(constructor)]
+    #[rod(check = |s: &Self| s.min <= s.max, message: "min must not exceed max")]
+    struct Range {
+        #[rod(i32 { size: 0..=100 })]
+        min: i32,
+        #[rod(i32 { size: 0..=100 })]
+        max: i32,
+        #[rod(String { length: 1.. })]
+        label: String,
+    }
+    let range = Range::new(1, 10, "small".to_string()).unwrap();
+    assert_eq!((range.min, range.max, range.label.as_str()), (1, 10, "small"));
+    let errors = Range::new(50, 200, String::new()).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    let errors = Range::new(10, 1, "inverted".to_string()).unwrap_err();
+    assert!(matches!(&errors[0], RodValidateError::UserDefined(msg) if msg == "min must not exceed max"));
+
+    #[derive(RodValidate)]
+    #[rod(constructor)]
+    struct Label<'a, T: RodValidate> {
+        #[rod(str { length: 1..=8 })]
+        text: &'a str,
+        value: T,
+    }
+    assert_eq!(Label::new("small", 5u8).unwrap().text, "small");
+    assert!(Label::new("", 5u8).is_err());
+}
+
+#[test]
+fn test_newtype() {
+    #[derive(RodValidate, Debug, PartialEq)]
+    #[rod(newtype)]
+    struct Email(#[rod(String { format: Email })] String);
+
+    #[derive(RodValidate, Debug)]
+    #[rod(newtype)]
+    struct Port {
+        #[rod(u16 { size: 1024.. })]
+        port: u16,
+    }
+
+    #[derive(RodValidate)]
+    struct Account {
+        email: Email,
+    }
+
+    let email = Email::new("user@example.com".to_string()).unwrap();
+    assert_eq!(email.len(), 16);
+    assert_eq!(&*email, "user@example.com");
+    assert_eq!(Email::try_from("admin@example.com".to_string()).unwrap().into_inner(), "admin@example.com");
+    let errors = Email::new("not an email".to_string()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path().as_deref(), Some("field_0"));
+    assert!(Email::try_from(String::new()).is_err());
+
+    assert_eq!(*Port::new(8080).unwrap(), 8080);
+    assert_eq!(Port::try_from(80).unwrap_err()[0].path().as_deref(), Some("port"));
+
+    assert!(Account { email }.validate_all().is_ok());
+    assert_eq!(Email::rod_schema().fields[0].name, "field_0");
+}
+
+#[test]
+fn test_setters() {
+    #[derive(RodValidate)]
+    #[rod(setters)]
+    struct Config {
+        #[rod(String { length: 1..=8 }, message: "Invalid name")]
+        name: String,
+        #[rod(u32 { size: 1..=64 })]
+        workers: u32,
+        #[rod(u32 { le_field: workers })]
+        busy_workers: u32,
+        #[rod(u32, check_self = |this: &Self, idle| *idle + this.busy_workers <= this.workers)]
+        idle_workers: u32,
+        #[rod(str { length: 1.. })]
+        mode: &'static str,
+    }
+    let mut config = Config { name: "server".to_string(), workers: 4, busy_workers: 0, idle_workers: 0, mode: "fast" };
+    assert!(config.set_name("worker".to_string()).is_ok());
+    assert_eq!(config.name, "worker");
+    assert!(matches!(config.set_name("far too long".to_string()), Err(RodValidateError::UserDefined(msg)) if msg == "Invalid name"));
+    assert_eq!(config.name, "worker");
+    assert!(matches!(config.set_workers(0), Err(RodValidateError::Integer(IntegerValidation::Size(path, ..))) if path == "workers"));
+    assert!(config.set_busy_workers(4).is_ok());
+    assert!(config.set_busy_workers(5).is_err());
+    assert_eq!(config.busy_workers, 4);
+    assert!(config.set_idle_workers(1).is_err());
+    assert!(config.set_idle_workers(0).is_ok());
+    assert!(config.set_mode("").is_err());
+    assert!(config.set_mode("slow").is_ok());
+
+    #[derive(RodValidate)]
+    #[rod(setters)]
+    struct Entry<'a, T>
+    where
+        T: RodValidate,
+    {
+        #[rod(str { length: 1..=8 })]
+        key: &'a str,
+        value: T,
+    }
+    let mut entry = Entry { key: "size", value: 1u32 };
+    assert!(entry.set_key("").is_err());
+    assert!(entry.set_key("length").is_ok());
+    assert_eq!((entry.key, entry.value), ("length", 1));
+}
+
+#[test]
+fn test_builder() {
+    #[derive(RodValidate, RodBuilder, Debug)]
+    struct User {
+        #[rod(rename = "userName", String { length: 3..=12 })]
+        user_name: String,
+        #[rod(u8 { size: 18.. })]
+        age: u8,
+        #[rod(Option { required: false, inner: String { length: 1.. } })]
+        nickname: Option<String>,
+    }
+    let user = User::builder().user_name("ferris".to_string()).age(30).build().unwrap();
+    assert_eq!((user.user_name.as_str(), user.age, user.nickname), ("ferris", 30, None));
+    let user = User::builder().user_name("ferris".to_string()).age(30).nickname(Some("crab".to_string())).build().unwrap();
+    assert_eq!(user.nickname.as_deref(), Some("crab"));
+
+    let errors = User::builder().build().unwrap_err();
+    assert_eq!(errors.iter().map(|error| error.to_string()).collect::<Vec<_>>(), ["Expected `userName` to be set", "Expected `age` to be set"]);
+    assert_eq!(errors[0].code(), "struct.missing");
+    let errors = UserBuilder::default().user_name("ab".to_string()).age(10).build().unwrap_err();
+    assert_eq!(errors.len(), 2);
+
+    #[derive(RodValidate, RodBuilder)]
+    struct Order<'a, T: RodValidate> {
+        #[rod(str { length: 1..=8 })]
+        id: &'a str,
+        items: Vec<T>,
+    }
+    let order = Order::builder().id("A-1").items(vec![1u32, 2]).build().unwrap();
+    assert_eq!((order.id, order.items.len()), ("A-1", 2));
+    assert!(Order::<u32>::builder().id("").items(Vec::new()).build().is_err());
+}
+
+#[test]
+fn test_sanitize() {
+    #[derive(RodValidate)]
+    struct Profile {
+        #[rod(String { trim, lowercase, format: Email })]
+        email: String,
+    }
+    #[derive(RodValidate)]
+    struct Test {
+        #[rod(String { trim, length: 3..=10 })]
+        name: String,
+        #[rod(String { uppercase, length: 2 })]
+        country: String,
+        #[rod(String { length: 1.. })]
+        untouched: String,
+        profile: Profile,
+    }
+    let mut test = Test {
+        name: "  ferris ".to_string(),
+        country: "nl".to_string(),
+        untouched: " a ".to_string(),
+        profile: Profile { email: " Ferris@Example.com".to_string() },
+    };
+    assert!(test.validate().is_err());
+    assert!(test.validate_sanitized().is_ok());
+    assert_eq!(test.name, "ferris");
+    assert_eq!(test.country, "NL");
+    assert_eq!(test.untouched, " a ");
+    assert_eq!(test.profile.email, "ferris@example.com");
+
+    #[derive(RodValidate)]
+    enum TestEnum {
+        Named {
+            #[rod(String { trim })]
+            value: String,
+        },
+        Unnamed(#[rod(u8)] u8, #[rod(String { lowercase })] String),
+        Unit,
+    }
+    let mut named = TestEnum::Named { value: " a ".to_string() };
+    named.sanitize();
+    assert!(matches!(named, TestEnum::Named { value } if value == "a"));
+    let mut unnamed = TestEnum::Unnamed(1, "ABC".to_string());
+    unnamed.sanitize();
+    assert!(matches!(unnamed, TestEnum::Unnamed(1, value) if value == "abc"));
+    TestEnum::Unit.sanitize();
+}
+
+#[test]
+fn test_coerce() {
+    #[derive(RodValidate)]
+    struct Config {
+        #[rod(coerce(i32), i32 { size: 1..=10 })]
+        workers: String,
+        #[rod(coerce(f64), f64 { size: 0.0..=1.0 }, message: "The ratio must be a number between 0 and 1")]
+        ratio: String,
+        #[rod(coerce(u16), u16, check = |s: &str| !s.starts_with('+'))]
+        port: &'static str,
+    }
+    let config = Config { workers: "4".to_string(), ratio: "0.5".to_string(), port: "8080" };
+    assert!(config.validate().is_ok());
+
+    let config = Config { workers: "ten".to_string(), ratio: "1.5".to_string(), port: "+80" };
+    let errors = config.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(
+        &errors[0],
+        RodValidateError::String(StringValidation::ParsesTo(path, value, "i32")) if path == "workers" && value == "ten"
+    ));
+    assert!(matches!(&errors[1], RodValidateError::UserDefined(message) if message == "The ratio must be a number between 0 and 1"));
+    assert!(matches!(&errors[2], RodValidateError::CheckFailed(path) if path == "port"));
+
+    let config = Config { workers: "11".to_string(), ratio: "x".to_string(), port: "80" };
+    let errors = config.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], RodValidateError::Integer(IntegerValidation::Size(path, ..)) if path == "workers"));
+    assert!(matches!(&errors[1], RodValidateError::UserDefined(message) if message == "The ratio must be a number between 0 and 1"));
+
+    #[derive(RodValidate)]
+    enum Setting {
+        Level(#[rod(coerce(u8), u8 { size: ..=3 })] String),
+    }
+    assert!(Setting::Level("2".to_string()).validate().is_ok());
+    assert!(Setting::Level("5".to_string()).validate().is_err());
+}
+
+#[test]
+fn test_patch() {
+    #[derive(RodValidate, Debug, Clone)]
+    struct Address {
+        #[rod(String { length: 4..=8 })]
+        zip: String,
+    }
+    #[derive(RodValidate)]
+    #[rod(patch(derive(Debug, Clone)), any_of(nickname, email))]
+    struct User {
+        #[rod(rename = "userName", String { length: 3..=12 })]
+        user_name: String,
+        #[rod(u8 { size: 18.. })]
+        age: u8,
+        #[rod(u8 { size: ..=80, le_field: age })]
+        years_active: u8,
+        #[rod(Option { required: false, inner: String { length: 1.. } })]
+        nickname: Option<String>,
+        #[rod(Option { required: false, inner: String { includes: "@" } })]
+        email: Option<String>,
+        #[rod(str { length: 1.. })]
+        role: &'static str,
+        address: Address,
+    }
+    let patch = UserPatch::default();
+    assert!(patch.validate_all().is_ok());
+
+    // Only the rules that compare the field to other fields are left out
+    let patch = UserPatch { age: Some(30), years_active: Some(40), nickname: Some(None), ..Default::default() };
+    assert!(patch.validate_all().is_ok());
+    let patch = UserPatch { years_active: Some(90), ..Default::default() };
+    assert!(matches!(patch.validate(), Err(RodValidateError::Integer(IntegerValidation::Size(path, ..))) if path == "years_active"));
+
+    let patch = UserPatch {
+        user_name: Some("ab".to_string()),
+        age: Some(12),
+        role: Some(""),
+        address: Some(Address { zip: "1".to_string() }),
+        ..Default::default()
+    };
+    assert!(matches!(patch.validate(), Err(RodValidateError::String(StringValidation::Length(path, ..))) if path == "userName"));
+    let errors = patch.clone().validate_all().unwrap_err();
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(&errors[1], RodValidateError::Integer(IntegerValidation::Size(path, ..)) if path == "age"));
+    assert!(matches!(&errors[3], RodValidateError::String(StringValidation::Length(path, ..)) if path == "address.zip"));
+
+    let mut user = User {
+        user_name: "ferris".to_string(),
+        age: 30,
+        years_active: 5,
+        nickname: Some("crab".to_string()),
+        email: None,
+        role: "admin",
+        address: Address { zip: "1234".to_string() },
+    };
+    let patch = UserPatch { age: Some(31), nickname: Some(None), email: Some(Some("ferris@example.com".to_string())), ..Default::default() };
+    assert!(patch.validate_all().is_ok());
+    patch.apply(&mut user);
+    assert_eq!(user.age, 31);
+    assert_eq!(user.nickname, None);
+    assert_eq!(user.email.as_deref(), Some("ferris@example.com"));
+    assert_eq!(user.user_name, "ferris");
+    assert!(user.validate_all().is_ok());
+
+    #[derive(RodValidate)]
+    #[rod(patch)]
+    struct Draft<'a> {
+        #[rod(str { length: 1..=20 }, check_self = |this: &Self, title| title.len() < this.body.len())]
+        title: &'a str,
+        #[rod(str { length: 1.. })]
+        body: &'a str,
+    }
+    let mut draft = Draft { title: "Hello", body: "Hello, world" };
+    assert!(DraftPatch { title: Some(""), body: None }.validate().is_err());
+    let patch = DraftPatch { title: Some("Hello, world!"), body: None };
+    assert!(patch.validate_all().is_ok());
+    patch.apply(&mut draft);
+    assert_eq!(draft.title, "Hello, world!");
+}
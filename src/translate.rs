@@ -0,0 +1,24 @@
+use std::sync::RwLock;
+
+use crate::errors::Params;
+
+/// A function that turns a message key, like `errors.user.name.too_short`, and the params of the error into a message.
+pub type RodTranslator = fn(&str, &Params) -> String;
+
+static TRANSLATOR: RwLock<Option<RodTranslator>> = RwLock::new(None);
+
+/// Sets the function used to build the messages of fields with a `message_key`, replacing the previous one.
+/// It is called when the error is reported, so it should be set before validating, e.g. at startup.
+pub fn set_translator(translator: RodTranslator) {
+    *TRANSLATOR.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(translator);
+}
+
+/// Builds the message of `key` with the translator set by [`set_translator`].
+/// Without a translator, the key itself is the message.
+pub fn translate(key: &str, params: &Params) -> String {
+    let translator = *TRANSLATOR.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match translator {
+        Some(translator) => translator(key, params),
+        None => key.to_string(),
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::Deref;
+
+use crate::errors::{RodValidateError, RodValidateErrorList, RodValidateReport};
+use crate::schema::StructSchema;
+use crate::{RodFix, RodValidate};
+
+/// A value that passed `validate_all`. It can only be built with [`Valid::new`], so a function that takes a `Valid<User>`
+/// knows the user was validated without checking again. The value is read through `Deref`, and cannot be changed in place.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Valid<T>(T);
+
+impl<T: RodValidate> Valid<T> {
+    /// Validates `value` with `validate_all`, returning it wrapped in `Valid` if it is valid.
+    pub fn new(value: T) -> Result<Self, RodValidateErrorList> {
+        value.validate_all()?;
+        Ok(Valid(value))
+    }
+}
+
+impl<T> Valid<T> {
+    /// Returns the value, which is no longer known to be valid once it changes.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// A `Valid<T>` field of a derived type is validated like the `T` it holds.
+impl<T: RodValidate> RodValidate for Valid<T> {
+    fn validate(&self) -> Result<(), RodValidateError> {
+        self.0.validate()
+    }
+    fn validate_all(&self) -> Result<(), RodValidateErrorList> {
+        self.0.validate_all()
+    }
+    fn validate_all_limited(&self, max_errors: usize) -> Result<(), RodValidateErrorList> {
+        self.0.validate_all_limited(max_errors)
+    }
+    fn validate_report(&self) -> RodValidateReport {
+        self.0.validate_report()
+    }
+    fn validate_field(&self, path: &str) -> Result<(), RodValidateErrorList> {
+        self.0.validate_field(path)
+    }
+    fn validate_tagged(&self, tags: &[&str]) -> Result<(), RodValidateError> {
+        self.0.validate_tagged(tags)
+    }
+    fn validate_all_tagged(&self, tags: &[&str]) -> Result<(), RodValidateErrorList> {
+        self.0.validate_all_tagged(tags)
+    }
+    fn suggest_fixes(&self) -> Vec<RodFix> {
+        self.0.suggest_fixes()
+    }
+    fn rod_schema() -> StructSchema {
+        T::rod_schema()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Valid<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes a `T` and validates it, failing with the validation errors as the message if it is invalid.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + RodValidate> serde::Deserialize<'de> for Valid<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Valid::new(value).map_err(|errors| serde::de::Error::custom(errors.to_compact_string()))
+    }
+}
@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(coerce(i32), i32 { size: 1..=10 })]
+    field: u64,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+enum Test {
+    Range {
+        #[rod(u32 { le_field: end })]
+        start: u32,
+        #[rod(u32)]
+        end: u32,
+    },
+}
+
+fn main() {}
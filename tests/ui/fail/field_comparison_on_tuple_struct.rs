@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test(
+    #[rod(String)] String,
+    #[rod(Option { String { eq_field: password } })] Option<String>,
+);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+#[rod(field_validators)]
+struct Test {
+    #[rod(String { length: 1.. })]
+    sanitized: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rod_validation::prelude::*;
+
+struct Slug;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(String { format: Slug })]
+    field: String,
+}
+
+fn main() {}
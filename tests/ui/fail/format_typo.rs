@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(String { format: Emial })]
+    field: String,
+}
+
+fn main() {}
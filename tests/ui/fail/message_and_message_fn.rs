@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(i32 { size: 0..=8 }, message: "Too big", message_fn = |value| format!("{} is too big", value))]
+    field: i32,
+}
+
+fn main() {}
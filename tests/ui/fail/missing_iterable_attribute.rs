@@ -0,0 +1,10 @@
+use std::collections::HashSet;
+
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    tags: HashSet<String>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    retries: Option<(i32, i32)>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(String { length: 1..=10 })]
+    name: String,
+}
+
+#[derive(RodValidate)]
+enum Shape {
+    Point(Test, (f64, Option<Vec<Test>>)),
+}
+
+fn main() {}
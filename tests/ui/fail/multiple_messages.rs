@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(i32 { size: 0..=8 }, message: "Too big", message: "Way too big")]
+    field: i32,
+}
+
+fn main() {}
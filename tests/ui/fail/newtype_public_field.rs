@@ -0,0 +1,7 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+#[rod(newtype)]
+struct Email(#[rod(String { format: Email })] pub String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(Option { Iterable { item: String }, allow_none: true })]
+    tags: Option<Vec<i32>>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(Option { String { length: 5 } })]
+    field: Option<i32>,
+}
+
+fn main() {}
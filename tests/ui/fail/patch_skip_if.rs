@@ -0,0 +1,11 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+#[rod(patch)]
+struct Test {
+    draft: bool,
+    #[rod(String { length: 1.. }, skip_if = |s: &Self| s.draft)]
+    title: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Inner {
+    #[rod(i32)]
+    field: i32,
+}
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(rename = "innerValue")]
+    inner: Inner,
+}
+
+fn main() {}
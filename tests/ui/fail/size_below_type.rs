@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(Option { u32 { size: -1..10 } })]
+    retries: Option<u32>,
+}
+
+fn main() {}
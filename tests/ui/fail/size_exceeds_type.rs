@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(u8 { size: 0..=300 })]
+    percent: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(str { trim, length: 1.. })]
+    field: &'static str,
+}
+
+fn main() {}
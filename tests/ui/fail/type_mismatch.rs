@@ -0,0 +1,9 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(String { length: 5 })]
+    field: i32,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct User {
+    #[rod(String { length: 3..=20 })]
+    name: String,
+    #[rod(u8 { size: 18..=120 }, message: "Invalid age")]
+    age: u8,
+    #[rod(Option { String { length: 1..=50 } })]
+    nickname: Option<String>,
+}
+
+fn main() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        nickname: Some("Al".to_string()),
+    };
+    assert!(user.validate().is_ok());
+}
@@ -0,0 +1,25 @@
+use rod_validation::prelude::*;
+
+rod_validation::formats! {
+    Emails => r"^[^@\s]+@[^@\s]+(,[^@\s]+@[^@\s]+)*$",
+}
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(String { format: self::Emails })]
+    recipients: String,
+}
+
+mod nested {
+    use rod_validation::prelude::*;
+
+    #[derive(RodValidate)]
+    pub struct Test {
+        #[rod(String { format: super::Emails })]
+        pub recipients: String,
+    }
+}
+
+fn main() {
+    let _ = nested::Test { recipients: String::new() };
+}
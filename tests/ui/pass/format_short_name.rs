@@ -0,0 +1,16 @@
+use rod_validation::prelude::*;
+
+rod_validation::formats! {
+    Ulid => r"^[0-9A-HJKMNP-TV-Z]{26}$",
+    Uri => r"^[a-z][a-z0-9+.-]*:",
+}
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(String { format: Ulid })]
+    id: String,
+    #[rod(String { format: Uri })]
+    link: String,
+}
+
+fn main() {}
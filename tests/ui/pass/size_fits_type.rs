@@ -0,0 +1,13 @@
+use rod_validation::prelude::*;
+
+#[derive(RodValidate)]
+struct Test {
+    #[rod(u8 { size: 0..=255 })]
+    byte: u8,
+    #[rod(i8 { size: -128..=127 })]
+    signed: i8,
+    #[rod(u64 { size: 1..=18446744073709551615 })]
+    big: u64,
+}
+
+fn main() {}